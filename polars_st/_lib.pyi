@@ -6,3 +6,11 @@ __version__: str
 def get_crs_from_code(srid: int) -> str | None: ...
 def get_crs_authority(definition: str) -> tuple[str, str] | None: ...
 def to_python_dict(series: PySeries) -> pl.Series: ...
+def geoparquet_metadata(
+    column_name: str,
+    srid: int,
+    bounds: list[float] | None = None,
+    geometry_types: list[str] = ...,
+) -> str: ...
+def parse_geoparquet_metadata(metadata: str) -> tuple[str, int]: ...
+def clear_sjoin_cache() -> None: ...
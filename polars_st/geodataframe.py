@@ -12,6 +12,7 @@ from pyogrio import write_arrow
 
 from polars_st._lib import get_crs_from_code
 from polars_st.casting import st
+from polars_st.geoparquet import geoparquet_metadata
 from polars_st.geoseries import GeoSeries
 from polars_st.selectors import geom
 
@@ -31,7 +32,6 @@ if TYPE_CHECKING:
     from lonboard.types.map import MapKwargs
     from polars._typing import (
         FrameInitTypes,
-        JoinStrategy,
         JoinValidation,
         Orientation,
         SchemaDefinition,
@@ -192,7 +192,7 @@ class GeoDataFrameNameSpace:
         self,
         other: DataFrame,
         on: str | Expr = "geometry",
-        how: JoinStrategy = "inner",
+        how: Literal["inner", "left", "right", "full"] = "inner",
         predicate: Literal[
             "intersects_bbox",
             "intersects",
@@ -214,7 +214,13 @@ class GeoDataFrameNameSpace:
         validate: JoinValidation = "m:m",
         coalesce: bool | None = None,
     ) -> GeoDataFrame:
-        """Perform a spatial join operation with another DataFrame."""
+        """Perform a spatial join operation with another DataFrame.
+
+        The right geometries are queried in parallel, but the output order is stable:
+        matches are sorted by right row, then by left row. With `how` set to `"left"`,
+        `"right"` or `"full"`, the unmatched rows follow the matches. The index of the left
+        geometries is kept for the next join until `st.clear_sjoin_cache()` is called.
+        """
         if not isinstance(other, DataFrame):
             msg = f"expected `other` join table to be a DataFrame, got {type(other).__name__!r}"
             raise TypeError(msg)
@@ -238,6 +244,58 @@ class GeoDataFrameNameSpace:
             .pipe(lambda df: cast("GeoDataFrame", df))
         )
 
+    def self_sjoin(
+        self,
+        on: str | Expr = "geometry",
+        predicate: Literal[
+            "intersects_bbox",
+            "intersects",
+            "within",
+            "dwithin",
+            "contains",
+            "overlaps",
+            "crosses",
+            "touches",
+            "covers",
+            "covered_by",
+            "contains_properly",
+        ] = "intersects",
+        distance: float | None = None,
+        *,
+        distinct: bool = False,
+        suffix: str = "_right",
+    ) -> GeoDataFrame:
+        """Perform a spatial join of the DataFrame with itself.
+
+        See [`GeoLazyFrameNameSpace.self_sjoin`][polars_st.GeoLazyFrameNameSpace.self_sjoin].
+
+        Examples:
+            >>> gdf = st.GeoDataFrame({
+            ...     "id": [0, 1, 2],
+            ...     "geometry": [
+            ...         "POLYGON ((0 0, 2 0, 2 2, 0 2, 0 0))",
+            ...         "POLYGON ((1 1, 3 1, 3 3, 1 3, 1 1))",
+            ...         "POLYGON ((5 5, 6 5, 6 6, 5 5))",
+            ...     ],
+            ... })
+            >>> gdf.st.self_sjoin(distinct=True).select("id", "id_right")
+            shape: (1, 2)
+            ┌─────┬──────────┐
+            │ id  ┆ id_right │
+            │ --- ┆ ---      │
+            │ i64 ┆ i64      │
+            ╞═════╪══════════╡
+            │ 0   ┆ 1        │
+            └─────┴──────────┘
+        """
+        return (
+            self._df.lazy()
+            .pipe(st)
+            .self_sjoin(on, predicate, distance, distinct=distinct, suffix=suffix)
+            .collect()
+            .pipe(lambda df: cast("GeoDataFrame", df))
+        )
+
     def to_wkt(
         self,
         *geometry_columns: str,
@@ -299,12 +357,20 @@ class GeoDataFrameNameSpace:
             ),
         )
 
-    def to_geojson(self, *geometry_columns: str, indent: int | None = None) -> DataFrame:
+    def to_geojson(
+        self,
+        *geometry_columns: str,
+        indent: int | None = None,
+        output_dimension: Literal[2, 3] = 3,
+        rounding_precision: int | None = None,
+    ) -> DataFrame:
         """Serialize the DataFrame geometry column as GeoJSON.
 
         See [`GeoExprNameSpace.to_geojson`][polars_st.GeoExprNameSpace.to_geojson].
         """
-        return self._df.with_columns(geom(*geometry_columns).st.to_geojson(indent))
+        return self._df.with_columns(
+            geom(*geometry_columns).st.to_geojson(indent, output_dimension, rounding_precision),
+        )
 
     def to_shapely(self, *geometry_columns: str) -> DataFrame:
         """Convert the DataFrame geometry column to a shapely representation.
@@ -363,6 +429,43 @@ class GeoDataFrameNameSpace:
             crs=get_unique_crs_or_raise(self._df, geometry_name),
         )
 
+    def geoparquet_metadata(self, geometry_name: str = "geometry") -> str:
+        """Create the GeoParquet `geo` metadata describing a geometry column.
+
+        The SRID, bounding box and geometry types are computed from the data.
+        See [`geoparquet_metadata`][polars_st.geoparquet_metadata] for details.
+
+        Examples:
+            >>> import json
+            >>> gdf = st.GeoDataFrame(["POINT(0 0)", "POINT(1 2)"])
+            >>> metadata = json.loads(gdf.st.geoparquet_metadata())
+            >>> pprint.pprint(metadata["columns"]["geometry"])
+            {'bbox': [0.0, 0.0, 1.0, 2.0],
+             'crs': None,
+             'encoding': 'WKB',
+             'geometry_types': ['Point']}
+        """
+        srids = self._df.select(geom(geometry_name).st.srid()).unique().drop_nulls()
+        if len(srids) > 1:
+            msg = "DataFrame with mixed SRIDs aren't supported for this operation"
+            raise ValueError(msg)
+        srid = srids[0, 0] if len(srids) == 1 else 0
+        bounds = self._df.select(geom(geometry_name).st.total_bounds())[0, 0]
+        geometry_types = (
+            self._df.select(geom(geometry_name).st.geometry_type().cast(pl.String))
+            .to_series()
+            .drop_nulls()
+            .unique()
+            .sort()
+            .to_list()
+        )
+        return geoparquet_metadata(
+            geometry_name,
+            srid,
+            bounds.to_list() if bounds is not None and not bounds.is_nan().any() else None,
+            geometry_types,
+        )
+
     @property
     def __geo_interface__(self) -> dict:
         """Return a GeoJSON FeatureCollection [`dict`][] representation of the DataFrame.
@@ -16,7 +16,7 @@ from polars_st.geometry import GeometryType, PolarsGeometryType
 from polars_st.utils.internal import is_empty_method
 
 if TYPE_CHECKING:
-    from collections.abc import Sequence
+    from collections.abc import Callable, Sequence
 
     from polars_st.typing import (
         IntoExprColumn,
@@ -80,6 +80,10 @@ class GeoExprNameSpace:
     def geometry_type(self) -> pl.Expr:
         """Return the type of each geometry.
 
+        The type is read from the EWKB header without parsing the whole geometry,
+        and returned as a `PolarsGeometryType` enum,
+        which can be cast to `pl.String` to get the type names.
+
         Examples:
             >>> gdf = st.GeoDataFrame([
             ...     "POINT(0 0)",
@@ -155,11 +159,49 @@ class GeoExprNameSpace:
         """Return the length of each geometry."""
         ...
 
+    @register_plugin()
+    def length_3d(self) -> pl.Expr:
+        """Return the length of each geometry, accounting for the Z coordinate.
+
+        Coordinates without Z are treated as having a Z of 0, so that this is equal to
+        [`length`][polars_st.GeoExprNameSpace.length] for 2D geometries. Polygons
+        return the length of their rings. Curved geometries raise an error, they can be
+        linearized first with [`segmentize`][polars_st.GeoExprNameSpace.segmentize].
+        """
+        ...
+
     @register_plugin()
     def minimum_clearance(self) -> pl.Expr:
         """Return the geometry minimum clearance."""
         ...
 
+    @register_plugin()
+    def minimum_bounding_radius(self) -> pl.Expr:
+        """Return the radius of the minimum bounding circle of each geometry.
+
+        The radius is computed from the convex hull vertices, without building the
+        circle itself. Empty geometries return NaN.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame([
+            ...     "POINT (0 0)",
+            ...     "LINESTRING (0 0, 2 0)",
+            ...     "POLYGON ((0 0, 6 0, 3 4, 0 0))",
+            ... ])
+            >>> gdf.select(st.minimum_bounding_radius())
+            shape: (3, 1)
+            ┌──────────┐
+            │ geometry │
+            │ ---      │
+            │ f64      │
+            ╞══════════╡
+            │ 0.0      │
+            │ 1.0      │
+            │ 3.125    │
+            └──────────┘
+        """
+        ...
+
     @register_plugin()
     def x(self) -> pl.Expr:
         """Return the `x` value of Point geometries."""
@@ -182,12 +224,94 @@ class GeoExprNameSpace:
 
     @register_plugin()
     def count_coordinates(self) -> pl.Expr:
-        """Return the number of coordinates in each geometry."""
+        """Return the number of coordinates in each geometry.
+
+        Like PostGIS `ST_NPoints`, the coordinates of all rings and parts are counted,
+        so this is the total number of vertices of any geometry.
+        """
+        ...
+
+    def num_vertices(self) -> pl.Expr:
+        """Return the total number of vertices in each geometry.
+
+        Alias of [`count_coordinates`][polars_st.GeoExprNameSpace.count_coordinates], matching
+        PostGIS `ST_NPoints`. Unlike [`count_points`][polars_st.GeoExprNameSpace.count_points],
+        rings and parts of any geometry type are counted.
+        """
+        return self.count_coordinates()
+
+    @register_plugin()
+    def count_coordinates_per_part(self) -> pl.Expr:
+        """Return the number of coordinates of each part of each geometry.
+
+        Each part of multi-part geometries and collections is counted separately, and
+        polygons give one count per ring, exterior ring first. This is useful to spot
+        degenerate parts.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame([
+            ...     "MULTILINESTRING ((0 0, 1 1), (0 0, 1 1, 2 2))",
+            ...     "POLYGON ((0 0, 3 0, 0 3, 0 0), (1 1, 2 1, 1 2, 1 1))",
+            ... ])
+            >>> gdf.select(st.count_coordinates_per_part())
+            shape: (2, 1)
+            ┌───────────┐
+            │ geometry  │
+            │ ---       │
+            │ list[u32] │
+            ╞═══════════╡
+            │ [2, 3]    │
+            │ [4, 4]    │
+            └───────────┘
+        """
+        ...
+
+    @register_plugin()
+    def coordinates(
+        self,
+        output_dimension: Literal[2, 3] | None = None,
+        structured: bool = False,
+    ) -> pl.Expr:
+        """Return the coordinates of each geometry.
+
+        Args:
+            output_dimension: Dimension of the returned coordinates. Defaults to the
+                coordinate dimension of each geometry.
+            structured: If `True`, coordinates are nested in lists mirroring the geometry
+                structure: parts, then rings, then coordinates. Each member of a
+                collection is a part, and points and lines have a single ring.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame([
+            ...     "POLYGON ((0 0, 1 0, 1 1, 0 0), (0.2 0.1, 0.8 0.1, 0.8 0.7, 0.2 0.1))",
+            ...     "MULTIPOINT ((0 0), (1 1))",
+            ... ])
+            >>> coords = gdf.select(st.coordinates(structured=True)).to_series()
+            >>> coords.list.len().to_list(), coords.list.first().list.len().to_list()
+            ([1, 2], [2, 1])
+        """
         ...
 
     @register_plugin()
-    def coordinates(self, output_dimension: Literal[2, 3] | None = None) -> pl.Expr:
-        """Return the coordinates of each geometry."""
+    def coordinates_struct(self) -> pl.Expr:
+        """Return the coordinates of each geometry as structs with named ordinates.
+
+        Each coordinate is a struct with fields `x`, `y`, `z` and `m`. The `z` and `m`
+        fields are null for geometries without those dimensions.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["LINESTRING Z (0 1 2, 3 4 5)"])
+            >>> gdf.select(st.coordinates_struct()).explode("geometry").unnest("geometry")
+            shape: (2, 4)
+            ┌─────┬─────┬─────┬──────┐
+            │ x   ┆ y   ┆ z   ┆ m    │
+            │ --- ┆ --- ┆ --- ┆ ---  │
+            │ f64 ┆ f64 ┆ f64 ┆ f64  │
+            ╞═════╪═════╪═════╪══════╡
+            │ 0.0 ┆ 1.0 ┆ 2.0 ┆ null │
+            │ 3.0 ┆ 4.0 ┆ 5.0 ┆ null │
+            └─────┴─────┴─────┴──────┘
+        """
         ...
 
     @register_plugin()
@@ -222,7 +346,28 @@ class GeoExprNameSpace:
 
     @register_plugin()
     def count_points(self) -> pl.Expr:
-        """Return the number of points in LineString geometries."""
+        """Return the number of points in LineString geometries.
+
+        Like PostGIS `ST_NumPoints`, this returns 0 for any other geometry type. Use
+        [`count_coordinates`][polars_st.GeoExprNameSpace.count_coordinates] for the
+        total number of vertices of polygons and collections.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame([
+            ...     "LINESTRING (0 0, 1 1, 2 0)",
+            ...     "POLYGON ((0 0, 1 0, 1 1, 0 0))",
+            ... ])
+            >>> gdf.select(points=st.count_points(), coordinates=st.count_coordinates())
+            shape: (2, 2)
+            ┌────────┬─────────────┐
+            │ points ┆ coordinates │
+            │ ---    ┆ ---         │
+            │ u32    ┆ u32         │
+            ╞════════╪═════════════╡
+            │ 3      ┆ 3           │
+            │ 0      ┆ 4           │
+            └────────┴─────────────┘
+        """
         ...
 
     @register_plugin()
@@ -230,11 +375,215 @@ class GeoExprNameSpace:
         """Return the nth point of LineString geometries."""
         ...
 
+    @register_plugin()
+    def start_point(self) -> GeoExpr:
+        """Return the first point of LineString geometries.
+
+        Non-linear and empty geometries return null.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame([
+            ...     "LINESTRING (0 0, 1 1, 2 0)",
+            ...     "LINESTRING EMPTY",
+            ...     "POINT (0 0)",
+            ... ])
+            >>> gdf.select(st.start_point().st.to_wkt())
+            shape: (3, 1)
+            ┌─────────────┐
+            │ geometry    │
+            │ ---         │
+            │ str         │
+            ╞═════════════╡
+            │ POINT (0 0) │
+            │ null        │
+            │ null        │
+            └─────────────┘
+        """
+        ...
+
+    @register_plugin()
+    def end_point(self) -> GeoExpr:
+        """Return the last point of LineString geometries.
+
+        Non-linear and empty geometries return null.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame([
+            ...     "LINESTRING (0 0, 1 1, 2 0)",
+            ...     "LINESTRING EMPTY",
+            ...     "POINT (0 0)",
+            ... ])
+            >>> gdf.select(st.end_point().st.to_wkt())
+            shape: (3, 1)
+            ┌─────────────┐
+            │ geometry    │
+            │ ---         │
+            │ str         │
+            ╞═════════════╡
+            │ POINT (2 0) │
+            │ null        │
+            │ null        │
+            └─────────────┘
+        """
+        ...
+
+    @register_plugin()
+    def set_point(
+        self,
+        index: IntoIntegerExpr,
+        point: IntoGeoExprColumn,
+        strict: bool = False,
+    ) -> GeoExpr:
+        """Replace the nth point of LineString geometries.
+
+        Args:
+            index: Index of the point to replace.
+            point: Point geometry to set. Z and M are matched by name:
+                missing values are set to NaN and those the line lacks are dropped.
+            strict: If True, raise an error when the index is out of range instead of
+                returning null.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["LINESTRING (0 0, 1 1, 2 0)"])
+            >>> gdf.select(st.geom().st.set_point(1, st.point(pl.lit([1.0, 2.0]))).st.to_wkt())
+            shape: (1, 1)
+            ┌────────────────────────────┐
+            │ geometry                   │
+            │ ---                        │
+            │ str                        │
+            ╞════════════════════════════╡
+            │ LINESTRING (0 0, 1 2, 2 0) │
+            └────────────────────────────┘
+        """
+        ...
+
+    @register_plugin()
+    def add_point(
+        self,
+        point: IntoGeoExprColumn,
+        index: IntoIntegerExpr | None = None,
+        strict: bool = False,
+    ) -> GeoExpr:
+        """Insert a point in LineString geometries.
+
+        Args:
+            point: Point geometry to insert. Z and M are matched by name:
+                missing values are set to NaN and those the line lacks are dropped.
+            index: Index at which the point is inserted. If null, the point is appended
+                at the end of the line.
+            strict: If True, raise an error when the index is out of range instead of
+                returning null.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["LINESTRING (0 0, 1 1)"])
+            >>> gdf.select(st.geom().st.add_point(st.point(pl.lit([2.0, 0.0]))).st.to_wkt())
+            shape: (1, 1)
+            ┌────────────────────────────┐
+            │ geometry                   │
+            │ ---                        │
+            │ str                        │
+            ╞════════════════════════════╡
+            │ LINESTRING (0 0, 1 1, 2 0) │
+            └────────────────────────────┘
+        """
+        ...
+
+    @register_plugin()
+    def remove_point(self, index: IntoIntegerExpr, strict: bool = False) -> GeoExpr:
+        """Remove the nth point of LineString geometries.
+
+        Lines that would be left with a single point return null.
+
+        Args:
+            index: Index of the point to remove.
+            strict: If True, raise an error when the index is out of range or when the
+                resulting line would be invalid instead of returning null.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["LINESTRING (0 0, 1 1, 2 0)"])
+            >>> gdf.select(st.geom().st.remove_point(1).st.to_wkt())
+            shape: (1, 1)
+            ┌───────────────────────┐
+            │ geometry              │
+            │ ---                   │
+            │ str                   │
+            ╞═══════════════════════╡
+            │ LINESTRING (0 0, 2 0) │
+            └───────────────────────┘
+        """
+        ...
+
     @register_plugin()
     def parts(self) -> pl.Expr:
         """Return the list of parts for multipart geometries."""
         ...
 
+    def explode(self) -> pl.Expr:
+        """Return one row per part of multipart geometries.
+
+        The result is a struct with each part and the index of the row it originates
+        from. Single geometries are returned as a single part, empty collections
+        produce no row, and null geometries produce a single null row.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame([
+            ...     "MULTIPOINT ((0 0), (1 1))",
+            ...     "POINT (2 2)",
+            ...     "GEOMETRYCOLLECTION EMPTY",
+            ... ])
+            >>> gdf.select(st.geom().st.explode()).unnest("geometry").with_columns(st.to_wkt())
+            shape: (3, 2)
+            ┌─────────────┬───────┐
+            │ geometry    ┆ index │
+            │ ---         ┆ ---   │
+            │ str         ┆ u32   │
+            ╞═════════════╪═══════╡
+            │ POINT (0 0) ┆ 0     │
+            │ POINT (1 1) ┆ 0     │
+            │ POINT (2 2) ┆ 1     │
+            └─────────────┴───────┘
+        """
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="explode",
+            args=[self._expr],
+            is_elementwise=False,
+        )
+
+    def dump_points(self) -> pl.Expr:
+        """Return one row per vertex of each geometry.
+
+        The result is a struct with each vertex as a Point, the index of the row it
+        originates from, the index of the part it belongs to in multipart geometries,
+        and the index of the ring it belongs to in polygons (0 being the exterior
+        ring). Z and M coordinates are preserved. Empty geometries produce no row,
+        and null geometries produce a single null row.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame([
+            ...     "MULTIPOINT ((0 0), (1 1))",
+            ...     "LINESTRING (2 2, 3 3)",
+            ... ])
+            >>> gdf.select(st.geom().st.dump_points()).unnest("geometry").with_columns(st.to_wkt())
+            shape: (4, 4)
+            ┌─────────────┬───────┬──────┬──────┐
+            │ geometry    ┆ index ┆ part ┆ ring │
+            │ ---         ┆ ---   ┆ ---  ┆ ---  │
+            │ str         ┆ u32   ┆ u32  ┆ u32  │
+            ╞═════════════╪═══════╪══════╪══════╡
+            │ POINT (0 0) ┆ 0     ┆ 0    ┆ 0    │
+            │ POINT (1 1) ┆ 0     ┆ 1    ┆ 0    │
+            │ POINT (2 2) ┆ 1     ┆ 0    ┆ 0    │
+            │ POINT (3 3) ┆ 1     ┆ 0    ┆ 0    │
+            └─────────────┴───────┴──────┴──────┘
+        """
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="dump_points",
+            args=[self._expr],
+            is_elementwise=False,
+        )
+
     @register_plugin()
     def precision(self) -> pl.Expr:
         """Return the precision of each geometry."""
@@ -254,13 +603,58 @@ class GeoExprNameSpace:
         """Return the distance from each geometry to other."""
         ...
 
+    @register_plugin()
+    def distance_3d(self, other: IntoGeoExprColumn) -> pl.Expr:
+        """Return the distance from each geometry to other, accounting for the Z coordinate.
+
+        Coordinates without Z are treated as having a Z of 0. Polygons are surfaces lying
+        in the plane of their exterior ring. Curved geometries raise an error, they can be
+        linearized first with [`segmentize`][polars_st.GeoExprNameSpace.segmentize].
+        Like [`distance`][polars_st.GeoExprNameSpace.distance], empty geometries
+        return NaN.
+        """
+        ...
+
+    def distance_matrix(self, other: IntoGeoExprColumn) -> pl.Expr:
+        """Return the distances from each geometry to every geometry of other.
+
+        Unlike [`distance`][polars_st.GeoExprNameSpace.distance], `other` is not
+        matched row by row: row `i` of the result lists the distances from geometry `i`
+        to each geometry of the whole `other` column, in order. This avoids a cross join
+        for modest sizes, but the output grows with the product of both lengths. Null
+        geometries of other give null distances, and empty geometries give NaN, like
+        [`distance`][polars_st.GeoExprNameSpace.distance].
+
+        Args:
+            other: Column of geometries to measure the distance to.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["POINT (0 0)", "POINT (3 4)"])
+            >>> others = st.GeoSeries(["POINT (0 0)", "POINT (0 4)"])
+            >>> gdf.select(st.geom().st.distance_matrix(pl.lit(others))).to_series().to_list()
+            [[0.0, 4.0], [5.0, 3.0]]
+        """
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="distance_matrix",
+            args=[self._expr, other],
+            is_elementwise=False,
+        )
+
     @register_plugin()
     def hausdorff_distance(
         self,
         other: IntoGeoExprColumn,
         densify: float | None = None,
     ) -> pl.Expr:
-        """Return the hausdorff distance from each geometry to other."""
+        """Return the hausdorff distance from each geometry to other.
+
+        Args:
+            other: Geometries to compute the distance to.
+            densify: If set, each segment is split into segments of equal length whose
+                size is this fraction of the original segment length, which gives a
+                more accurate result. Must be in the range (0, 1].
+        """
         ...
 
     @register_plugin()
@@ -269,7 +663,14 @@ class GeoExprNameSpace:
         other: IntoGeoExprColumn,
         densify: float | None = None,
     ) -> pl.Expr:
-        """Return the frechet distance from each geometry to other."""
+        """Return the frechet distance from each geometry to other.
+
+        Args:
+            other: Geometries to compute the distance to.
+            densify: If set, each segment is split into segments of equal length whose
+                size is this fraction of the original segment length, which gives a
+                more accurate result. Must be in the range (0, 1].
+        """
         ...
 
     # Projection operations
@@ -279,6 +680,60 @@ class GeoExprNameSpace:
         """Return the geometry SRID."""
         ...
 
+    @register_plugin()
+    def srid_authority(self) -> pl.Expr:
+        """Return the authority string of each geometry SRID, such as `"EPSG:4326"`.
+
+        The SRID is read from the EWKB header, without parsing the geometry. SRID 0, or
+        codes without a known EPSG definition, return null.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(
+            ...     ["POINT (0 0)", "SRID=4326;POINT (0 0)"],
+            ...     geometry_format="ewkt",
+            ... )
+            >>> gdf.select(st.srid_authority())
+            shape: (2, 1)
+            ┌───────────┐
+            │ geometry  │
+            │ ---       │
+            │ str       │
+            ╞═══════════╡
+            │ null      │
+            │ EPSG:4326 │
+            └───────────┘
+        """
+        ...
+
+    @register_plugin()
+    def has_srid(self) -> pl.Expr:
+        """Return `True` for each geometry with a non-zero SRID.
+
+        The SRID is read from the EWKB header, without parsing the geometry.
+        """
+        ...
+
+    @register_plugin()
+    def strip_srid(self) -> GeoExpr:
+        """Remove the SRID of each geometry.
+
+        The SRID is removed from the EWKB header, leaving the rest of the geometry bytes
+        untouched. This is useful before writing to formats that don't support EWKB.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["SRID=4326;POINT (0 0)"], geometry_format="ewkt")
+            >>> gdf.select(st.strip_srid().st.has_srid())
+            shape: (1, 1)
+            ┌──────────┐
+            │ geometry │
+            │ ---      │
+            │ bool     │
+            ╞══════════╡
+            │ false    │
+            └──────────┘
+        """
+        ...
+
     @register_plugin()
     def set_srid(self, srid: IntoIntegerExpr) -> GeoExpr:
         """Set the SRID of each geometry to a given value.
@@ -289,11 +744,53 @@ class GeoExprNameSpace:
         ...
 
     @register_plugin()
-    def to_srid(self, srid: IntoIntegerExpr) -> GeoExpr:
+    def to_srid(
+        self,
+        srid: IntoIntegerExpr,
+        always_xy: bool = True,
+        on_error: Literal["raise", "null"] = "raise",
+    ) -> GeoExpr:
         """Transform the coordinates of each geometry into a new CRS.
 
         Args:
             srid: The srid code of the new CRS
+            always_xy: If True, coordinates of geographic CRS are read and written as
+                (longitude, latitude), whatever the axis order of the CRS. If False,
+                they follow the EPSG axis order, which is (latitude, longitude) for
+                geographic CRS such as EPSG:4326.
+            on_error: What to do when the coordinates of a geometry cannot be
+                transformed: `"raise"` an error with the row index and the coordinate,
+                or return `"null"` for that geometry.
+        """
+        ...
+
+    @register_plugin()
+    def to_crs(self, source: str, target: str, always_xy: bool = True) -> GeoExpr:
+        """Transform the coordinates of each geometry from a CRS to another.
+
+        Unlike [`to_srid`][polars_st.GeoExprNameSpace.to_srid], the SRID stored in the
+        geometries is ignored, which allows reprojecting geometries whose CRS is only
+        known out of band. The SRID of the result is set to the EPSG code of `target`
+        when it has one, or 0 otherwise.
+
+        Args:
+            source: Definition of the current CRS, as an authority string (`"EPSG:4326"`),
+                a WKT string with an EPSG authority, or a proj4 string.
+            target: Definition of the new CRS, in the same formats as `source`.
+            always_xy: See [`to_srid`][polars_st.GeoExprNameSpace.to_srid].
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["POINT (0 0)"])
+            >>> gdf = gdf.select(st.to_crs(source="EPSG:4326", target="+proj=merc +lon_0=0"))
+            >>> gdf.select(st.srid())
+            shape: (1, 1)
+            ┌──────────┐
+            │ geometry │
+            │ ---      │
+            │ i32      │
+            ╞══════════╡
+            │ 0        │
+            └──────────┘
         """
         ...
 
@@ -369,7 +866,70 @@ class GeoExprNameSpace:
         ...
 
     @register_plugin()
-    def to_geojson(self, indent: int | None = None) -> pl.Expr:
+    def wkb_flavor(self) -> pl.Expr:
+        """Return the flavor and byte order of each geometry WKB.
+
+        The encoding is read from the WKB header, without parsing the geometry, and
+        returned as `"{flavor}_{byte_order}"` where flavor is `iso` or `ewkb` and byte
+        order is `ndr` (little endian) or `xdr` (big endian). Geometries without Z, M
+        or SRID are encoded identically in both flavors and are reported as `iso`.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["POINT (0 0)", "POINT Z (0 0 0)"])
+            >>> gdf.select(st.to_wkb(byte_order=0)).select(st.geom().st.wkb_flavor())
+            shape: (2, 1)
+            ┌──────────┐
+            │ geometry │
+            │ ---      │
+            │ str      │
+            ╞══════════╡
+            │ iso_xdr  │
+            │ ewkb_xdr │
+            └──────────┘
+        """
+        ...
+
+    @register_plugin()
+    def normalize_wkb(
+        self,
+        byte_order: Literal[0, 1] = 1,
+        flavor: Literal["iso", "ewkb"] = "ewkb",
+    ) -> pl.Expr:
+        """Rewrite each geometry WKB with a uniform byte order and flavor.
+
+        The WKB is transcoded without being parsed as a geometry, so coordinates are
+        kept exactly. Other functions of this library expect EWKB: use `flavor="iso"`
+        only before writing to formats such as GeoParquet that require ISO WKB.
+
+        Args:
+            byte_order: Use 0 for big endian and 1 for little endian.
+            flavor: Use `"ewkb"` for Extended WKB, which keeps the SRID, or `"iso"` for
+                ISO WKB, which drops it.
+        """
+        ...
+
+    @register_plugin()
+    def to_wkb_base64(self, alphabet: Literal["standard", "url_safe"] = "standard") -> pl.Expr:
+        """Serialize each geometry as base64 encoded EWKB (Extended Well-Known Binary).
+
+        Args:
+            alphabet: Base64 alphabet to use, either the standard one or the URL-safe
+                one using `-` and `_`.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["POINT (1 2)"])
+            >>> gdf.select(st.to_wkb_base64()).item()
+            'AQEAAAAAAAAAAADwPwAAAAAAAABA'
+        """
+        ...
+
+    @register_plugin()
+    def to_geojson(
+        self,
+        indent: int | None = None,
+        output_dimension: Literal[2, 3] = 3,
+        rounding_precision: int | None = None,
+    ) -> pl.Expr:
         """Serialize each geometry as GeoJSON.
 
         Args:
@@ -377,40 +937,163 @@ class GeoExprNameSpace:
                 If indent is not `None`, then GeoJSON will be pretty-printed.
                 An indent level of 0 will only insert newlines. `None` (the default)
                 outputs the most compact representation.
+            output_dimension:
+                The output dimension for the GeoJSON. Specifying 2 drops the Z
+                coordinate of 3D geometries, as some consumers only accept 2D positions.
+            rounding_precision:
+                If not `None`, coordinates are rounded to this number of decimal places.
+                Set to `None` (the default) to write the full precision.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["POINT Z (1 2 3)"])
+            >>> gdf.select(st.to_geojson(output_dimension=2)).item()
+            '{"type":"Point","coordinates":[1.0,2.0]}'
+            >>> gdf = st.GeoDataFrame(["POINT (1.23456789 2.5)"])
+            >>> gdf.select(st.to_geojson(rounding_precision=3)).item()
+            '{"type":"Point","coordinates":[1.235,2.5]}'
         """
         ...
 
-    def to_shapely(self) -> pl.Expr:
-        """Convert each geometry to a Shapely object."""
-        import shapely
+    @register_plugin()
+    def to_kml(self) -> pl.Expr:
+        """Serialize each geometry as a KML geometry element.
 
-        return self._expr.map_batches(
-            lambda s: pl.Series(s.name, shapely.from_wkb(s), dtype=pl.Object()),
-            return_dtype=pl.Object(),
-            is_elementwise=True,
-        )
+        Collections are written as `MultiGeometry`, and M coordinates are dropped.
 
-    def to_dict(self) -> pl.Expr:
-        """Convert each geometry to a GeoJSON-like Python [`dict`][] object."""
-        return self._expr.map_batches(
-            lambda s: pl.Series(s.name, _lib.to_python_dict(s._s), dtype=pl.Object),  # noqa: SLF001
-            return_dtype=pl.Object(),
-            is_elementwise=True,
-        )
+        Examples:
+            >>> gdf = st.GeoDataFrame(["POINT (1 2)", "LINESTRING (0 0, 1 1)"])
+            >>> kml = gdf.select(st.to_kml()).to_series()
+            >>> kml[0]
+            '<Point><coordinates>1,2</coordinates></Point>'
+            >>> kml[1]
+            '<LineString><coordinates>0,0 1,1</coordinates></LineString>'
+        """
+        ...
 
-    def cast(self, into: IntoExprColumn) -> pl.Expr:
-        """Cast each geometry into a different compatible geometry type.
+    @register_plugin()
+    def to_gml(self, version: Literal[2, 3] = 3) -> pl.Expr:
+        """Serialize each geometry as a GML geometry element.
 
-        Valid casts are:
+        The SRID, if set, is written as the `srsName` attribute. M coordinates are dropped.
 
-        | Source          | Destination |
-        |-----------------|-------------|
-        | Point           | MultiPoint  |
-        | MultiPoint      | LineString, CircularString |
-        | LineString      | MultiPoint, CircularString, MultiLineString, MultiCurve |
-        | CircularString  | MultiPoint, LineString, MultiLineString, MultiCurve |
-        | MultiLineString | Polygon |
-        | Polygon         | MultiPolygon, MultiSurface |
+        Args:
+            version: Version of the GML specification to follow.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["SRID=4326;POINT (1 2)"])
+            >>> gdf.select(st.to_gml()).item()
+            '<gml:Point srsName="EPSG:4326"><gml:pos>1 2</gml:pos></gml:Point>'
+            >>> gdf.select(st.to_gml(version=2)).item()
+            '<gml:Point srsName="EPSG:4326"><gml:coordinates>1,2</gml:coordinates></gml:Point>'
+        """
+        ...
+
+    @register_plugin()
+    def to_svg(self, rounding_precision: int | None = None) -> pl.Expr:
+        """Serialize each geometry as an SVG fragment, for quick rendering.
+
+        Points become `<circle>` elements, lines `<polyline>` elements, and polygons
+        `<path>` elements using the even-odd fill rule for holes. Parts of collections
+        are grouped in a `<g>` element. The Y axis is flipped so that geometries render
+        upright. No styling is applied.
+
+        Args:
+            rounding_precision: If not `None`, coordinates are rounded to this number of
+                decimal places.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["POINT (1 2)", "LINESTRING (0 0, 1 1)"])
+            >>> gdf.select(st.to_svg()).to_series().to_list()
+            ['<circle cx="1" cy="-2" r="1"/>', '<polyline fill="none" points="0,0 1,-1"/>']
+        """
+        ...
+
+    @register_plugin()
+    def geohash_encode(self, precision: int = 12, use_centroid: bool = False) -> pl.Expr:
+        """Encode each point as a geohash string.
+
+        Coordinates are expected to be longitudes and latitudes.
+
+        Args:
+            precision: Length of the geohash, between 1 and 12.
+            use_centroid: If `True`, non-point geometries are encoded using their
+                centroid. Otherwise, an error is raised for non-point geometries.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame([
+            ...     "POINT (-5.6 42.6)",
+            ...     "POINT (2.35 48.85)",
+            ... ])
+            >>> gdf.select(st.geohash_encode(precision=5))
+            shape: (2, 1)
+            ┌──────────┐
+            │ geometry │
+            │ ---      │
+            │ str      │
+            ╞══════════╡
+            │ ezs42    │
+            │ u09tv    │
+            └──────────┘
+        """
+        ...
+
+    @register_plugin()
+    def h3_cell(self, resolution: int) -> pl.Expr:
+        """Return the index of the H3 cell containing each geometry.
+
+        Coordinates must be longitudes and latitudes: an error is raised for
+        geometries with an SRID other than 4326 or 0, which should be reprojected
+        first with [`to_srid`][polars_st.GeoExprNameSpace.to_srid]. Non-point
+        geometries are indexed using their centroid.
+
+        Args:
+            resolution: H3 resolution, between 0 and 15.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["POINT (-122.0553238 37.3615593)"])
+            >>> gdf.select(st.h3_cell(resolution=7).map_elements(hex, pl.String))
+            shape: (1, 1)
+            ┌───────────────────┐
+            │ geometry          │
+            │ ---               │
+            │ str               │
+            ╞═══════════════════╡
+            │ 0x872830828ffffff │
+            └───────────────────┘
+        """
+        ...
+
+    def to_shapely(self) -> pl.Expr:
+        """Convert each geometry to a Shapely object."""
+        import shapely
+
+        return self._expr.map_batches(
+            lambda s: pl.Series(s.name, shapely.from_wkb(s), dtype=pl.Object()),
+            return_dtype=pl.Object(),
+            is_elementwise=True,
+        )
+
+    def to_dict(self) -> pl.Expr:
+        """Convert each geometry to a GeoJSON-like Python [`dict`][] object."""
+        return self._expr.map_batches(
+            lambda s: pl.Series(s.name, _lib.to_python_dict(s._s), dtype=pl.Object),  # noqa: SLF001
+            return_dtype=pl.Object(),
+            is_elementwise=True,
+        )
+
+    def cast(self, into: IntoExprColumn) -> pl.Expr:
+        """Cast each geometry into a different compatible geometry type.
+
+        Valid casts are:
+
+        | Source          | Destination |
+        |-----------------|-------------|
+        | Point           | MultiPoint  |
+        | MultiPoint      | LineString, CircularString |
+        | LineString      | MultiPoint, CircularString, MultiLineString, MultiCurve |
+        | CircularString  | MultiPoint, LineString, MultiLineString, MultiCurve |
+        | MultiLineString | Polygon |
+        | Polygon         | MultiPolygon, MultiSurface |
         | CurvePolygon    | MultiSurface |
         | Any             | GeometryCollection |
         """
@@ -441,7 +1124,10 @@ class GeoExprNameSpace:
 
     @register_plugin()
     def is_ccw(self) -> pl.Expr:
-        """Return `True` for linear geometries with counter-clockwise coord sequence."""
+        """Return `True` for linear geometries with counter-clockwise coord sequence.
+
+        Polygons are tested on their exterior ring.
+        """
         ...
 
     @register_plugin()
@@ -474,6 +1160,33 @@ class GeoExprNameSpace:
         """Return an explanation string for the invalidity of each geometry."""
         ...
 
+    @register_plugin()
+    def is_valid_detail(self) -> pl.Expr:
+        """Return the validity of each geometry, with the reason and location of invalidity.
+
+        The result is a struct with the validity as `is_valid`, the explanation as `reason`
+        and a Point at the location of the first invalidity found as `location`. The reason
+        and location are null for valid geometries.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame([
+            ...     "POLYGON ((0 0, 2 2, 2 0, 0 2, 0 0))",
+            ...     "POLYGON ((0 0, 1 0, 1 1, 0 0))",
+            ... ])
+            >>> detail = gdf.select(st.geom().st.is_valid_detail()).unnest("geometry")
+            >>> detail.with_columns(st.geom("location").st.to_wkt())
+            shape: (2, 3)
+            ┌──────────┬───────────────────┬─────────────┐
+            │ is_valid ┆ reason            ┆ location    │
+            │ ---      ┆ ---               ┆ ---         │
+            │ bool     ┆ str               ┆ str         │
+            ╞══════════╪═══════════════════╪═════════════╡
+            │ false    ┆ Self-intersection ┆ POINT (1 1) │
+            │ true     ┆ null              ┆ null        │
+            └──────────┴───────────────────┴─────────────┘
+        """
+        ...
+
     # Binary predicates
 
     @register_plugin()
@@ -486,6 +1199,36 @@ class GeoExprNameSpace:
         """Return `True` when each geometry contains other."""
         ...
 
+    @register_plugin()
+    def contains_points(self, points: IntoGeoExprColumn) -> pl.Expr:
+        """Return `True` when each geometry contains the point on the same row.
+
+        Each distinct geometry is prepared once and tested against all of its points, which
+        is faster than [`contains`][polars_st.GeoExprNameSpace.contains] when polygons are
+        repeated across rows, such as after a join.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame({
+            ...     "polygon": ["POLYGON ((0 0, 2 0, 2 2, 0 2, 0 0))"] * 3,
+            ...     "point": ["POINT (1 1)", "POINT (2 1)", "POINT (3 3)"],
+            ... }, geometry_name="polygon").with_columns(st.from_wkt("point"))
+            >>> gdf.select(
+            ...     contains=st.geom("polygon").st.contains_points(st.geom("point")),
+            ...     intersects=st.geom("polygon").st.intersects_points(st.geom("point")),
+            ... )
+            shape: (3, 2)
+            ┌──────────┬────────────┐
+            │ contains ┆ intersects │
+            │ ---      ┆ ---        │
+            │ bool     ┆ bool       │
+            ╞══════════╪════════════╡
+            │ true     ┆ true       │
+            │ false    ┆ true       │
+            │ false    ┆ false      │
+            └──────────┴────────────┘
+        """
+        ...
+
     @register_plugin()
     def contains_properly(self, other: IntoGeoExprColumn) -> pl.Expr:
         """Return `True` when each geometry properly contains other."""
@@ -516,6 +1259,15 @@ class GeoExprNameSpace:
         """Return `True` when each geometry intersects other."""
         ...
 
+    @register_plugin()
+    def intersects_points(self, points: IntoGeoExprColumn) -> pl.Expr:
+        """Return `True` when each geometry intersects the point on the same row.
+
+        Like [`contains_points`][polars_st.GeoExprNameSpace.contains_points], each distinct
+        geometry is prepared once.
+        """
+        ...
+
     @register_plugin()
     def overlaps(self, other: IntoGeoExprColumn) -> pl.Expr:
         """Return `True` when each geometry overlaps other."""
@@ -540,9 +1292,15 @@ class GeoExprNameSpace:
     def equals_exact(
         self,
         other: IntoGeoExprColumn,
-        tolerance: float = 0.0,
+        tolerance: IntoNumericExpr = 0.0,
     ) -> pl.Expr:
-        """Return `True` when each geometry is equal to other."""
+        """Return `True` when each geometry is equal to other.
+
+        Args:
+            other: Geometries to compare to.
+            tolerance: Maximum distance between matching vertices, either a scalar or
+                an expression for a different tolerance on each row.
+        """
         ...
 
     @register_plugin()
@@ -551,17 +1309,103 @@ class GeoExprNameSpace:
         ...
 
     @register_plugin()
-    def relate(self, other: IntoGeoExprColumn) -> pl.Expr:
-        """Return the DE-9IM intersection matrix of each geometry with other."""
+    def relate(
+        self,
+        other: IntoGeoExprColumn,
+        boundary_node_rule: Literal[
+            "mod2",
+            "endpoint",
+            "multivalent_endpoint",
+            "monovalent_endpoint",
+        ] = "mod2",
+    ) -> pl.Expr:
+        """Return the DE-9IM intersection matrix of each geometry with other.
+
+        Args:
+            other: Geometries to relate to.
+            boundary_node_rule: Rule deciding which endpoints of lines are on their
+                boundary, which changes the matrix of self-touching or connected lines:
+
+                - `"mod2"`: endpoints shared by an odd number of lines, as specified
+                    by OGC. This is the default.
+                - `"endpoint"`: all endpoints.
+                - `"multivalent_endpoint"`: endpoints shared by more than one line.
+                - `"monovalent_endpoint"`: endpoints not shared with another line.
+        """
         ...
 
-    @register_plugin()
     def relate_pattern(
         self,
         other: IntoGeoExprColumn,
-        pattern: str,
+        pattern: str | pl.Expr | pl.Series,
+        boundary_node_rule: Literal[
+            "mod2",
+            "endpoint",
+            "multivalent_endpoint",
+            "monovalent_endpoint",
+        ] = "mod2",
     ) -> pl.Expr:
-        """Return `True` when the DE-9IM intersection matrix of geometry with other matches a given pattern."""  # noqa: E501
+        """Return `True` when the DE-9IM matrix of geometry with other matches a given pattern.
+
+        Args:
+            other: Geometries to relate to.
+            pattern: DE-9IM pattern of 9 characters among `T`, `F`, `*`, `0`, `1`
+                and `2`, in any case. A string is used as the pattern of every row, while an
+                expression, such as `pl.col("pattern")`, gives a pattern for each row.
+                Invalid patterns raise an error with the index of the row.
+            boundary_node_rule: Rule deciding which endpoints of lines are on their
+                boundary, see [`relate`][polars_st.GeoExprNameSpace.relate].
+        """
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="relate_pattern",
+            args=[self._expr, other, pl.lit(pattern) if isinstance(pattern, str) else pattern],
+            kwargs={"boundary_node_rule": boundary_node_rule},
+            is_elementwise=True,
+        )
+
+    def adjacent(self, other: IntoGeoExprColumn) -> pl.Expr:
+        """Return `True` when each geometry shares a boundary segment with other.
+
+        The interiors must not intersect and the boundaries must intersect along a line. Equivalent
+        to `relate_pattern(other, "F***1****")`.
+        """
+        return self.relate_pattern(other, "F***1****")
+
+    def touches_at_point(self, other: IntoGeoExprColumn) -> pl.Expr:
+        """Return `True` when the boundaries of each geometry and other only meet at points.
+
+        The interiors must not intersect and the boundaries must intersect at points only.
+        Equivalent to `relate_pattern(other, "F***0****")`.
+        """
+        return self.relate_pattern(other, "F***0****")
+
+    def interiors_intersect(self, other: IntoGeoExprColumn) -> pl.Expr:
+        """Return `True` when the interiors of each geometry and other intersect.
+
+        Unlike `intersects`, geometries that only touch on their boundaries return `False`.
+        Equivalent to `relate_pattern(other, "T********")`.
+        """
+        return self.relate_pattern(other, "T********")
+
+    def contains_boundary(self, other: IntoGeoExprColumn) -> pl.Expr:
+        """Return `True` when the boundary of other lies within each geometry.
+
+        The boundary of other must intersect the interior of geometry and must not reach its
+        exterior. Equivalent to `relate_pattern(other, "*T*****F*")`.
+        """
+        return self.relate_pattern(other, "*T*****F*")
+
+    @register_plugin()
+    def equal_boundary(self, other: IntoGeoExprColumn) -> pl.Expr:
+        """Return `True` when the boundary of each geometry is equal to the boundary of other.
+
+        Boundaries are compared topologically, as with
+        [`equals`][polars_st.GeoExprNameSpace.equals]. A polygon and its exterior ring
+        given as a closed line differ, since a closed line has an empty boundary.
+        Geometry collections are given an empty boundary. Unlike the other named
+        predicates, no single DE-9IM mask of the geometries expresses this relation.
+        """
         ...
 
     # Set operations
@@ -581,6 +1425,49 @@ class GeoExprNameSpace:
         """Return the coverage union of each geometry with other."""
         ...
 
+    @register_plugin()
+    def coverage_is_valid(self, gap_width: float = 0.0) -> pl.Expr:
+        """Return `True` when the parts of each collection form a valid polygonal coverage.
+
+        A valid coverage is a set of polygons that don't overlap, and whose shared edges
+        have exactly the same vertices. This uses the GEOS coverage validator
+        (`GEOSCoverageIsValid`).
+
+        Args:
+            gap_width: Maximum width of gaps between polygons to detect. Gaps narrower
+                than this make the coverage invalid. The default of 0 disables gap
+                detection.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame([
+            ...     "GEOMETRYCOLLECTION (POLYGON ((0 0, 1 0, 1 1, 0 1, 0 0)),"
+            ...     " POLYGON ((1 0, 2 0, 2 1, 1 1, 1 0)))",
+            ...     "GEOMETRYCOLLECTION (POLYGON ((0 0, 1 0, 1 1, 0 1, 0 0)),"
+            ...     " POLYGON ((0.5 0, 2 0, 2 1, 0.5 1, 0.5 0)))",
+            ... ])
+            >>> gdf.select(st.coverage_is_valid()).to_series().to_list()
+            [True, False]
+        """
+        ...
+
+    @register_plugin()
+    def coverage_simplify(self, tolerance: float, simplify_boundary: bool = True) -> GeoExpr:
+        """Simplify the parts of each collection as a polygonal coverage.
+
+        Shared edges are simplified consistently, so that adjacent polygons don't develop
+        gaps or overlaps. The parts of each collection must form a valid coverage (see
+        [`coverage_is_valid`][polars_st.GeoExprNameSpace.coverage_is_valid]), otherwise
+        the result is undefined. This uses the GEOS coverage simplifier
+        (`GEOSCoverageSimplifyVW`), based on the Visvalingam-Whyatt algorithm.
+
+        Args:
+            tolerance: Simplification tolerance, as the square root of the area of the
+                triangles removed by the Visvalingam-Whyatt algorithm.
+            simplify_boundary: If `False`, the outer boundary of the coverage is kept
+                unchanged, and only edges shared between polygons are simplified.
+        """
+        ...
+
     @register_plugin()
     def intersection(
         self,
@@ -611,8 +1498,13 @@ class GeoExprNameSpace:
     # Constructive operations
 
     @register_plugin()
-    def boundary(self) -> GeoExpr:
-        """Return the topological boundary of each geometry."""
+    def boundary(self, grid_size: float | None = None) -> GeoExpr:
+        """Return the topological boundary of each geometry.
+
+        Args:
+            grid_size: If set, the boundary is rounded to a grid of this size, which may
+                merge boundary vertices closer than a grid cell.
+        """
         ...
 
     @register_plugin()
@@ -624,10 +1516,94 @@ class GeoExprNameSpace:
         join_style: Literal["round", "mitre", "bevel"] = "round",
         mitre_limit: float = 5.0,
         single_sided: bool = False,
+        on_empty: Literal["empty", "null", "original"] = "empty",
+        output_precision: float | None = None,
     ) -> GeoExpr:
-        """Return a buffer around each geometry."""
+        """Return a buffer around each geometry.
+
+        Negative distances erode geometries and are only supported on polygonal
+        inputs: a non-empty point or line buffered by a negative distance raises
+        an error.
+
+        Args:
+            distance: Buffer distance. Negative values shrink polygons.
+            quad_segs: Number of segments used to approximate a quarter circle.
+            cap_style: Style of the line endings.
+            join_style: Style of the line joins.
+            mitre_limit: Limit on the mitre ratio used for very sharp corners.
+            single_sided: Only buffer on one side of each line.
+            on_empty: What to return when a negative buffer erodes a geometry
+                entirely: an empty polygon (`"empty"`), null (`"null"`), or the
+                input geometry unchanged (`"original"`). No warning is emitted, as the
+                buffer runs in the plugin without access to the Python interpreter:
+                use `"null"` and `is_null` to find the eroded rows.
+            output_precision: If set, the buffer is snapped to a grid of that size,
+                keeping the output compact. Precision is reduced with the
+                `"valid_output"` mode of
+                [`set_precision`][polars_st.GeoExprNameSpace.set_precision], which
+                may collapse narrow parts of the buffer.
+
+        Examples:
+            >>> df = pl.select(geometry=st.from_wkt(pl.lit("POLYGON ((0 0, 1 0, 1 1, 0 1, 0 0))")))
+            >>> df.select(st.buffer(distance=-1.0, on_empty="null"))
+            shape: (1, 1)
+            ┌──────────┐
+            │ geometry │
+            │ ---      │
+            │ binary   │
+            ╞══════════╡
+            │ null     │
+            └──────────┘
+        """
         ...
 
+    def buffer_with_columns(
+        self,
+        distance: IntoNumericExpr,
+        quad_segs: IntoIntegerExpr = 8,
+        cap_style: IntoExprColumn | None = None,
+        join_style: IntoExprColumn | None = None,
+        mitre_limit: IntoNumericExpr = 5.0,
+    ) -> GeoExpr:
+        """Return a buffer around each geometry, with buffer parameters read per row.
+
+        Unlike [`buffer`][polars_st.GeoExprNameSpace.buffer], whose parameters are
+        shared by all rows, every parameter can be an expression, for example to use
+        different cap styles for different feature classes. Strings are column names:
+        use `pl.lit` for constant styles. Rows where any parameter is null return null.
+
+        Args:
+            distance: Buffer distance. Negative values shrink polygons.
+            quad_segs: Number of segments used to approximate a quarter circle.
+            cap_style: Style of the line endings, one of `"round"`, `"square"` or
+                `"flat"`. Defaults to `"round"`.
+            join_style: Style of the line joins, one of `"round"`, `"mitre"` or
+                `"bevel"`. Defaults to `"round"`.
+            mitre_limit: Limit on the mitre ratio used for very sharp corners.
+
+        Examples:
+            >>> df = st.GeoDataFrame({
+            ...     "geometry": ["LINESTRING (0 0, 2 0)", "LINESTRING (0 0, 2 0)"],
+            ...     "cap": ["flat", "square"],
+            ... })
+            >>> buffers = df.select(st.buffer_with_columns(distance=1.0, cap_style="cap"))
+            >>> buffers.select(st.area()).to_series().to_list()
+            [4.0, 8.0]
+        """
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="buffer_with_columns",
+            args=[
+                self._expr,
+                distance,
+                quad_segs,
+                pl.lit("round") if cap_style is None else cap_style,
+                pl.lit("round") if join_style is None else join_style,
+                mitre_limit,
+            ],
+            is_elementwise=True,
+        ).pipe(lambda e: cast("GeoExpr", e))
+
     @register_plugin()
     def offset_curve(
         self,
@@ -635,8 +1611,30 @@ class GeoExprNameSpace:
         quad_segs: int = 8,
         join_style: Literal["round", "mitre", "bevel"] = "round",
         mitre_limit: float = 5.0,
+        boundary: bool = False,
+        single_sided: bool = False,
     ) -> GeoExpr:
-        """Return a line at a given distance of each geometry."""
+        """Return a line at a given distance of each geometry.
+
+        The offset curve is only defined for linear geometries: points and areal
+        geometries return null.
+
+        The sign of `distance` selects the side, relative to the direction of the
+        line: positive values are on its left, negative values on its right. Use
+        [`reverse`][polars_st.GeoExprNameSpace.reverse] to swap sides.
+
+        Args:
+            distance: Offset distance. Positive values offset to the left of the
+                line, negative values to the right.
+            quad_segs: Number of segments used to approximate a quarter circle.
+            join_style: Style of the line joins.
+            mitre_limit: Limit on the mitre ratio used for very sharp corners.
+            boundary: If True, areal geometries are offset along their boundary
+                instead of returning null.
+            single_sided: If True, return the polygon between the line and its offset
+                curve, like a buffer on a single side of the line with flat ends,
+                instead of the offset curve itself.
+        """
         ...
 
     @register_plugin()
@@ -644,14 +1642,75 @@ class GeoExprNameSpace:
         """Return the centroid of each geometry."""
         ...
 
+    @register_plugin()
+    def largest_part_centroid(self) -> GeoExpr:
+        """Return the centroid of the largest part of each geometry.
+
+        The largest part is the one with the highest dimension, then the largest area
+        for polygons or length for lines. Parts of nested collections are considered
+        individually. This is useful for label placement on multi-part geometries, where
+        the centroid of the whole geometry may fall between its parts. Empty geometries
+        return an empty point.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame([
+            ...     "MULTIPOLYGON (((0 0, 4 0, 4 4, 0 4, 0 0)), ((10 0, 11 0, 11 1, 10 1, 10 0)))",
+            ... ])
+            >>> gdf.select(st.largest_part_centroid().st.to_wkt())
+            shape: (1, 1)
+            ┌─────────────┐
+            │ geometry    │
+            │ ---         │
+            │ str         │
+            ╞═════════════╡
+            │ POINT (2 2) │
+            └─────────────┘
+        """
+        ...
+
     @register_plugin()
     def center(self) -> GeoExpr:
         """Return the bounding box center of each geometry."""
         ...
 
     @register_plugin()
-    def clip_by_rect(self, bounds: IntoExprColumn) -> GeoExpr:
-        """Clips each geometry by a bounding rectangle."""
+    def clip_by_rect(self, bounds: IntoExprColumn, grid_size: float | None = None) -> GeoExpr:
+        """Clips each geometry by a bounding rectangle.
+
+        Args:
+            bounds: Bounds of the rectangle, as `[xmin, ymin, xmax, ymax]`.
+            grid_size: If set, the clipped geometry is rounded to a grid of this size,
+                and slivers along the rectangle narrower than a grid cell collapse.
+        """
+        ...
+
+    @register_plugin()
+    def clip(self, mask: IntoGeoExprColumn) -> GeoExpr:
+        """Clip each geometry by a mask geometry.
+
+        Unlike [`intersection`][polars_st.GeoExprNameSpace.intersection], the result
+        keeps the dimension of the input: the points and lines created where a polygon
+        only touches the mask are dropped. GeometryCollection members are clipped one
+        by one and members falling outside the mask are removed. When `mask` is a
+        single geometry, it is only prepared once.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame([
+            ...     "POLYGON ((0 0, 2 0, 2 2, 0 2, 0 0))",
+            ...     "POLYGON ((1 0, 2 0, 2 1, 1 1, 1 0))",
+            ... ])
+            >>> mask = st.from_wkt(pl.lit("POLYGON ((-1 -1, 1 -1, 1 1, -1 1, -1 -1))"))
+            >>> gdf.select(st.geom().st.clip(mask).st.area())
+            shape: (2, 1)
+            ┌──────────┐
+            │ geometry │
+            │ ---      │
+            │ f64      │
+            ╞══════════╡
+            │ 1.0      │
+            │ 0.0      │
+            └──────────┘
+        """
         ...
 
     @register_plugin()
@@ -661,89 +1720,599 @@ class GeoExprNameSpace:
 
     @register_plugin()
     def concave_hull(self, ratio: float = 0.0, allow_holes: bool = False) -> GeoExpr:
-        """Return the concave hull of each geometry."""
-        ...
+        """Return the concave hull of each geometry.
 
-    @register_plugin()
-    def segmentize(self, max_segment_length: IntoNumericExpr) -> GeoExpr: ...
+        Geometries with fewer than three non-collinear points return their convex
+        hull, which is a Point, a LineString or an empty geometry.
 
-    @register_plugin()
-    def envelope(self) -> GeoExpr:
-        """Return the envelope of each geometry."""
+        Args:
+            ratio: Maximum edge length of the hull, as a fraction between 0 and 1 of the
+                range between the shortest and longest edges of the Delaunay
+                triangulation of the input. 1 gives the convex hull.
+            allow_holes: Whether the hull may contain holes.
+        """
         ...
 
     @register_plugin()
-    def extract_unique_points(self) -> GeoExpr: ...
+    def concave_hull_by_length(
+        self,
+        max_length: IntoNumericExpr,
+        allow_holes: bool = False,
+    ) -> GeoExpr:
+        """Return the concave hull of each geometry, with an absolute maximum edge length.
 
-    @register_plugin()
-    def build_area(self) -> GeoExpr: ...
+        Unlike [`concave_hull`][polars_st.GeoExprNameSpace.concave_hull], whose ratio
+        is relative to the edge lengths of each geometry, `max_length` is expressed in
+        the units of the coordinates, so the same value gives comparable detail across
+        geometries of different sizes. A length greater than the longest edge of the
+        convex hull gives the convex hull.
 
-    @register_plugin()
-    def make_valid(self) -> GeoExpr: ...
+        Geometries with fewer than three non-collinear points return their convex
+        hull, which is a Point, a LineString or an empty geometry.
 
-    @register_plugin()
-    def normalize(self) -> GeoExpr: ...
+        Args:
+            max_length: Maximum length of the edges of the hull.
+            allow_holes: Whether the hull may contain holes.
+        """
+        ...
 
     @register_plugin()
-    def node(self) -> GeoExpr: ...
+    def segmentize(self, max_segment_length: IntoNumericExpr) -> GeoExpr:
+        """Add vertices so that no segment of each geometry is longer than a given length.
 
-    @register_plugin()
-    def point_on_surface(self) -> GeoExpr:
-        """Return a point that intersects of each geometry."""
-        ...
+        Each segment is split into equal pieces. Z and M values of the new vertices are
+        interpolated linearly, and points are returned unchanged. Curves are returned as
+        linear geometries, with each arc sampled at even angles.
 
-    @register_plugin()
-    def remove_repeated_points(self, tolerance: IntoNumericExpr = 0.0) -> GeoExpr:
-        """Remove the repeated points for each geometry."""
-        ...
+        Args:
+            max_segment_length: Maximum length of the segments, measured in XY. Must be
+                greater than 0.
 
-    @register_plugin()
-    def reverse(self) -> GeoExpr:
-        """Reverse the coordinates order of each geometry."""
+        Examples:
+            >>> gdf = st.GeoDataFrame(["LINESTRING Z (0 0 0, 3 0 6)"])
+            >>> gdf.select(st.segmentize(max_segment_length=1.0)).st.to_wkt()
+            shape: (1, 1)
+            ┌─────────────────────────────────┐
+            │ geometry                        │
+            │ ---                             │
+            │ str                             │
+            ╞═════════════════════════════════╡
+            │ LINESTRING Z (0 0 0, 1 0 2, 2 … │
+            └─────────────────────────────────┘
+        """
         ...
 
     @register_plugin()
-    def simplify(
-        self,
-        tolerance: IntoNumericExpr,
-        preserve_topology: bool = True,
-    ) -> GeoExpr:
-        """Simplify each geometry with a given tolerance."""
-        ...
+    def densify_geodesic(self, max_segment_length: IntoNumericExpr) -> GeoExpr:
+        """Add vertices along the geodesic so that no segment is longer than a given length.
 
-    @register_plugin()
-    def force_2d(self) -> GeoExpr:
-        """Force the dimensionality of a geometry to 2D."""
-        ...
+        Unlike [`segmentize`][polars_st.GeoExprNameSpace.segmentize], which interpolates
+        in the plane, the new vertices lie on the shortest path on the ellipsoid of the
+        CRS, so that long edges follow the great circle. Coordinates are read as
+        (longitude, latitude) in degrees, and geometries must have a geographic SRID such
+        as EPSG:4326. Z and M values are interpolated linearly.
 
-    @register_plugin()
-    def force_3d(self, z: IntoNumericExpr = 0.0) -> GeoExpr:
-        """Force the dimensionality of a geometry to 3D."""
+        Args:
+            max_segment_length: Maximum length of the segments, in meters. Must be greater
+                than 0.
+        """
         ...
 
     @register_plugin()
-    def flip_coordinates(self) -> GeoExpr:
-        """Flip the x and y coordinates of each geometry."""
-        ...
+    def subdivide(self, max_vertices: int = 256) -> pl.Expr:
+        """Split each geometry into a list of pieces with a limited number of vertices.
+
+        Like PostGIS `ST_Subdivide`, geometries are recursively clipped by the halves of
+        their bounding box, cut across its longest side, until every piece has at most
+        `max_vertices` vertices. Collections are subdivided member by member. Smaller
+        pieces have tighter bounding boxes, which makes spatial indexes, such as the one
+        used by [`sjoin`][polars_st.GeoDataFrameNameSpace.sjoin], much more selective.
+
+        Empty geometries produce an empty list.
+
+        Args:
+            max_vertices: Maximum number of vertices of each piece, at least 5.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame([
+            ...     "LINESTRING (0 0, 1 0, 2 0, 3 0, 4 0, 5 0, 6 0, 7 0)",
+            ...     "POINT EMPTY",
+            ... ])
+            >>> gdf.select(st.geom().st.subdivide(5).list.len())
+            shape: (2, 1)
+            ┌──────────┐
+            │ geometry │
+            │ ---      │
+            │ u32      │
+            ╞══════════╡
+            │ 2        │
+            │ 0        │
+            └──────────┘
+        """
+        ...
+
+    @register_plugin()
+    def envelope(self) -> GeoExpr:
+        """Return the envelope of each geometry."""
+        ...
+
+    @register_plugin()
+    def extract_unique_points(self, tolerance: IntoNumericExpr | None = None) -> GeoExpr:
+        """Return the unique vertices of each geometry as a MultiPoint.
+
+        Args:
+            tolerance: If set, coordinates are first snapped to a grid of this size, so
+                that vertices differing only by floating point noise are merged. Can be
+                a column to use a different tolerance for each geometry. Null values
+                keep the exact coordinates.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["LINESTRING (0 0, 1 1, 0.0001 0, 1 1.0002)"])
+            >>> gdf.select(st.extract_unique_points().st.count_geometries()).item()
+            4
+            >>> gdf.select(st.extract_unique_points(tolerance=0.01).st.to_wkt()).item()
+            'MULTIPOINT ((0 0), (1 1))'
+        """
+        ...
+
+    @register_plugin()
+    def build_area(self) -> GeoExpr: ...
+
+    @register_plugin()
+    def build_area_full(self) -> pl.Expr:
+        """Build the area formed by the linework of each geometry, with the edges that
+        don't bound it.
+
+        The result is a struct with the `area` returned by
+        [`build_area`][polars_st.GeoExprNameSpace.build_area] and the `remainder` of
+        the linework, noded, that is not part of its boundary. Dangling edges, gaps in
+        rings and edges crossing the area all end up in the remainder, which is empty
+        when every edge was used. Null geometries give a null struct.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame([
+            ...     "MULTILINESTRING ((0 0, 1 0, 1 1, 0 1, 0 0), (1 1, 2 2))",
+            ...     "LINESTRING (0 0, 1 0, 1 1, 0 1, 0 0)",
+            ... ])
+            >>> result = gdf.select(st.geom().st.build_area_full()).unnest("geometry")
+            >>> result.select(st.geom("area").st.area(), st.geom("remainder").st.length())
+            shape: (2, 2)
+            ┌──────┬───────────┐
+            │ area ┆ remainder │
+            │ ---  ┆ ---       │
+            │ f64  ┆ f64       │
+            ╞══════╪═══════════╡
+            │ 1.0  ┆ 1.414214  │
+            │ 1.0  ┆ 0.0       │
+            └──────┴───────────┘
+        """
+        ...
+
+    @register_plugin()
+    def make_valid(
+        self,
+        method: Literal["linework", "structure"] = "linework",
+        keep_collapsed: bool = True,
+    ) -> GeoExpr:
+        """Repair invalid geometries.
+
+        Args:
+            method: Algorithm used to repair geometries:
+
+                - `"linework"`: build valid geometries from all the edges of the input,
+                    keeping every vertex.
+                - `"structure"`: rebuild polygons from their rings, treating holes and
+                    shells according to the structure of the input. This often gives
+                    cleaner results for polygons.
+            keep_collapsed: With the `"structure"` method, whether to keep components
+                that collapsed to a lower dimension, such as a polygon with no area.
+                Ignored by the `"linework"` method.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["POLYGON ((0 0, 1 1, 1 2, 1 1, 0 0))"])
+            >>> gdf.select(st.make_valid().st.to_wkt()).item()
+            'MULTILINESTRING ((0 0, 1 1), (1 1, 1 2))'
+            >>> repaired = st.make_valid(method="structure", keep_collapsed=False)
+            >>> gdf.select(repaired.st.to_wkt()).item()
+            'POLYGON EMPTY'
+        """
+        ...
+
+    @register_plugin()
+    def normalize(self) -> GeoExpr: ...
+
+    @register_plugin()
+    def node(self) -> GeoExpr: ...
+
+    @register_plugin()
+    def node_prec(self, grid_size: IntoNumericExpr) -> GeoExpr:
+        """Snap each geometry to a grid, then node its linework.
+
+        Vertices closer than the grid size are merged before noding, so nearly
+        coincident lines share their nodes instead of creating slivers. This is
+        equivalent to [`set_precision`][polars_st.GeoExprNameSpace.set_precision]
+        with the `"valid_output"` mode followed by
+        [`node`][polars_st.GeoExprNameSpace.node].
+
+        Snapping requires valid input: call
+        [`make_valid`][polars_st.GeoExprNameSpace.make_valid] first on invalid
+        polygons. The result is linework, so it does not need to be repaired afterwards.
+
+        Args:
+            grid_size: Size of the precision grid, can be a column to use a different
+                size for each geometry.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["MULTILINESTRING ((0 0, 2 0), (1 0.001, 1 1))"])
+            >>> gdf.select(st.node().st.count_geometries()).item()
+            2
+            >>> gdf.select(st.node_prec(grid_size=0.01).st.count_geometries()).item()
+            3
+        """
+        ...
+
+    @register_plugin()
+    def point_on_surface(self) -> GeoExpr:
+        """Return a point that intersects of each geometry."""
+        ...
+
+    @register_plugin()
+    def points_on_surface(self) -> pl.Expr:
+        """Return a point that intersects each part of each geometry.
+
+        Multi-part geometries and collections give one point per non-empty part,
+        recursing into nested collections, e.g. to label each island of an archipelago.
+        Other geometries give a single point.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame([
+            ...     "MULTIPOLYGON (((0 0, 2 0, 2 2, 0 2, 0 0)), ((4 0, 6 0, 6 2, 4 2, 4 0)))",
+            ... ])
+            >>> gdf.select(st.points_on_surface()).explode("geometry").select(st.to_wkt())
+            shape: (2, 1)
+            ┌─────────────┐
+            │ geometry    │
+            │ ---         │
+            │ str         │
+            ╞═════════════╡
+            │ POINT (1 1) │
+            │ POINT (5 1) │
+            └─────────────┘
+        """
+        ...
+
+    @register_plugin()
+    def remove_holes(self) -> GeoExpr:
+        """Remove the interior rings of each polygon.
+
+        Multipolygons and geometry collections are processed part by part, other
+        geometries are returned unchanged.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame([
+            ...     "POLYGON ((0 0, 4 0, 4 4, 0 4, 0 0), (1 1, 2 1, 2 2, 1 1))",
+            ... ])
+            >>> gdf.select(area=st.area(), filled=st.remove_holes().st.area())
+            shape: (1, 2)
+            ┌──────┬────────┐
+            │ area ┆ filled │
+            │ ---  ┆ ---    │
+            │ f64  ┆ f64    │
+            ╞══════╪════════╡
+            │ 15.5 ┆ 16.0   │
+            └──────┴────────┘
+        """
+        ...
+
+    @register_plugin()
+    def orient(self, exterior_cw: bool = False) -> GeoExpr:
+        """Enforce the winding order of each polygon rings.
+
+        Interior rings are wound opposite to the exterior ring. Multipolygons and
+        geometry collections are processed part by part, other geometries are returned
+        unchanged.
+
+        Args:
+            exterior_cw: If `False` (the default), exterior rings are made
+                counter-clockwise as mandated by the GeoJSON right-hand rule. If `True`,
+                they are made clockwise as in the Shapefile format.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["POLYGON ((0 0, 0 1, 1 1, 1 0, 0 0))"])
+            >>> gdf.select(before=st.is_ccw(), after=st.orient().st.is_ccw())
+            shape: (1, 2)
+            ┌────────┬───────┐
+            │ before ┆ after │
+            │ ---    ┆ ---   │
+            │ bool   ┆ bool  │
+            ╞════════╪═══════╡
+            │ false  ┆ true  │
+            └────────┴───────┘
+        """
+        ...
+
+    @register_plugin()
+    def remove_repeated_points(self, tolerance: IntoNumericExpr = 0.0) -> GeoExpr:
+        """Remove the repeated points for each geometry."""
+        ...
+
+    @register_plugin()
+    def reverse(self, lines_only: bool = False) -> GeoExpr:
+        """Reverse the coordinates order of each geometry.
+
+        By default, the rings of polygons are reversed too, which flips their winding
+        order.
+
+        Args:
+            lines_only: If True, only linear components are reversed: points and
+                polygons are returned unchanged, including inside geometry collections.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame([
+            ...     "GEOMETRYCOLLECTION (LINESTRING (0 0, 1 0), POLYGON ((0 0, 1 0, 1 1, 0 0)))",
+            ... ])
+            >>> gdf.select(st.reverse(lines_only=True).st.to_wkt()).item()
+            'GEOMETRYCOLLECTION (LINESTRING (1 0, 0 0), POLYGON ((0 0, 1 0, 1 1, 0 0)))'
+        """
+        ...
+
+    @register_plugin()
+    def simplify(
+        self,
+        tolerance: IntoNumericExpr,
+        preserve_topology: bool = True,
+        preserve_endpoints: bool = False,
+    ) -> GeoExpr:
+        """Simplify each geometry with a given tolerance.
+
+        Args:
+            tolerance: The maximum allowed geometry displacement.
+            preserve_topology: If True, use a topology preserving algorithm that
+                prevents the creation of invalid geometries.
+            preserve_endpoints: If True, the first and last coordinates of lines and the
+                start vertex of polygon rings are kept in place, so that connected
+                networks stay connected after simplification. GEOS already keeps the
+                endpoints of open lines, but may drop the start vertex of a ring.
+                Geometries that lost parts or holes are returned unpinned.
+        """
+        ...
+
+    @register_plugin()
+    def simplify_vw(
+        self,
+        tolerance: IntoNumericExpr,
+        preserve_topology: bool = True,
+    ) -> GeoExpr:
+        """Simplify each geometry with the Visvalingam-Whyatt algorithm.
+
+        Vertices are removed in order of the area of the triangle they form with their
+        neighbours, until all remaining triangles are at least `tolerance` large. Line
+        endpoints are kept, and polygon rings stay closed with at least 4 points.
+
+        Args:
+            tolerance: The minimum effective area of kept vertices.
+            preserve_topology: If True, vertices whose removal would make a line or ring
+                self-intersect, or cross another ring or part of the same geometry, are
+                kept.
+        """
+        ...
+
+    @register_plugin()
+    def force_2d(self) -> GeoExpr:
+        """Force the dimensionality of a geometry to 2D."""
+        ...
+
+    @register_plugin()
+    def force_3d(self, z: IntoNumericExpr = 0.0, fill_nan: bool = False) -> GeoExpr:
+        """Force the dimensionality of a geometry to 3D.
+
+        Geometries without Z get `z` on every coordinate. Geometries that already have
+        Z are returned unchanged, including any NaN Z value, unless `fill_nan` is set.
+
+        Args:
+            z: Z value given to the coordinates of 2D geometries.
+            fill_nan: If True, NaN Z values of 3D geometries are replaced by `z` too.
+        """
+        ...
+
+    @register_plugin()
+    def flip_coordinates(self) -> GeoExpr:
+        """Flip the x and y coordinates of each geometry."""
+        ...
 
     @register_plugin()
     def minimum_rotated_rectangle(self) -> GeoExpr: ...
 
+    @register_plugin()
+    def minimum_width(self) -> GeoExpr:
+        """Return the two-point LineString spanning the minimum width of each geometry.
+
+        Empty geometries return an empty LineString.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["POLYGON ((0 0, 4 0, 4 1, 0 1, 0 0))"])
+            >>> gdf.select(st.minimum_width().st.length())
+            shape: (1, 1)
+            ┌──────────┐
+            │ geometry │
+            │ ---      │
+            │ f64      │
+            ╞══════════╡
+            │ 1.0      │
+            └──────────┘
+        """
+        ...
+
+    @register_plugin()
+    def minimum_clearance_line(self) -> GeoExpr:
+        """Return the two-point LineString spanning the minimum clearance of each geometry.
+
+        The length of the line is the
+        [`minimum_clearance`][polars_st.GeoExprNameSpace.minimum_clearance] of the
+        geometry. Empty geometries, or geometries without a minimum clearance (e.g. a
+        single point), return an empty LineString.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["POLYGON ((0 0, 4 0, 4 1, 2 0.2, 0 1, 0 0))"])
+            >>> gdf.select(st.minimum_clearance_line().st.length() == st.minimum_clearance())
+            shape: (1, 1)
+            ┌──────────┐
+            │ geometry │
+            │ ---      │
+            │ bool     │
+            ╞══════════╡
+            │ true     │
+            └──────────┘
+        """
+        ...
+
     @register_plugin()
     def snap(
         self,
         other: IntoGeoExprColumn,
         tolerance: IntoNumericExpr,
-    ) -> GeoExpr: ...
+        grid_size: float | None = None,
+    ) -> GeoExpr:
+        """Snap the vertices of each geometry to other within the given tolerance.
+
+        Args:
+            other: Geometry to snap to.
+            tolerance: Maximum distance between a vertex and the vertex it snaps to.
+            grid_size: If set, the snapped geometry is then rounded to a grid of this
+                size, which moves vertices of other that don't lie on the grid.
+        """
+        ...
 
     @register_plugin()
     def shortest_line(self, other: IntoGeoExprColumn) -> GeoExpr:
-        """Return the shortest line between each geometry and other."""
+        """Return the shortest line between each geometry and other.
+
+        When other is a single geometry, it is prepared once and reused for every row.
+        """
+        ...
+
+    @register_plugin()
+    def nearest_points(self, other: IntoGeoExprColumn) -> pl.Expr:
+        """Return the shortest line between each geometry and other, with its length.
+
+        The result is a struct with the shortest line, as returned by
+        [`shortest_line`][polars_st.GeoExprNameSpace.shortest_line], and the
+        `distance` between both geometries, which is NaN when either is empty.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["POINT (0 1)", "POINT (3 4)"])
+            >>> road = st.from_wkt(pl.lit("LINESTRING (0 0, 10 0)"))
+            >>> gdf.select(st.geom().st.nearest_points(road)).unnest("geometry").with_columns(
+            ...     st.to_wkt()
+            ... )
+            shape: (2, 2)
+            ┌───────────────────────┬──────────┐
+            │ geometry              ┆ distance │
+            │ ---                   ┆ ---      │
+            │ str                   ┆ f64      │
+            ╞═══════════════════════╪══════════╡
+            │ LINESTRING (0 1, 0 0) ┆ 1.0      │
+            │ LINESTRING (3 4, 3 0) ┆ 4.0      │
+            └───────────────────────┴──────────┘
+        """
         ...
 
+    def nearest_point_in_set(self, candidates: IntoGeoExprColumn) -> pl.Expr:
+        """Return the shortest line to the nearest geometry in a set of candidates.
+
+        Unlike [`shortest_line`][polars_st.GeoExprNameSpace.shortest_line], `candidates`
+        is not matched row by row: each geometry is compared to the whole candidate
+        column, using a spatial index. The result is a struct with the shortest line
+        and the index of the nearest candidate. Null or empty geometries, or an empty
+        candidate set, return null.
+
+        Args:
+            candidates: Column of candidate geometries.
+
+        Examples:
+            >>> deliveries = st.GeoDataFrame(["POINT (0 1)", "POINT (9 9)"])
+            >>> depots = st.GeoSeries(["POINT (0 0)", "POINT (10 10)", "POINT (5 5)"])
+            >>> deliveries.select(st.geom().st.nearest_point_in_set(pl.lit(depots))).unnest(
+            ...     "geometry"
+            ... ).with_columns(st.to_wkt())
+            shape: (2, 2)
+            ┌─────────────────────────┬───────┐
+            │ geometry                ┆ index │
+            │ ---                     ┆ ---   │
+            │ str                     ┆ u32   │
+            ╞═════════════════════════╪═══════╡
+            │ LINESTRING (0 1, 0 0)   ┆ 0     │
+            │ LINESTRING (9 9, 10 10) ┆ 1     │
+            └─────────────────────────┴───────┘
+        """
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="nearest_point_in_set",
+            args=[self._expr, candidates],
+            is_elementwise=False,
+        )
+
+    def apply_coordinates(
+        self,
+        function: Callable[[float, float, float], tuple[float, float, float]]
+        | Callable[[pl.Series, pl.Series, pl.Series], tuple[Sequence[float], ...]],
+        *,
+        vectorized: bool = False,
+    ) -> GeoExpr:
+        """Apply a Python function to the coordinates of each geometry.
+
+        The function receives the X, Y and Z of each coordinate and must return the new
+        X, Y and Z. Z is NaN for 2D geometries, and the returned Z is then ignored.
+        Errors raised by the function are propagated as a `ComputeError`.
+
+        The coordinates are exchanged with Python once per batch, but calling a Python
+        function for each coordinate is still much slower than built-in transforms such
+        as [`affine_transform`][polars_st.GeoExprNameSpace.affine_transform] or
+        [`to_srid`][polars_st.GeoExprNameSpace.to_srid]. Prefer `vectorized=True`
+        with a function working on whole series when possible.
+
+        Args:
+            function: Function mapping `(x, y, z)` to a new `(x, y, z)` tuple.
+            vectorized: If True, `function` is called once per batch with three
+                [`Series`][polars.Series] holding all X, Y and Z values, and must return
+                three sequences of the same length.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["POINT (1 2)", "LINESTRING (0 0, 3 4)"])
+            >>> to_km = st.geom().st.apply_coordinates(lambda x, y, z: (x / 1000, y / 1000, z))
+            >>> gdf.select(to_km.st.to_wkt()).to_series().to_list()
+            ['POINT (0.001 0.002)', 'LINESTRING (0 0, 0.003 0.004)']
+            >>> flip = st.geom().st.apply_coordinates(lambda x, y, z: (y, x, z), vectorized=True)
+            >>> gdf.select(flip.st.to_wkt()).to_series().to_list()
+            ['POINT (2 1)', 'LINESTRING (0 0, 4 3)']
+        """
+
+        def batch(xs: list[float], ys: list[float], zs: list[float]) -> tuple[list[float], ...]:
+            if vectorized:
+                series = (pl.Series(xs), pl.Series(ys), pl.Series(zs))
+                return tuple(list(values) for values in function(*series))  # type: ignore[arg-type]
+            coords = zip(xs, ys, zs, strict=True)
+            results = [function(*coord) for coord in coords]  # type: ignore[arg-type]
+            return tuple(list(values) for values in zip(*results, strict=True)) or ([], [], [])
+
+        def apply(s: pl.Series) -> pl.Series:
+            wkb = _lib.apply_coordinates(s._s, batch)  # noqa: SLF001
+            return pl.Series(s.name, wkb, dtype=pl.Binary)
+
+        return self._expr.map_batches(
+            apply,
+            return_dtype=pl.Binary(),
+            is_elementwise=True,
+        ).pipe(lambda e: cast("GeoExpr", e))
+
     # Affine tranforms
 
-    def affine_transform(self, matrix: IntoExprColumn | Sequence[float]) -> GeoExpr:
+    def affine_transform(
+        self,
+        matrix: IntoExprColumn | Sequence[float],
+        *,
+        keep_srid: bool = True,
+    ) -> GeoExpr:
         """Apply a 2D or 3D transformation matrix to the coordinates of each geometry.
 
         Args:
@@ -755,6 +2324,20 @@ class GeoExprNameSpace:
                 - `m11`, `m12`, `m13`, `m21`, `m22`, `m23`, `m31`, `m32`, `m33`, `tx`, `ty`, `tz`
                     for 3D transformations
 
+                Can be an array or list column to use a different matrix for each row.
+                Matrices of another length or with null elements raise an error
+                mentioning the row index.
+            keep_srid: Whether to keep the SRID of the geometries. An arbitrary
+                transform usually moves geometries out of their coordinate reference
+                system, set it to False to reset the SRID to 0.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["SRID=2154;POINT (1 2)"])
+            >>> gdf.select(st.affine_transform(matrix=[2, 0, 0, 2, 10, 0]).st.to_ewkt()).item()
+            'SRID=2154;POINT (12 4)'
+            >>> scaled = st.affine_transform(matrix=[2, 0, 0, 2, 10, 0], keep_srid=False)
+            >>> gdf.select(scaled.st.to_ewkt()).item()
+            'POINT (12 4)'
         """
         return register_plugin_function(
             plugin_path=Path(__file__).parent,
@@ -765,6 +2348,7 @@ class GeoExprNameSpace:
                 if isinstance(matrix, pl.Expr | pl.Series | str)
                 else pl.lit(matrix, dtype=pl.Array(pl.Float64, len(matrix))),
             ],
+            kwargs={"keep_srid": keep_srid},
             is_elementwise=True,
         ).pipe(lambda e: cast("GeoExpr", e))
 
@@ -781,43 +2365,139 @@ class GeoExprNameSpace:
             is_elementwise=True,
         ).pipe(lambda e: cast("GeoExpr", e))
 
-    @register_plugin()
     def rotate(
         self,
         angle: IntoNumericExpr,
-        origin: Literal["center", "centroid"] | Sequence[float] = "center",
-    ) -> GeoExpr: ...
+        origin: Literal["center", "centroid"] | Sequence[float] | pl.Expr | pl.Series = "center",
+    ) -> GeoExpr:
+        """Rotate each geometry counter-clockwise by an angle in degrees.
+
+        Args:
+            angle: Rotation angle in degrees.
+            origin: Point to rotate around: the `"center"` of the bounding box, the
+                `"centroid"`, a sequence of coordinates, or an expression of points to
+                use a different origin for each row, such as `st.geom("anchor")`.
+        """
+        return self._transform("rotate", "rotate_around_geom", angle, origin)
 
     def scale(
         self,
         x: IntoNumericExpr = 1.0,
         y: IntoNumericExpr = 1.0,
         z: IntoNumericExpr = 1.0,
-        origin: Literal["center", "centroid"] | Sequence[float] = "center",
+        origin: Literal["center", "centroid"] | Sequence[float] | pl.Expr | pl.Series = "center",
     ) -> GeoExpr:
-        return register_plugin_function(
-            plugin_path=Path(__file__).parent,
-            function_name="scale",
-            args=[self._expr, pl.concat_list(x, y, z)],
-            kwargs={"origin": origin},
-            is_elementwise=True,
-        ).pipe(lambda e: cast("GeoExpr", e))
+        """Scale each geometry by factors along each axis.
+
+        Args:
+            x: Scale factor along the X axis.
+            y: Scale factor along the Y axis.
+            z: Scale factor along the Z axis.
+            origin: Point to scale from, see
+                [`rotate`][polars_st.GeoExprNameSpace.rotate].
+
+        Examples:
+            >>> gdf = st.GeoDataFrame({
+            ...     "geometry": ["LINESTRING (1 1, 2 1)", "LINESTRING (0 0, 1 1)"],
+            ...     "anchor": ["POINT (1 1)", "POINT (1 1)"],
+            ... })
+            >>> scaled = st.scale(x=2, y=2, origin=st.geom("anchor"))
+            >>> gdf.select(scaled.st.to_wkt()).to_series().to_list()
+            ['LINESTRING (1 1, 3 1)', 'LINESTRING (-1 -1, 1 1)']
+        """
+        return self._transform("scale", "scale_from_geom", pl.concat_list(x, y, z), origin)
 
     def skew(
         self,
         x: IntoNumericExpr = 0.0,
         y: IntoNumericExpr = 0.0,
         z: IntoNumericExpr = 0.0,
-        origin: Literal["center", "centroid"] | Sequence[float] = "center",
+        origin: Literal["center", "centroid"] | Sequence[float] | pl.Expr | pl.Series = "center",
     ) -> GeoExpr:
+        """Skew each geometry by angles in degrees along each axis.
+
+        Args:
+            x: Skew angle along the X axis.
+            y: Skew angle along the Y axis.
+            z: Skew angle along the Z axis.
+            origin: Point to skew from, see
+                [`rotate`][polars_st.GeoExprNameSpace.rotate].
+        """
+        return self._transform("skew", "skew_from_geom", pl.concat_list(x, y, z), origin)
+
+    def _transform(
+        self,
+        function_name: str,
+        geom_function_name: str,
+        parameters: IntoExprColumn | float,
+        origin: Literal["center", "centroid"] | Sequence[float] | pl.Expr | pl.Series,
+    ) -> GeoExpr:
+        if isinstance(origin, pl.Expr | pl.Series):
+            return register_plugin_function(
+                plugin_path=Path(__file__).parent,
+                function_name=geom_function_name,
+                args=[self._expr, parameters, origin],
+                is_elementwise=True,
+            ).pipe(lambda e: cast("GeoExpr", e))
         return register_plugin_function(
             plugin_path=Path(__file__).parent,
-            function_name="skew",
-            args=[self._expr, pl.concat_list(x, y, z)],
+            function_name=function_name,
+            args=[self._expr, parameters],
             kwargs={"origin": origin},
             is_elementwise=True,
         ).pipe(lambda e: cast("GeoExpr", e))
 
+    def snap_to_grid(
+        self,
+        size_x: IntoNumericExpr,
+        size_y: IntoNumericExpr | None = None,
+        size_z: IntoNumericExpr = 0.0,
+        origin_x: IntoNumericExpr = 0.0,
+        origin_y: IntoNumericExpr = 0.0,
+        origin_z: IntoNumericExpr = 0.0,
+    ) -> GeoExpr:
+        """Snap each coordinate to the nearest cell of a grid anchored at a given origin.
+
+        Unlike [`set_precision`][polars_st.GeoExprNameSpace.set_precision], coordinates
+        are rounded independently without fixing the resulting topology, and consecutive
+        vertices falling in the same cell are kept. Chain with
+        [`remove_repeated_points`][polars_st.GeoExprNameSpace.remove_repeated_points]
+        to deduplicate them.
+
+        Args:
+            size_x: Grid cell size along the X axis.
+            size_y: Grid cell size along the Y axis. Defaults to `size_x`.
+            size_z: Grid cell size along the Z axis. Z values are left untouched when
+                zero, which is the default. M values are never snapped.
+            origin_x: X coordinate of the grid origin.
+            origin_y: Y coordinate of the grid origin.
+            origin_z: Z coordinate of the grid origin.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame([
+            ...     "LINESTRING (0.4 1.6, 2.6 3.3)",
+            ... ])
+            >>> gdf.select(st.snap_to_grid(1.0, origin_x=0.5)).st.to_wkt()
+            shape: (1, 1)
+            ┌───────────────────────────┐
+            │ geometry                  │
+            │ ---                       │
+            │ str                       │
+            ╞═══════════════════════════╡
+            │ LINESTRING (0.5 2, 2.5 3) │
+            └───────────────────────────┘
+        """
+        size_y = size_x if size_y is None else size_y
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="snap_to_grid",
+            args=[
+                self._expr,
+                pl.concat_list(size_x, size_y, size_z, origin_x, origin_y, origin_z),
+            ],
+            is_elementwise=True,
+        ).pipe(lambda e: cast("GeoExpr", e))
+
     # Linestring operations
 
     @register_plugin()
@@ -827,6 +2507,41 @@ class GeoExprNameSpace:
         normalized: bool = False,
     ) -> GeoExpr: ...
 
+    def interpolate_many(
+        self,
+        distances: IntoExprColumn | Sequence[float],
+        normalized: bool = False,
+    ) -> pl.Expr:
+        """Return a list of points at given distances along each line.
+
+        Like [`interpolate`][polars_st.GeoExprNameSpace.interpolate], but with a list
+        of distances, so that many points can be sampled along each line without
+        exploding the frame. Distances beyond the length of a line return its endpoint.
+
+        Args:
+            distances: Distances along the line, either a list column or the same
+                sequence of distances for every row.
+            normalized: If True, distances are fractions of the length of each line.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["LINESTRING (0 0, 10 0)"])
+            >>> points = gdf.select(st.geom().st.interpolate_many([0.0, 2.5, 20.0]))
+            >>> points.explode("geometry").select(st.to_wkt()).to_series().to_list()
+            ['POINT (0 0)', 'POINT (2.5 0)', 'POINT (10 0)']
+        """
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="interpolate_many",
+            args=[
+                self._expr,
+                distances
+                if isinstance(distances, pl.Expr | pl.Series | str)
+                else pl.lit(distances, dtype=pl.List(pl.Float64)),
+            ],
+            kwargs={"normalized": normalized},
+            is_elementwise=True,
+        )
+
     @register_plugin()
     def project(
         self,
@@ -835,15 +2550,135 @@ class GeoExprNameSpace:
     ) -> pl.Expr: ...
 
     @register_plugin()
-    def substring(self, start: IntoNumericExpr, end: IntoNumericExpr) -> GeoExpr:
-        """Returns the substring of each line starting and ending at the given fractional locations."""  # noqa: E501
+    def substring(
+        self,
+        start: IntoNumericExpr,
+        end: IntoNumericExpr,
+        normalized: bool = True,
+    ) -> GeoExpr:
+        """Returns the substring of each line starting and ending at the given locations.
+
+        Locations outside of the line are clamped to its endpoints. If `start` is
+        greater than `end`, the returned substring is reversed.
+
+        Args:
+            start: Start location of the substring.
+            end: End location of the substring.
+            normalized: If True, `start` and `end` are fractions of the line length.
+                Otherwise, they are distances along the line.
+
+        Examples:
+            >>> df = pl.select(geometry=st.from_wkt(pl.lit("LINESTRING (0 0, 10 0)")))
+            >>> df.select(st.substring(start=8.0, end=2.0, normalized=False).st.to_wkt())
+            shape: (1, 1)
+            ┌───────────────────────┐
+            │ geometry              │
+            │ ---                   │
+            │ str                   │
+            ╞═══════════════════════╡
+            │ LINESTRING (8 0, 2 0) │
+            └───────────────────────┘
+        """
+        ...
+
+    @register_plugin()
+    def line_merge(
+        self,
+        directed: bool = False,
+        tolerance: IntoNumericExpr | None = None,
+    ) -> GeoExpr:
+        """Merge the lines of each geometry that are connected by their endpoints.
+
+        Args:
+            directed: If True, only lines with the same direction are merged.
+            tolerance: If set, the endpoints of each line closer than this distance to
+                the endpoint of a previous line are moved onto it before merging, so
+                that lines separated by tiny gaps are connected. Interior vertices are
+                left unchanged. Can be a column to use a different tolerance for each
+                geometry. Null values only merge exactly touching lines.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["MULTILINESTRING ((0 0, 1 0), (1.001 0, 2 0))"])
+            >>> gdf.select(st.line_merge().st.count_geometries()).item()
+            2
+            >>> gdf.select(st.line_merge(tolerance=0.01).st.to_wkt()).item()
+            'LINESTRING (0 0, 1 0, 2 0)'
+        """
         ...
 
     @register_plugin()
-    def line_merge(self, directed: bool = False) -> GeoExpr: ...
+    def shared_paths(
+        self,
+        other: IntoGeoExprColumn,
+        direction: Literal["both", "forward", "backward"] = "both",
+    ) -> GeoExpr:
+        """Return the paths shared by each pair of linear geometries.
+
+        Args:
+            other: Linear geometries to compare with.
+            direction: Which shared paths to return:
+
+                - `"both"`: a GeometryCollection of two MultiLineStrings, the paths
+                    with the same direction in both geometries, then those with
+                    opposite directions.
+                - `"forward"`: only the MultiLineString of paths with the same
+                    direction.
+                - `"backward"`: only the MultiLineString of paths with opposite
+                    directions.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["LINESTRING (0 0, 4 0, 4 4)"])
+            >>> other = st.from_wkt(pl.lit("MULTILINESTRING ((0 0, 1 0), (4 4, 4 2))"))
+            >>> gdf.select(
+            ...     forward=st.geom().st.shared_paths(other, direction="forward").st.length(),
+            ...     backward=st.geom().st.shared_paths(other, direction="backward").st.length(),
+            ... )
+            shape: (1, 2)
+            ┌─────────┬──────────┐
+            │ forward ┆ backward │
+            │ ---     ┆ ---      │
+            │ f64     ┆ f64      │
+            ╞═════════╪══════════╡
+            │ 1.0     ┆ 2.0      │
+            └─────────┴──────────┘
+        """
+        ...
 
     @register_plugin()
-    def shared_paths(self, other: IntoGeoExprColumn) -> GeoExpr: ...
+    def split(self, blade: IntoGeoExprColumn) -> GeoExpr:
+        """Split each geometry by a blade, returning a GeometryCollection of the pieces.
+
+        This mirrors PostGIS `ST_Split`. GEOS has no direct primitive for it, so:
+
+        - Lines split by points are cut at the linear position of every blade point
+          lying on them.
+        - Lines split by lines are noded against the blade, using `difference`.
+          Parts of the line overlapping the blade are dropped.
+        - Polygons split by lines have their boundary noded with the blade, the result
+          is polygonized, and only the faces inside the original polygon are kept.
+
+        A polygonal blade acts through its boundary. Other combinations, such as points
+        or polygons split by points, return the geometry unchanged in a collection.
+        Multi geometries and collections are split member by member.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame([
+            ...     "POLYGON ((0 0, 2 0, 2 2, 0 2, 0 0))",
+            ...     "LINESTRING (0 1, 4 1)",
+            ... ])
+            >>> blade = st.from_wkt(pl.lit("LINESTRING (1 -1, 1 3)"))
+            >>> gdf.select(st.geom().st.split(blade).st.count_geometries())
+            shape: (2, 1)
+            ┌──────────┐
+            │ geometry │
+            │ ---      │
+            │ u32      │
+            ╞══════════╡
+            │ 2        │
+            │ 2        │
+            └──────────┘
+        """
+        ...
 
     # Aggregations
 
@@ -853,13 +2688,162 @@ class GeoExprNameSpace:
         ...
 
     @register_plugin(is_aggregation=True)
-    def collect(self, into: GeometryType | None = None) -> GeoExpr:
-        """Aggregate geometries into a single collection."""
+    def collect(
+        self,
+        into: GeometryType | None = None,
+        on_mismatch: Literal["raise", "skip", "wrap"] = "raise",
+    ) -> GeoExpr:
+        """Aggregate geometries into a single collection.
+
+        Args:
+            into: Type of the collection to create. By default, the most specific
+                collection type fitting all geometries is used.
+            on_mismatch: What to do when some geometries cannot be part of a collection
+                of type `into` (e.g. a LineString in a MultiPolygon):
+
+                - `"raise"`: raise an error listing the offending geometry types.
+                - `"skip"`: leave the offending geometries out of the collection.
+                - `"wrap"`: return a GeometryCollection instead.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame([
+            ...     "POLYGON ((0 0, 1 0, 1 1, 0 0))",
+            ...     "LINESTRING (0 0, 1 1)",
+            ... ])
+            >>> gdf.select(st.collect(into="MultiPolygon", on_mismatch="skip")).st.to_wkt()
+            shape: (1, 1)
+            ┌─────────────────────────────────┐
+            │ geometry                        │
+            │ ---                             │
+            │ str                             │
+            ╞═════════════════════════════════╡
+            │ MULTIPOLYGON (((0 0, 1 0, 1 1,… │
+            └─────────────────────────────────┘
+        """
+        ...
+
+    @register_plugin(is_aggregation=True)
+    def collect_line(self) -> GeoExpr:
+        """Aggregate points into a single LineString, in row order.
+
+        At least two non-empty points are required. Null and empty points are skipped.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame({
+            ...     "track": [1, 1, 2, 1, 2],
+            ...     "geometry": [
+            ...         "POINT (0 0)",
+            ...         "POINT (1 1)",
+            ...         "POINT (5 5)",
+            ...         "POINT (2 0)",
+            ...         "POINT (6 5)",
+            ...     ],
+            ... })
+            >>> gdf.group_by("track", maintain_order=True).agg(st.collect_line()).st.to_wkt()
+            shape: (2, 2)
+            ┌───────┬────────────────────────────┐
+            │ track ┆ geometry                   │
+            │ ---   ┆ ---                        │
+            │ i64   ┆ str                        │
+            ╞═══════╪════════════════════════════╡
+            │ 1     ┆ LINESTRING (0 0, 1 1, 2 0) │
+            │ 2     ┆ LINESTRING (5 5, 6 5)      │
+            └───────┴────────────────────────────┘
+        """
         ...
 
+    @register_plugin(is_aggregation=True)
+    def collect_polygon(self) -> GeoExpr:
+        """Aggregate points into a single Polygon, in row order.
+
+        The ring is closed if the last point differs from the first one. At least three
+        distinct non-empty points are required. Null and empty points are skipped.
+        """
+        ...
+
+    def to_geojson_feature_collection(
+        self,
+        properties: IntoExprColumn | Sequence[IntoExprColumn] | None = None,
+        indent: int | None = None,
+        output_dimension: Literal[2, 3] = 3,
+        rounding_precision: int | None = None,
+    ) -> pl.Expr:
+        """Serialize all geometries as a single GeoJSON FeatureCollection.
+
+        Each row becomes a Feature, in order. Null geometries become features with a
+        null geometry.
+
+        Args:
+            properties: Column names or expressions of the same frame to include as the
+                properties of each feature. They are combined with
+                [`pl.struct`][polars.struct] and encoded as JSON, so properties from another
+                frame must be joined first.
+            indent: If indent is not `None`, then GeoJSON will be pretty-printed.
+                An indent level of 0 will only insert newlines. `None` (the default)
+                outputs the most compact representation.
+            output_dimension: The output dimension for the GeoJSON. Specifying 2 drops
+                the Z coordinate of 3D geometries.
+            rounding_precision: If not `None`, coordinates are rounded to this number
+                of decimal places.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame({
+            ...     "name": ["Alice", "Bob"],
+            ...     "geometry": ["POINT (0 0)", None],
+            ... })
+            >>> geojson = gdf.select(st.geom().st.to_geojson_feature_collection("name"))
+            >>> print(geojson.item())
+            {"type":"FeatureCollection","features":[{"type":"Feature","geometry":{"type":"Point","coordinates":[0.0,0.0]},"properties":{"name":"Alice"}},{"type":"Feature","geometry":null,"properties":{"name":"Bob"}}]}
+        """  # noqa: E501
+        properties_json = (
+            pl.lit("{}") if properties is None else pl.struct(properties).struct.json_encode()
+        )
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="to_geojson_feature_collection",
+            args=[self._expr, properties_json],
+            kwargs={
+                "indent": indent,
+                "output_dimension": output_dimension,
+                "rounding_precision": rounding_precision,
+            },
+            is_elementwise=False,
+            returns_scalar=True,
+        )
+
     @register_plugin(is_aggregation=True)
     def union_all(self, grid_size: float | None = None) -> GeoExpr:
-        """Return the union of all geometries."""
+        """Return the union of all geometries.
+
+        All geometries are merged at once, which makes this the "dissolve" operation
+        when used in a group by. Unlike
+        [`coverage_union_all`][polars_st.GeoExprNameSpace.coverage_union_all], the
+        geometries may overlap.
+
+        Args:
+            grid_size: If set, the result is snapped to a grid of this size.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame({
+            ...     "region": ["a", "a", "b"],
+            ...     "geometry": [
+            ...         "POLYGON ((0 0, 2 0, 2 2, 0 2, 0 0))",
+            ...         "POLYGON ((1 0, 3 0, 3 2, 1 2, 1 0))",
+            ...         "POLYGON ((5 5, 6 5, 6 6, 5 5))",
+            ...     ],
+            ... })
+            >>> dissolved = gdf.group_by("region", maintain_order=True).agg(st.union_all())
+            >>> dissolved.select("region", st.area())
+            shape: (2, 2)
+            ┌────────┬──────────┐
+            │ region ┆ geometry │
+            │ ---    ┆ ---      │
+            │ str    ┆ f64      │
+            ╞════════╪══════════╡
+            │ a      ┆ 6.0      │
+            │ b      ┆ 0.5      │
+            └────────┴──────────┘
+        """
         ...
 
     @register_plugin(is_aggregation=True)
@@ -869,7 +2853,30 @@ class GeoExprNameSpace:
 
     @register_plugin(is_aggregation=True)
     def intersection_all(self, grid_size: float | None = None) -> GeoExpr:
-        """Return the intersection of all geometries."""
+        """Return the intersection of all geometries.
+
+        Geometries are intersected in row order, stopping as soon as the running
+        intersection is empty.
+
+        Args:
+            grid_size: If set, each intersection is snapped to a grid of this size.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame([
+            ...     "POLYGON ((0 0, 2 0, 2 2, 0 2, 0 0))",
+            ...     "POLYGON ((1 0, 3 0, 3 2, 1 2, 1 0))",
+            ...     "POLYGON ((0 1, 3 1, 3 3, 0 3, 0 1))",
+            ... ])
+            >>> gdf.select(st.intersection_all().st.area())
+            shape: (1, 1)
+            ┌──────────┐
+            │ geometry │
+            │ ---      │
+            │ f64      │
+            ╞══════════╡
+            │ 1.0      │
+            └──────────┘
+        """
         ...
 
     @register_plugin(is_aggregation=True)
@@ -885,16 +2892,108 @@ class GeoExprNameSpace:
     @register_plugin(is_aggregation=True)
     def polygonize(self) -> GeoExpr: ...
 
+    @register_plugin(is_aggregation=True)
+    def polygonize_full(self) -> pl.Expr:
+        """Return the polygons formed from the linework of all geometries, with the edges
+        that could not be used.
+
+        The result is a struct of four geometry collections: `polygons` are the formed
+        polygons, `dangles` the edges with an end that is not connected to another edge,
+        `cut_edges` the edges connected at both ends that don't form part of a polygon,
+        and `invalid_rings` the rings that are closed but invalid. This is useful to find
+        why a network of lines does not close into polygons.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame([
+            ...     "LINESTRING (0 0, 1 0, 1 1, 0 1, 0 0)",
+            ...     "LINESTRING (1 1, 2 2)",
+            ... ])
+            >>> result = gdf.select(st.geom().st.polygonize_full()).unnest("geometry")
+            >>> result.select(pl.all().st.count_geometries())
+            shape: (1, 4)
+            ┌──────────┬─────────┬───────────┬───────────────┐
+            │ polygons ┆ dangles ┆ cut_edges ┆ invalid_rings │
+            │ ---      ┆ ---     ┆ ---       ┆ ---           │
+            │ u32      ┆ u32     ┆ u32       ┆ u32           │
+            ╞══════════╪═════════╪═══════════╪═══════════════╡
+            │ 1        ┆ 1       ┆ 0         ┆ 0             │
+            └──────────┴─────────┴───────────┴───────────────┘
+        """
+        ...
+
     @register_plugin(is_aggregation=True)
     def voronoi_polygons(
         self,
         tolerance: float = 0.0,
         extend_to: bytes | None = None,
         only_edges: bool = False,
+        ordered: bool = False,
     ) -> GeoExpr:
-        """Return a Voronoi diagram of all geometries vertices."""
+        """Return a Voronoi diagram of all geometries vertices.
+
+        Args:
+            tolerance: Snap input vertices together if their distance is less than this
+                value.
+            extend_to: If provided, the diagram will be extended to cover the envelope
+                of this geometry (unless this envelope is smaller than the input
+                geometry).
+            only_edges: If set to True, the diagram will return a MultiLineString of
+                the cells edges instead of a collection of polygons.
+            ordered: If set to True, the cells are sorted by the row index of the input
+                geometry they were generated from, so that the nth cell matches the nth
+                input point when all points are distinct. Cannot be combined with
+                `only_edges`. Use
+                [`voronoi_with_index`][polars_st.GeoExprNameSpace.voronoi_with_index]
+                to get the index of each cell explicitly.
+        """
         ...
 
+    def voronoi_with_index(
+        self,
+        tolerance: float = 0.0,
+        extend_to: bytes | None = None,
+    ) -> pl.Expr:
+        """Return the Voronoi cells of all geometries, with the index of their seed geometry.
+
+        The result is a struct with one row per cell, holding the cell polygon and the
+        row index of the input geometry it was generated from, which can be used to
+        transfer attributes from the input points to the cells. Cells are sorted by
+        index. Duplicate points share a single cell, attributed to the first of them.
+
+        Args:
+            tolerance: Snap input vertices together if their distance is less than this
+                value.
+            extend_to: If provided, the diagram will be extended to cover the envelope
+                of this geometry (unless this envelope is smaller than the input
+                geometry).
+
+        Examples:
+            >>> gdf = st.GeoDataFrame([
+            ...     "POINT (0 0)",
+            ...     "POINT (2 0)",
+            ...     "POINT (1 2)",
+            ... ])
+            >>> cells = gdf.select(st.geom().st.voronoi_with_index()).unnest("geometry")
+            >>> cells.select("index", st.geom().st.contains(gdf["geometry"]))
+            shape: (3, 2)
+            ┌───────┬──────────┐
+            │ index ┆ geometry │
+            │ ---   ┆ ---      │
+            │ u32   ┆ bool     │
+            ╞═══════╪══════════╡
+            │ 0     ┆ true     │
+            │ 1     ┆ true     │
+            │ 2     ┆ true     │
+            └───────┴──────────┘
+        """
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="voronoi_with_index",
+            args=[self._expr],
+            kwargs={"tolerance": tolerance, "extend_to": extend_to},
+            is_elementwise=False,
+        )
+
     @register_plugin(is_aggregation=True)
     def delaunay_triangles(
         self,
@@ -903,3 +3002,127 @@ class GeoExprNameSpace:
     ) -> GeoExpr:
         """Return a Delaunay triangulation of all geometries vertices."""
         ...
+
+    @register_plugin()
+    def constrained_delaunay(self, only_edges: bool = False) -> GeoExpr:
+        """Return a constrained Delaunay triangulation of each polygonal geometry.
+
+        Unlike [`delaunay_triangles`][polars_st.GeoExprNameSpace.delaunay_triangles],
+        the triangulation is computed for each geometry separately, and the polygon
+        edges are guaranteed to be edges of the triangulation. Non-polygonal
+        geometries return an empty collection.
+
+        Args:
+            only_edges: If set to True, the triangulation will return a MultiLineString
+                of the triangle edges instead of a collection of triangles.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame([
+            ...     "POLYGON ((0 0, 2 0, 2 2, 1 1, 0 2, 0 0))",
+            ... ])
+            >>> gdf.select(st.constrained_delaunay().st.count_geometries())
+            shape: (1, 1)
+            ┌──────────┐
+            │ geometry │
+            │ ---      │
+            │ u32      │
+            ╞══════════╡
+            │ 3        │
+            └──────────┘
+        """
+        ...
+
+    @register_plugin(is_aggregation=True)
+    def constrained_delaunay_all(self, only_edges: bool = False) -> GeoExpr:
+        """Return a constrained Delaunay triangulation of all polygonal geometries.
+
+        All geometries are collected, then each polygon is triangulated with its edges
+        kept as edges of the triangulation, like
+        [`constrained_delaunay`][polars_st.GeoExprNameSpace.constrained_delaunay].
+        Non-polygonal geometries are ignored.
+
+        Args:
+            only_edges: If set to True, the triangulation will return a MultiLineString
+                of the triangle edges instead of a collection of triangles.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame([
+            ...     "POLYGON ((0 0, 2 0, 2 2, 1 1, 0 2, 0 0))",
+            ...     "POLYGON ((3 0, 4 0, 4 1, 3 0))",
+            ... ])
+            >>> gdf.select(st.constrained_delaunay_all().st.count_geometries())
+            shape: (1, 1)
+            ┌──────────┐
+            │ geometry │
+            │ ---      │
+            │ u32      │
+            ╞══════════╡
+            │ 4        │
+            └──────────┘
+        """
+        ...
+
+    def unique_geometries(self, grid_size: float | None = None) -> pl.Expr:
+        """Return the distinct geometries of the column, in order of first occurrence.
+
+        Geometries are compared after normalization, so that geometries that are equal
+        but encoded differently (e.g. with reversed coordinates) are deduplicated.
+        The result is a struct with the first occurrence of each geometry and its row
+        index, which can be used to deduplicate other columns.
+
+        Args:
+            grid_size: If set, geometries are snapped to a grid of that size before
+                being compared.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame([
+            ...     "LINESTRING (0 0, 1 1)",
+            ...     "POINT (0 0)",
+            ...     "LINESTRING (1 1, 0 0)",
+            ... ])
+            >>> gdf.select(st.geom().st.unique_geometries()).unnest("geometry").with_columns(
+            ...     st.to_wkt()
+            ... )
+            shape: (2, 2)
+            ┌───────────────────────┬───────┐
+            │ geometry              ┆ index │
+            │ ---                   ┆ ---   │
+            │ str                   ┆ u32   │
+            ╞═══════════════════════╪═══════╡
+            │ LINESTRING (0 0, 1 1) ┆ 0     │
+            │ POINT (0 0)           ┆ 1     │
+            └───────────────────────┴───────┘
+        """
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="unique_geometries",
+            args=[self._expr],
+            kwargs={"grid_size": grid_size},
+            is_elementwise=False,
+        )
+
+    @register_plugin()
+    def geometry_hash(self, grid_size: float | None = None) -> pl.Expr:
+        """Return a hash of each geometry after normalization.
+
+        Geometries that are equal but encoded differently (e.g. with reversed
+        coordinates or a different byte order) share the same hash, so it can be used
+        to group or deduplicate geometries. This is a normalization-based hash, not a
+        topological equality test: geometries that are topologically equal but have
+        different vertices, such as a line with an extra collinear vertex, hash
+        differently. Hashes are not stable across versions of polars-st.
+
+        Args:
+            grid_size: If set, geometries are snapped to a grid of that size before
+                being hashed.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame([
+            ...     "LINESTRING (0 0, 1 1)",
+            ...     "POINT (0 0)",
+            ...     "LINESTRING (1 1, 0 0)",
+            ... ])
+            >>> gdf.group_by(st.geom().st.geometry_hash()).len().height
+            2
+        """
+        ...
@@ -10,12 +10,12 @@ from polars.api import register_lazyframe_namespace
 from polars.datatypes import N_INFER_DEFAULT
 from polars.plugins import register_plugin_function
 
+from polars_st import _lib
 from polars_st.geodataframe import GeoDataFrame
 
 if TYPE_CHECKING:
     from polars._typing import (
         FrameInitTypes,
-        JoinStrategy,
         JoinValidation,
         MaintainOrderJoin,
         Orientation,
@@ -27,9 +27,19 @@ if TYPE_CHECKING:
 __all__ = [
     "GeoLazyFrame",
     "GeoLazyFrameNameSpace",
+    "clear_sjoin_cache",
 ]
 
 
+def clear_sjoin_cache() -> None:
+    """Free the spatial index kept from the last spatial join.
+
+    The index of the left geometries is reused when the next join has the same left
+    geometries, which keeps them in memory until another left frame is joined.
+    """
+    _lib.clear_sjoin_cache()
+
+
 class GeoLazyFrame(LazyFrame):
     @property
     def st(self) -> GeoLazyFrameNameSpace: ...
@@ -66,7 +76,7 @@ class GeoLazyFrameNameSpace:
         self,
         other: LazyFrame,
         on: str | Expr = "geometry",
-        how: JoinStrategy = "inner",
+        how: Literal["inner", "left", "right", "full"] = "inner",
         predicate: Literal[
             "intersects_bbox",
             "intersects",
@@ -92,13 +102,19 @@ class GeoLazyFrameNameSpace:
         allow_parallel: bool = True,
         force_parallel: bool = False,
     ) -> LazyFrame:
-        """Perform a spatial join operation with another LazyFrame."""
+        """Perform a spatial join operation with another LazyFrame.
+
+        The right geometries are queried in parallel, but the output order is stable:
+        matches are sorted by right row, then by left row. With `how` set to `"left"`,
+        `"right"` or `"full"`, the unmatched rows follow the matches. The index of the left
+        geometries is kept for the next join until `st.clear_sjoin_cache()` is called.
+        """
         if not isinstance(other, LazyFrame):
             msg = f"expected `other` join table to be a LazyFrame, not a {type(other).__name__!r}"
             raise TypeError(msg)
 
-        if how == "cross":
-            msg = """Use of `how="cross" not supported on sjoin.`"""
+        if how not in {"inner", "left", "right", "full"}:
+            msg = f"Use of `how={how!r}` not supported on sjoin."
             raise ValueError(msg)
 
         left_expr = left_on or on
@@ -124,7 +140,7 @@ class GeoLazyFrameNameSpace:
                     plugin_path=Path(__file__).parent,
                     function_name="sjoin",
                     args=["_sjoin_geom_left", "_sjoin_geom_right"],
-                    kwargs={"predicate": {"type": predicate, "param": distance}},
+                    kwargs={"predicate": {"type": predicate, "param": distance}, "how": how},
                     is_elementwise=True,
                 ),
             )
@@ -134,11 +150,12 @@ class GeoLazyFrameNameSpace:
             )
         )
 
+        # Unmatched rows are already part of the index, with a null index on the other side
         return (
             sjoin_index.join(
                 self._lf.with_row_index("_sjoin_index_left"),
                 on="_sjoin_index_left",
-                how="full",
+                how="left",
                 suffix=suffix,
                 coalesce=coalesce,
                 maintain_order=maintain_order,
@@ -148,7 +165,8 @@ class GeoLazyFrameNameSpace:
             .join(
                 other.with_row_index("_sjoin_index_right"),
                 on="_sjoin_index_right",
-                how=how,
+                how="left",
+                suffix=suffix,
                 validate=validate,
                 nulls_equal=nulls_equal,
                 coalesce=coalesce,
@@ -156,9 +174,81 @@ class GeoLazyFrameNameSpace:
                 allow_parallel=allow_parallel,
                 force_parallel=force_parallel,
             )
-            .drop(
-                "_sjoin_index_left",
-                "_sjoin_index_right",
-                "_sjoin_index_left_right",
+            .select(pl.exclude("^_sjoin_index_.*$"))
+        )
+
+    def self_sjoin(
+        self,
+        on: str | Expr = "geometry",
+        predicate: Literal[
+            "intersects_bbox",
+            "intersects",
+            "within",
+            "dwithin",
+            "contains",
+            "overlaps",
+            "crosses",
+            "touches",
+            "covers",
+            "covered_by",
+            "contains_properly",
+        ] = "intersects",
+        distance: float | None = None,
+        *,
+        distinct: bool = False,
+        suffix: str = "_right",
+    ) -> LazyFrame:
+        """Perform a spatial join of the LazyFrame with itself.
+
+        Rows are never joined with themselves. Matches are ordered as in
+        [`sjoin`][polars_st.GeoLazyFrameNameSpace.sjoin].
+
+        Args:
+            on: Geometry column or expression to join on.
+            predicate: Spatial predicate tested between each pair of geometries.
+            distance: Distance used by the `"dwithin"` predicate.
+            distinct: If `True`, only the first of two mirrored pairs (`(i, j)` and
+                `(j, i)`) is kept, which is most useful with symmetric predicates such
+                as `"intersects"`.
+            suffix: Suffix appended to the columns of the right side.
+        """
+        if parse_into_expression(on).meta_has_multiple_outputs():
+            msg = "spatial join expressions should not return multiple output"
+            raise ValueError(msg)
+
+        sjoin_index = (
+            self._lf.select(_sjoin_geom=on)
+            .select(
+                register_plugin_function(
+                    plugin_path=Path(__file__).parent,
+                    function_name="self_sjoin",
+                    args=["_sjoin_geom"],
+                    kwargs={
+                        "predicate": {"type": predicate, "param": distance},
+                        "distinct": distinct,
+                    },
+                    is_elementwise=False,
+                ),
+            )
+            .select(
+                _sjoin_index_left=pl.nth(0).struct[0],
+                _sjoin_index_right=pl.nth(0).struct[1],
+            )
+        )
+        return (
+            sjoin_index.join(
+                self._lf.with_row_index("_sjoin_index_left"),
+                on="_sjoin_index_left",
+                how="left",
+                suffix=suffix,
+                maintain_order="left",
+            )
+            .join(
+                self._lf.with_row_index("_sjoin_index_right"),
+                on="_sjoin_index_right",
+                how="left",
+                suffix=suffix,
+                maintain_order="left",
             )
+            .select(pl.exclude("^_sjoin_index_.*$"))
         )
@@ -0,0 +1,56 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars_st import _lib
+
+if TYPE_CHECKING:
+    from collections.abc import Sequence
+
+
+__all__ = [
+    "geoparquet_metadata",
+    "parse_geoparquet_metadata",
+]
+
+
+def geoparquet_metadata(
+    column_name: str,
+    srid: int,
+    bounds: Sequence[float] | None = None,
+    geometry_types: Sequence[str] = (),
+) -> str:
+    """Create the GeoParquet `geo` metadata for a WKB geometry column.
+
+    The returned JSON string is meant to be attached to a Parquet file as the value of
+    the `geo` key-value metadata. The CRS is written as PROJJSON converted from the EPSG
+    definition of `srid`, or `null` when `srid` is 0 or isn't a geographic or projected
+    CRS.
+
+    Args:
+        column_name: Name of the primary geometry column.
+        srid: EPSG code of the geometries.
+        bounds: Bounding box of all geometries, as `[xmin, ymin, xmax, ymax]`.
+        geometry_types: Geometry types present in the column, empty if unknown.
+
+    Examples:
+        >>> metadata = st.geoparquet_metadata("geometry", 4326, [0.0, 0.0, 1.0, 1.0])
+        >>> st.parse_geoparquet_metadata(metadata)
+        ('geometry', 4326)
+    """
+    bounds = list(bounds) if bounds is not None else None
+    return _lib.geoparquet_metadata(column_name, srid, bounds, list(geometry_types))
+
+
+def parse_geoparquet_metadata(metadata: str) -> tuple[str, int]:
+    """Read the primary geometry column and its EPSG code from GeoParquet `geo` metadata.
+
+    A missing CRS is interpreted as `OGC:CRS84` (SRID 4326), as required by the
+    specification. An unknown CRS, or one without an EPSG identifier, gives SRID 0.
+
+    Examples:
+        >>> metadata = '{"version": "1.1.0", "primary_column": "geom", "columns": {"geom": {}}}'
+        >>> st.parse_geoparquet_metadata(metadata)
+        ('geom', 4326)
+    """
+    return _lib.parse_geoparquet_metadata(metadata)
@@ -22,7 +22,7 @@ from polars_st.parsing import (
 from polars_st.utils.internal import is_empty_method
 
 if TYPE_CHECKING:
-    from collections.abc import Sequence
+    from collections.abc import Callable, Sequence
 
     import altair as alt
     import geopandas as gpd
@@ -280,11 +280,21 @@ class GeoSeriesNameSpace:
         """See [`GeoExprNameSpace.length`][polars_st.GeoExprNameSpace.length]."""
         ...
 
+    @dispatch
+    def length_3d(self) -> pl.Series:
+        """See [`GeoExprNameSpace.length_3d`][polars_st.GeoExprNameSpace.length_3d]."""
+        ...
+
     @dispatch
     def minimum_clearance(self) -> pl.Series:
         """See [`GeoExprNameSpace.minimum_clearance`][polars_st.GeoExprNameSpace.minimum_clearance]."""  # noqa: E501
         ...
 
+    @dispatch
+    def minimum_bounding_radius(self) -> pl.Series:
+        """See [`GeoExprNameSpace.minimum_bounding_radius`][polars_st.GeoExprNameSpace.minimum_bounding_radius]."""  # noqa: E501
+        ...
+
     @dispatch
     def x(self) -> pl.Series:
         """See [`GeoExprNameSpace.x`][polars_st.GeoExprNameSpace.x]."""
@@ -311,10 +321,29 @@ class GeoSeriesNameSpace:
         ...
 
     @dispatch
-    def coordinates(self, output_dimension: Literal[2, 3] | None = None) -> pl.Series:
+    def num_vertices(self) -> pl.Series:
+        """See [`GeoExprNameSpace.num_vertices`][polars_st.GeoExprNameSpace.num_vertices]."""
+        ...
+
+    @dispatch
+    def count_coordinates_per_part(self) -> pl.Series:
+        """See [`GeoExprNameSpace.count_coordinates_per_part`][polars_st.GeoExprNameSpace.count_coordinates_per_part]."""  # noqa: E501
+        ...
+
+    @dispatch
+    def coordinates(
+        self,
+        output_dimension: Literal[2, 3] | None = None,
+        structured: bool = False,
+    ) -> pl.Series:
         """See [`GeoExprNameSpace.coordinates`][polars_st.GeoExprNameSpace.coordinates]."""
         ...
 
+    @dispatch
+    def coordinates_struct(self) -> pl.Series:
+        """See [`GeoExprNameSpace.coordinates_struct`][polars_st.GeoExprNameSpace.coordinates_struct]."""  # noqa: E501
+        ...
+
     @dispatch
     def exterior_ring(self) -> GeoSeries:
         """See [`GeoExprNameSpace.exterior_ring`][polars_st.GeoExprNameSpace.exterior_ring]."""
@@ -355,11 +384,56 @@ class GeoSeriesNameSpace:
         """See [`GeoExprNameSpace.get_point`][polars_st.GeoExprNameSpace.get_point]."""
         ...
 
+    @dispatch
+    def start_point(self) -> GeoSeries:
+        """See [`GeoExprNameSpace.start_point`][polars_st.GeoExprNameSpace.start_point]."""
+        ...
+
+    @dispatch
+    def end_point(self) -> GeoSeries:
+        """See [`GeoExprNameSpace.end_point`][polars_st.GeoExprNameSpace.end_point]."""
+        ...
+
+    @dispatch
+    def set_point(
+        self,
+        index: IntoIntegerExpr,
+        point: IntoGeoExprColumn,
+        strict: bool = False,
+    ) -> GeoSeries:
+        """See [`GeoExprNameSpace.set_point`][polars_st.GeoExprNameSpace.set_point]."""
+        ...
+
+    @dispatch
+    def add_point(
+        self,
+        point: IntoGeoExprColumn,
+        index: IntoIntegerExpr | None = None,
+        strict: bool = False,
+    ) -> GeoSeries:
+        """See [`GeoExprNameSpace.add_point`][polars_st.GeoExprNameSpace.add_point]."""
+        ...
+
+    @dispatch
+    def remove_point(self, index: IntoIntegerExpr, strict: bool = False) -> GeoSeries:
+        """See [`GeoExprNameSpace.remove_point`][polars_st.GeoExprNameSpace.remove_point]."""
+        ...
+
     @dispatch
     def parts(self) -> pl.Series:
         """See [`GeoExprNameSpace.parts`][polars_st.GeoExprNameSpace.parts]."""
         ...
 
+    @dispatch
+    def explode(self) -> pl.Series:
+        """See [`GeoExprNameSpace.explode`][polars_st.GeoExprNameSpace.explode]."""
+        ...
+
+    @dispatch
+    def dump_points(self) -> pl.Series:
+        """See [`GeoExprNameSpace.dump_points`][polars_st.GeoExprNameSpace.dump_points]."""
+        ...
+
     @dispatch
     def precision(self) -> pl.Series:
         """See [`GeoExprNameSpace.precision`][polars_st.GeoExprNameSpace.precision]."""
@@ -379,6 +453,16 @@ class GeoSeriesNameSpace:
         """See [`GeoExprNameSpace.distance`][polars_st.GeoExprNameSpace.distance]."""
         ...
 
+    @dispatch
+    def distance_3d(self, other: IntoGeoExprColumn) -> pl.Series:
+        """See [`GeoExprNameSpace.distance_3d`][polars_st.GeoExprNameSpace.distance_3d]."""
+        ...
+
+    @dispatch
+    def distance_matrix(self, other: IntoGeoExprColumn) -> pl.Series:
+        """See [`GeoExprNameSpace.distance_matrix`][polars_st.GeoExprNameSpace.distance_matrix]."""  # noqa: E501
+        ...
+
     @dispatch
     def hausdorff_distance(
         self,
@@ -404,16 +488,41 @@ class GeoSeriesNameSpace:
         """See [`GeoExprNameSpace.srid`][polars_st.GeoExprNameSpace.srid]."""
         ...
 
+    @dispatch
+    def srid_authority(self) -> pl.Series:
+        """See [`GeoExprNameSpace.srid_authority`][polars_st.GeoExprNameSpace.srid_authority]."""
+        ...
+
+    @dispatch
+    def has_srid(self) -> pl.Series:
+        """See [`GeoExprNameSpace.has_srid`][polars_st.GeoExprNameSpace.has_srid]."""
+        ...
+
+    @dispatch
+    def strip_srid(self) -> GeoSeries:
+        """See [`GeoExprNameSpace.strip_srid`][polars_st.GeoExprNameSpace.strip_srid]."""
+        ...
+
     @dispatch
     def set_srid(self, srid: IntoIntegerExpr) -> GeoSeries:
         """See [`GeoExprNameSpace.set_srid`][polars_st.GeoExprNameSpace.set_srid]."""
         ...
 
     @dispatch
-    def to_srid(self, srid: IntoIntegerExpr) -> GeoSeries:
+    def to_srid(
+        self,
+        srid: IntoIntegerExpr,
+        always_xy: bool = True,
+        on_error: Literal["raise", "null"] = "raise",
+    ) -> GeoSeries:
         """See [`GeoExprNameSpace.to_srid`][polars_st.GeoExprNameSpace.to_srid]."""
         ...
 
+    @dispatch
+    def to_crs(self, source: str, target: str, always_xy: bool = True) -> GeoSeries:
+        """See [`GeoExprNameSpace.to_crs`][polars_st.GeoExprNameSpace.to_crs]."""
+        ...
+
     # Serialization
 
     @dispatch
@@ -449,10 +558,59 @@ class GeoSeriesNameSpace:
         ...
 
     @dispatch
-    def to_geojson(self, indent: int | None = None) -> pl.Series:
+    def wkb_flavor(self) -> pl.Series:
+        """See [`GeoExprNameSpace.wkb_flavor`][polars_st.GeoExprNameSpace.wkb_flavor]."""
+        ...
+
+    @dispatch
+    def normalize_wkb(
+        self,
+        byte_order: Literal[0, 1] = 1,
+        flavor: Literal["iso", "ewkb"] = "ewkb",
+    ) -> pl.Series:
+        """See [`GeoExprNameSpace.normalize_wkb`][polars_st.GeoExprNameSpace.normalize_wkb]."""
+        ...
+
+    @dispatch
+    def to_wkb_base64(self, alphabet: Literal["standard", "url_safe"] = "standard") -> pl.Series:
+        """See [`GeoExprNameSpace.to_wkb_base64`][polars_st.GeoExprNameSpace.to_wkb_base64]."""
+        ...
+
+    @dispatch
+    def to_geojson(
+        self,
+        indent: int | None = None,
+        output_dimension: Literal[2, 3] = 3,
+        rounding_precision: int | None = None,
+    ) -> pl.Series:
         """See [`GeoExprNameSpace.to_geojson`][polars_st.GeoExprNameSpace.to_geojson]."""
         ...
 
+    @dispatch
+    def to_kml(self) -> pl.Series:
+        """See [`GeoExprNameSpace.to_kml`][polars_st.GeoExprNameSpace.to_kml]."""
+        ...
+
+    @dispatch
+    def to_gml(self, version: Literal[2, 3] = 3) -> pl.Series:
+        """See [`GeoExprNameSpace.to_gml`][polars_st.GeoExprNameSpace.to_gml]."""
+        ...
+
+    @dispatch
+    def to_svg(self, rounding_precision: int | None = None) -> pl.Series:
+        """See [`GeoExprNameSpace.to_svg`][polars_st.GeoExprNameSpace.to_svg]."""
+        ...
+
+    @dispatch
+    def geohash_encode(self, precision: int = 12, use_centroid: bool = False) -> pl.Series:
+        """See [`GeoExprNameSpace.geohash_encode`][polars_st.GeoExprNameSpace.geohash_encode]."""
+        ...
+
+    @dispatch
+    def h3_cell(self, resolution: int) -> pl.Series:
+        """See [`GeoExprNameSpace.h3_cell`][polars_st.GeoExprNameSpace.h3_cell]."""
+        ...
+
     @dispatch
     def to_shapely(self) -> pl.Series:
         """See [`GeoExprNameSpace.to_shapely`][polars_st.GeoExprNameSpace.to_shapely]."""
@@ -546,6 +704,11 @@ class GeoSeriesNameSpace:
         """See [`GeoExprNameSpace.is_valid_reason`][polars_st.GeoExprNameSpace.is_valid_reason]."""
         ...
 
+    @dispatch
+    def is_valid_detail(self) -> pl.Series:
+        """See [`GeoExprNameSpace.is_valid_detail`][polars_st.GeoExprNameSpace.is_valid_detail]."""
+        ...
+
     # Binary predicates
 
     @dispatch
@@ -558,6 +721,11 @@ class GeoSeriesNameSpace:
         """See [`GeoExprNameSpace.contains`][polars_st.GeoExprNameSpace.contains]."""
         ...
 
+    @dispatch
+    def contains_points(self, points: IntoGeoExprColumn) -> pl.Series:
+        """See [`GeoExprNameSpace.contains_points`][polars_st.GeoExprNameSpace.contains_points]."""  # noqa: E501
+        ...
+
     @dispatch
     def contains_properly(self, other: IntoGeoExprColumn) -> pl.Series:
         """See [`GeoExprNameSpace.contains_properly`][polars_st.GeoExprNameSpace.contains_properly]."""  # noqa: E501
@@ -588,6 +756,11 @@ class GeoSeriesNameSpace:
         """See [`GeoExprNameSpace.intersects`][polars_st.GeoExprNameSpace.intersects]."""
         ...
 
+    @dispatch
+    def intersects_points(self, points: IntoGeoExprColumn) -> pl.Series:
+        """See [`GeoExprNameSpace.intersects_points`][polars_st.GeoExprNameSpace.intersects_points]."""  # noqa: E501
+        ...
+
     @dispatch
     def overlaps(self, other: IntoGeoExprColumn) -> pl.Series:
         """See [`GeoExprNameSpace.overlaps`][polars_st.GeoExprNameSpace.overlaps]."""
@@ -612,7 +785,7 @@ class GeoSeriesNameSpace:
     def equals_exact(
         self,
         other: IntoGeoExprColumn,
-        tolerance: float = 0.0,
+        tolerance: IntoNumericExpr = 0.0,
     ) -> pl.Series:
         """See [`GeoExprNameSpace.equals_exact`][polars_st.GeoExprNameSpace.equals_exact]."""
         ...
@@ -623,7 +796,16 @@ class GeoSeriesNameSpace:
         ...
 
     @dispatch
-    def relate(self, other: IntoGeoExprColumn) -> pl.Series:
+    def relate(
+        self,
+        other: IntoGeoExprColumn,
+        boundary_node_rule: Literal[
+            "mod2",
+            "endpoint",
+            "multivalent_endpoint",
+            "monovalent_endpoint",
+        ] = "mod2",
+    ) -> pl.Series:
         """See [`GeoExprNameSpace.relate`][polars_st.GeoExprNameSpace.relate]."""
         ...
 
@@ -631,11 +813,42 @@ class GeoSeriesNameSpace:
     def relate_pattern(
         self,
         other: IntoGeoExprColumn,
-        pattern: str,
+        pattern: str | pl.Expr | pl.Series,
+        boundary_node_rule: Literal[
+            "mod2",
+            "endpoint",
+            "multivalent_endpoint",
+            "monovalent_endpoint",
+        ] = "mod2",
     ) -> pl.Series:
         """See [`GeoExprNameSpace.relate_pattern`][polars_st.GeoExprNameSpace.relate_pattern]."""
         ...
 
+    @dispatch
+    def adjacent(self, other: IntoGeoExprColumn) -> pl.Series:
+        """See [`GeoExprNameSpace.adjacent`][polars_st.GeoExprNameSpace.adjacent]."""
+        ...
+
+    @dispatch
+    def touches_at_point(self, other: IntoGeoExprColumn) -> pl.Series:
+        """See [`GeoExprNameSpace.touches_at_point`][polars_st.GeoExprNameSpace.touches_at_point]."""  # noqa: E501
+        ...
+
+    @dispatch
+    def interiors_intersect(self, other: IntoGeoExprColumn) -> pl.Series:
+        """See [`GeoExprNameSpace.interiors_intersect`][polars_st.GeoExprNameSpace.interiors_intersect]."""  # noqa: E501
+        ...
+
+    @dispatch
+    def contains_boundary(self, other: IntoGeoExprColumn) -> pl.Series:
+        """See [`GeoExprNameSpace.contains_boundary`][polars_st.GeoExprNameSpace.contains_boundary]."""  # noqa: E501
+        ...
+
+    @dispatch
+    def equal_boundary(self, other: IntoGeoExprColumn) -> pl.Series:
+        """See [`GeoExprNameSpace.equal_boundary`][polars_st.GeoExprNameSpace.equal_boundary]."""  # noqa: E501
+        ...
+
     # Set operations
 
     @dispatch
@@ -657,6 +870,16 @@ class GeoSeriesNameSpace:
         """See [`GeoExprNameSpace.coverage_union`][polars_st.GeoExprNameSpace.coverage_union]."""
         ...
 
+    @dispatch
+    def coverage_is_valid(self, gap_width: float = 0.0) -> pl.Series:
+        """See [`GeoExprNameSpace.coverage_is_valid`][polars_st.GeoExprNameSpace.coverage_is_valid]."""  # noqa: E501
+        ...
+
+    @dispatch
+    def coverage_simplify(self, tolerance: float, simplify_boundary: bool = True) -> GeoSeries:
+        """See [`GeoExprNameSpace.coverage_simplify`][polars_st.GeoExprNameSpace.coverage_simplify]."""  # noqa: E501
+        ...
+
     @dispatch
     def intersection(
         self,
@@ -687,7 +910,7 @@ class GeoSeriesNameSpace:
     # Constructive operations
 
     @dispatch
-    def boundary(self) -> GeoSeries:
+    def boundary(self, grid_size: float | None = None) -> GeoSeries:
         """See [`GeoExprNameSpace.boundary`][polars_st.GeoExprNameSpace.boundary]."""
         ...
 
@@ -700,10 +923,24 @@ class GeoSeriesNameSpace:
         join_style: Literal["round", "mitre", "bevel"] = "round",
         mitre_limit: float = 5.0,
         single_sided: bool = False,
+        on_empty: Literal["empty", "null", "original"] = "empty",
+        output_precision: float | None = None,
     ) -> GeoSeries:
         """See [`GeoExprNameSpace.buffer`][polars_st.GeoExprNameSpace.buffer]."""
         ...
 
+    @dispatch
+    def buffer_with_columns(
+        self,
+        distance: IntoNumericExpr,
+        quad_segs: IntoIntegerExpr = 8,
+        cap_style: IntoExprColumn | None = None,
+        join_style: IntoExprColumn | None = None,
+        mitre_limit: IntoNumericExpr = 5.0,
+    ) -> GeoSeries:
+        """See [`GeoExprNameSpace.buffer_with_columns`][polars_st.GeoExprNameSpace.buffer_with_columns]."""  # noqa: E501
+        ...
+
     @dispatch
     def offset_curve(
         self,
@@ -711,6 +948,8 @@ class GeoSeriesNameSpace:
         quad_segs: int = 8,
         join_style: Literal["round", "mitre", "bevel"] = "round",
         mitre_limit: float = 5.0,
+        boundary: bool = False,
+        single_sided: bool = False,
     ) -> GeoSeries:
         """See [`GeoExprNameSpace.offset_curve`][polars_st.GeoExprNameSpace.offset_curve]."""
         ...
@@ -720,16 +959,30 @@ class GeoSeriesNameSpace:
         """See [`GeoExprNameSpace.centroid`][polars_st.GeoExprNameSpace.centroid]."""
         ...
 
+    @dispatch
+    def largest_part_centroid(self) -> GeoSeries:
+        """See [`GeoExprNameSpace.largest_part_centroid`][polars_st.GeoExprNameSpace.largest_part_centroid]."""  # noqa: E501
+        ...
+
     @dispatch
     def center(self) -> GeoSeries:
         """See [`GeoExprNameSpace.center`][polars_st.GeoExprNameSpace.center]."""
         ...
 
     @dispatch
-    def clip_by_rect(self, bounds: IntoExprColumn) -> GeoSeries:
+    def clip_by_rect(
+        self,
+        bounds: IntoExprColumn,
+        grid_size: float | None = None,
+    ) -> GeoSeries:
         """See [`GeoExprNameSpace.clip_by_rect`][polars_st.GeoExprNameSpace.clip_by_rect]."""
         ...
 
+    @dispatch
+    def clip(self, mask: IntoGeoExprColumn) -> GeoSeries:
+        """See [`GeoExprNameSpace.clip`][polars_st.GeoExprNameSpace.clip]."""
+        ...
+
     @dispatch
     def convex_hull(self) -> GeoSeries:
         """See [`GeoExprNameSpace.convex_hull`][polars_st.GeoExprNameSpace.convex_hull]."""
@@ -740,18 +993,37 @@ class GeoSeriesNameSpace:
         """See [`GeoExprNameSpace.concave_hull`][polars_st.GeoExprNameSpace.concave_hull]."""
         ...
 
+    @dispatch
+    def concave_hull_by_length(
+        self,
+        max_length: IntoNumericExpr,
+        allow_holes: bool = False,
+    ) -> GeoSeries:
+        """See [`GeoExprNameSpace.concave_hull_by_length`][polars_st.GeoExprNameSpace.concave_hull_by_length]."""  # noqa: E501
+        ...
+
     @dispatch
     def segmentize(self, max_segment_length: IntoNumericExpr) -> GeoSeries:
         """See [`GeoExprNameSpace.segmentize`][polars_st.GeoExprNameSpace.segmentize]."""
         ...
 
+    @dispatch
+    def densify_geodesic(self, max_segment_length: IntoNumericExpr) -> GeoSeries:
+        """See [`GeoExprNameSpace.densify_geodesic`][polars_st.GeoExprNameSpace.densify_geodesic]."""  # noqa: E501
+        ...
+
+    @dispatch
+    def subdivide(self, max_vertices: int = 256) -> pl.Series:
+        """See [`GeoExprNameSpace.subdivide`][polars_st.GeoExprNameSpace.subdivide]."""
+        ...
+
     @dispatch
     def envelope(self) -> GeoSeries:
         """See [`GeoExprNameSpace.envelope`][polars_st.GeoExprNameSpace.envelope]."""
         ...
 
     @dispatch
-    def extract_unique_points(self) -> GeoSeries:
+    def extract_unique_points(self, tolerance: IntoNumericExpr | None = None) -> GeoSeries:
         """See [`GeoExprNameSpace.extract_unique_points`][polars_st.GeoExprNameSpace.extract_unique_points]."""  # noqa: E501
         ...
 
@@ -761,7 +1033,16 @@ class GeoSeriesNameSpace:
         ...
 
     @dispatch
-    def make_valid(self) -> GeoSeries:
+    def build_area_full(self) -> pl.Series:
+        """See [`GeoExprNameSpace.build_area_full`][polars_st.GeoExprNameSpace.build_area_full]."""  # noqa: E501
+        ...
+
+    @dispatch
+    def make_valid(
+        self,
+        method: Literal["linework", "structure"] = "linework",
+        keep_collapsed: bool = True,
+    ) -> GeoSeries:
         """See [`GeoExprNameSpace.make_valid`][polars_st.GeoExprNameSpace.make_valid]."""
         ...
 
@@ -775,18 +1056,38 @@ class GeoSeriesNameSpace:
         """See [`GeoExprNameSpace.node`][polars_st.GeoExprNameSpace.node]."""
         ...
 
+    @dispatch
+    def node_prec(self, grid_size: IntoNumericExpr) -> GeoSeries:
+        """See [`GeoExprNameSpace.node_prec`][polars_st.GeoExprNameSpace.node_prec]."""
+        ...
+
     @dispatch
     def point_on_surface(self) -> GeoSeries:
         """See [`GeoExprNameSpace.point_on_surface`][polars_st.GeoExprNameSpace.point_on_surface]."""  # noqa: E501
         ...
 
+    @dispatch
+    def points_on_surface(self) -> pl.Series:
+        """See [`GeoExprNameSpace.points_on_surface`][polars_st.GeoExprNameSpace.points_on_surface]."""  # noqa: E501
+        ...
+
+    @dispatch
+    def orient(self, exterior_cw: bool = False) -> GeoSeries:
+        """See [`GeoExprNameSpace.orient`][polars_st.GeoExprNameSpace.orient]."""
+        ...
+
+    @dispatch
+    def remove_holes(self) -> GeoSeries:
+        """See [`GeoExprNameSpace.remove_holes`][polars_st.GeoExprNameSpace.remove_holes]."""
+        ...
+
     @dispatch
     def remove_repeated_points(self, tolerance: IntoNumericExpr = 0.0) -> GeoSeries:
         """See [`GeoExprNameSpace.remove_repeated_points`][polars_st.GeoExprNameSpace.remove_repeated_points]."""  # noqa: E501
         ...
 
     @dispatch
-    def reverse(self) -> GeoSeries:
+    def reverse(self, lines_only: bool = False) -> GeoSeries:
         """See [`GeoExprNameSpace.reverse`][polars_st.GeoExprNameSpace.reverse]."""
         ...
 
@@ -795,17 +1096,27 @@ class GeoSeriesNameSpace:
         self,
         tolerance: IntoNumericExpr,
         preserve_topology: bool = True,
+        preserve_endpoints: bool = False,
     ) -> GeoSeries:
         """See [`GeoExprNameSpace.simplify`][polars_st.GeoExprNameSpace.simplify]."""
         ...
 
+    @dispatch
+    def simplify_vw(
+        self,
+        tolerance: IntoNumericExpr,
+        preserve_topology: bool = True,
+    ) -> GeoSeries:
+        """See [`GeoExprNameSpace.simplify_vw`][polars_st.GeoExprNameSpace.simplify_vw]."""
+        ...
+
     @dispatch
     def force_2d(self) -> GeoSeries:
         """See [`GeoExprNameSpace.force_2d`][polars_st.GeoExprNameSpace.force_2d]."""
         ...
 
     @dispatch
-    def force_3d(self, z: IntoNumericExpr = 0.0) -> GeoSeries:
+    def force_3d(self, z: IntoNumericExpr = 0.0, fill_nan: bool = False) -> GeoSeries:
         """See [`GeoExprNameSpace.force_3d`][polars_st.GeoExprNameSpace.force_3d]."""
         ...
 
@@ -819,11 +1130,22 @@ class GeoSeriesNameSpace:
         """See [`GeoExprNameSpace.minimum_rotated_rectangle`][polars_st.GeoExprNameSpace.minimum_rotated_rectangle]."""  # noqa: E501
         ...
 
+    @dispatch
+    def minimum_width(self) -> GeoSeries:
+        """See [`GeoExprNameSpace.minimum_width`][polars_st.GeoExprNameSpace.minimum_width]."""
+        ...
+
+    @dispatch
+    def minimum_clearance_line(self) -> GeoSeries:
+        """See [`GeoExprNameSpace.minimum_clearance_line`][polars_st.GeoExprNameSpace.minimum_clearance_line]."""  # noqa: E501
+        ...
+
     @dispatch
     def snap(
         self,
         other: IntoGeoExprColumn,
         tolerance: IntoNumericExpr,
+        grid_size: float | None = None,
     ) -> GeoSeries:
         """See [`GeoExprNameSpace.snap`][polars_st.GeoExprNameSpace.snap]."""
         ...
@@ -833,10 +1155,36 @@ class GeoSeriesNameSpace:
         """See [`GeoExprNameSpace.shortest_line`][polars_st.GeoExprNameSpace.shortest_line]."""
         ...
 
+    @dispatch
+    def nearest_points(self, other: IntoGeoExprColumn) -> pl.Series:
+        """See [`GeoExprNameSpace.nearest_points`][polars_st.GeoExprNameSpace.nearest_points]."""  # noqa: E501
+        ...
+
+    @dispatch
+    def nearest_point_in_set(self, candidates: IntoGeoExprColumn) -> pl.Series:
+        """See [`GeoExprNameSpace.nearest_point_in_set`][polars_st.GeoExprNameSpace.nearest_point_in_set]."""  # noqa: E501
+        ...
+
     # Affine transforms
 
     @dispatch
-    def affine_transform(self, matrix: IntoExprColumn | Sequence[float]) -> GeoSeries:
+    def apply_coordinates(
+        self,
+        function: Callable[[float, float, float], tuple[float, float, float]]
+        | Callable[[pl.Series, pl.Series, pl.Series], tuple[Sequence[float], ...]],
+        *,
+        vectorized: bool = False,
+    ) -> GeoSeries:
+        """See [`GeoExprNameSpace.apply_coordinates`][polars_st.GeoExprNameSpace.apply_coordinates]."""  # noqa: E501
+        ...
+
+    @dispatch
+    def affine_transform(
+        self,
+        matrix: IntoExprColumn | Sequence[float],
+        *,
+        keep_srid: bool = True,
+    ) -> GeoSeries:
         """See [`GeoExprNameSpace.affine_transform`][polars_st.GeoExprNameSpace.affine_transform]."""  # noqa: E501
         ...
 
@@ -854,7 +1202,7 @@ class GeoSeriesNameSpace:
     def rotate(
         self,
         angle: IntoNumericExpr,
-        origin: Literal["center", "centroid"] | Sequence[float] = "center",
+        origin: Literal["center", "centroid"] | Sequence[float] | pl.Expr | pl.Series = "center",
     ) -> GeoSeries:
         """See [`GeoExprNameSpace.rotate`][polars_st.GeoExprNameSpace.rotate]."""
         ...
@@ -865,7 +1213,7 @@ class GeoSeriesNameSpace:
         x: IntoNumericExpr = 1.0,
         y: IntoNumericExpr = 1.0,
         z: IntoNumericExpr = 1.0,
-        origin: Literal["center", "centroid"] | Sequence[float] = "center",
+        origin: Literal["center", "centroid"] | Sequence[float] | pl.Expr | pl.Series = "center",
     ) -> GeoSeries:
         """See [`GeoExprNameSpace.scale`][polars_st.GeoExprNameSpace.scale]."""
         ...
@@ -876,11 +1224,24 @@ class GeoSeriesNameSpace:
         x: IntoNumericExpr = 0.0,
         y: IntoNumericExpr = 0.0,
         z: IntoNumericExpr = 0.0,
-        origin: Literal["center", "centroid"] | Sequence[float] = "center",
+        origin: Literal["center", "centroid"] | Sequence[float] | pl.Expr | pl.Series = "center",
     ) -> GeoSeries:
         """See [`GeoExprNameSpace.skew`][polars_st.GeoExprNameSpace.skew]."""
         ...
 
+    @dispatch
+    def snap_to_grid(
+        self,
+        size_x: IntoNumericExpr,
+        size_y: IntoNumericExpr | None = None,
+        size_z: IntoNumericExpr = 0.0,
+        origin_x: IntoNumericExpr = 0.0,
+        origin_y: IntoNumericExpr = 0.0,
+        origin_z: IntoNumericExpr = 0.0,
+    ) -> GeoSeries:
+        """See [`GeoExprNameSpace.snap_to_grid`][polars_st.GeoExprNameSpace.snap_to_grid]."""
+        ...
+
     # LineString operations
 
     @dispatch
@@ -892,6 +1253,15 @@ class GeoSeriesNameSpace:
         """See [`GeoExprNameSpace.interpolate`][polars_st.GeoExprNameSpace.interpolate]."""
         ...
 
+    @dispatch
+    def interpolate_many(
+        self,
+        distances: IntoExprColumn | Sequence[float],
+        normalized: bool = False,
+    ) -> pl.Series:
+        """See [`GeoExprNameSpace.interpolate_many`][polars_st.GeoExprNameSpace.interpolate_many]."""  # noqa: E501
+        ...
+
     @dispatch
     def project(
         self,
@@ -906,12 +1276,17 @@ class GeoSeriesNameSpace:
         self,
         start: IntoNumericExpr,
         end: IntoNumericExpr,
+        normalized: bool = True,
     ) -> GeoSeries:
         """See [`GeoExprNameSpace.substring`][polars_st.GeoExprNameSpace.substring]."""
         ...
 
     @dispatch
-    def line_merge(self, directed: bool = False) -> GeoSeries:
+    def line_merge(
+        self,
+        directed: bool = False,
+        tolerance: IntoNumericExpr | None = None,
+    ) -> GeoSeries:
         """See [`GeoExprNameSpace.line_merge`][polars_st.GeoExprNameSpace.line_merge]."""
         ...
 
@@ -919,10 +1294,16 @@ class GeoSeriesNameSpace:
     def shared_paths(
         self,
         other: IntoGeoExprColumn,
+        direction: Literal["both", "forward", "backward"] = "both",
     ) -> GeoSeries:
         """See [`GeoExprNameSpace.shared_paths`][polars_st.GeoExprNameSpace.shared_paths]."""
         ...
 
+    @dispatch
+    def split(self, blade: IntoGeoExprColumn) -> GeoSeries:
+        """See [`GeoExprNameSpace.split`][polars_st.GeoExprNameSpace.split]."""
+        ...
+
     # Aggregations
 
     @dispatch
@@ -931,10 +1312,24 @@ class GeoSeriesNameSpace:
         ...
 
     @dispatch
-    def collect(self, into: GeometryType | None = None) -> GeoSeries:
+    def collect(
+        self,
+        into: GeometryType | None = None,
+        on_mismatch: Literal["raise", "skip", "wrap"] = "raise",
+    ) -> GeoSeries:
         """See [`GeoExprNameSpace.collect`][polars_st.GeoExprNameSpace.collect]."""
         ...
 
+    @dispatch
+    def collect_line(self) -> GeoSeries:
+        """See [`GeoExprNameSpace.collect_line`][polars_st.GeoExprNameSpace.collect_line]."""
+        ...
+
+    @dispatch
+    def collect_polygon(self) -> GeoSeries:
+        """See [`GeoExprNameSpace.collect_polygon`][polars_st.GeoExprNameSpace.collect_polygon]."""  # noqa: E501
+        ...
+
     @dispatch
     def union_all(self, grid_size: float | None = None) -> GeoSeries:
         """See [`GeoExprNameSpace.union_all`][polars_st.GeoExprNameSpace.union_all]."""
@@ -965,16 +1360,31 @@ class GeoSeriesNameSpace:
         """See [`GeoExprNameSpace.polygonize`][polars_st.GeoExprNameSpace.polygonize]."""
         ...
 
+    @dispatch
+    def polygonize_full(self) -> pl.Series:
+        """See [`GeoExprNameSpace.polygonize_full`][polars_st.GeoExprNameSpace.polygonize_full]."""  # noqa: E501
+        ...
+
     @dispatch
     def voronoi_polygons(
         self,
         tolerance: float = 0.0,
         extend_to: bytes | None = None,
         only_edges: bool = False,
+        ordered: bool = False,
     ) -> GeoSeries:
         """See [`GeoExprNameSpace.voronoi_polygons`][polars_st.GeoExprNameSpace.voronoi_polygons]."""  # noqa: E501
         ...
 
+    @dispatch
+    def voronoi_with_index(
+        self,
+        tolerance: float = 0.0,
+        extend_to: bytes | None = None,
+    ) -> pl.Series:
+        """See [`GeoExprNameSpace.voronoi_with_index`][polars_st.GeoExprNameSpace.voronoi_with_index]."""  # noqa: E501
+        ...
+
     @dispatch
     def delaunay_triangles(
         self,
@@ -984,6 +1394,26 @@ class GeoSeriesNameSpace:
         """See [`GeoExprNameSpace.delaunay_triangles`][polars_st.GeoExprNameSpace.delaunay_triangles]."""  # noqa: E501
         ...
 
+    @dispatch
+    def constrained_delaunay(self, only_edges: bool = False) -> GeoSeries:
+        """See [`GeoExprNameSpace.constrained_delaunay`][polars_st.GeoExprNameSpace.constrained_delaunay]."""  # noqa: E501
+        ...
+
+    @dispatch
+    def constrained_delaunay_all(self, only_edges: bool = False) -> GeoSeries:
+        """See [`GeoExprNameSpace.constrained_delaunay_all`][polars_st.GeoExprNameSpace.constrained_delaunay_all]."""  # noqa: E501
+        ...
+
+    @dispatch
+    def unique_geometries(self, grid_size: float | None = None) -> pl.Series:
+        """See [`GeoExprNameSpace.unique_geometries`][polars_st.GeoExprNameSpace.unique_geometries]."""  # noqa: E501
+        ...
+
+    @dispatch
+    def geometry_hash(self, grid_size: float | None = None) -> pl.Series:
+        """See [`GeoExprNameSpace.geometry_hash`][polars_st.GeoExprNameSpace.geometry_hash]."""
+        ...
+
     def plot(self, **kwargs: Unpack[MarkConfigKwds]) -> alt.Chart:
         """Draw map plot.
 
@@ -1,7 +1,7 @@
 from __future__ import annotations
 
 from pathlib import Path
-from typing import TYPE_CHECKING, cast
+from typing import TYPE_CHECKING, Literal, cast
 
 import polars as pl
 from polars._utils.parse import parse_into_expression
@@ -12,21 +12,26 @@ if TYPE_CHECKING:
     from polars._typing import IntoExprColumn
 
     from polars_st.geoexpr import GeoExpr
-    from polars_st.typing import IntoIntegerExpr
+    from polars_st.typing import IntoIntegerExpr, IntoNumericExpr
 
 
 __all__ = [
+    "box",
     "circularstring",
     "circularstring",
     "from_ewkt",
     "from_geojson",
     "from_shapely",
     "from_wkb",
+    "from_wkb_base64",
     "from_wkt",
+    "geohash_decode",
+    "h3_to_polygon",
     "linestring",
     "multilinestring",
     "multipoint",
     "point",
+    "points_from_coords",
     "polygon",
     "rectangle",
 ]
@@ -79,6 +84,46 @@ def point(coords: IntoExprColumn, srid: IntoIntegerExpr = 0) -> GeoExpr:
     ).pipe(lambda e: cast("GeoExpr", e))
 
 
+def points_from_coords(
+    x: IntoNumericExpr,
+    y: IntoNumericExpr,
+    z: IntoNumericExpr | None = None,
+    m: IntoNumericExpr | None = None,
+    srid: IntoIntegerExpr = 0,
+) -> GeoExpr:
+    """Create Point geometries from separate coordinates columns.
+
+    Points have a Z or M dimension when `z` or `m` is given. Rows with a null
+    coordinate return null.
+
+    Examples:
+        >>> df = pl.DataFrame({
+        ...     "lon": [0.0, 1.0, None],
+        ...     "lat": [2.0, 3.0, 4.0],
+        ... })
+        >>> df = df.select(geometry=st.points_from_coords("lon", "lat", srid=4326))
+        >>> df.st.to_wkt()
+        shape: (3, 1)
+        ┌─────────────┐
+        │ geometry    │
+        │ ---         │
+        │ str         │
+        ╞═════════════╡
+        │ POINT (0 2) │
+        │ POINT (1 3) │
+        │ null        │
+        └─────────────┘
+    """
+    null = pl.lit(None, dtype=pl.Float64)
+    return register_plugin_function(
+        plugin_path=Path(__file__).parent,
+        function_name="points_from_coords",
+        args=[x, y, null if z is None else z, null if m is None else m, srid],
+        kwargs={"has_z": z is not None, "has_m": m is not None},
+        is_elementwise=True,
+    ).pipe(lambda e: cast("GeoExpr", e))
+
+
 def multipoint(coords: IntoExprColumn, srid: IntoIntegerExpr = 0) -> GeoExpr:
     """Create MultiPoint geometries from list of coordinates.
 
@@ -269,9 +314,55 @@ def rectangle(bounds: IntoExprColumn, srid: IntoIntegerExpr = 0) -> GeoExpr:
     ).pipe(lambda e: cast("GeoExpr", e))
 
 
-def from_wkb(expr: IntoExprColumn) -> GeoExpr:
+def box(
+    xmin: IntoNumericExpr,
+    ymin: IntoNumericExpr,
+    xmax: IntoNumericExpr,
+    ymax: IntoNumericExpr,
+    srid: IntoIntegerExpr = 0,
+) -> GeoExpr:
+    """Create Polygon geometries from separate bounds columns.
+
+    Degenerate boxes collapse to a LineString (zero width or height) or a Point
+    (zero width and height). Rows with a null or NaN bound return null.
+
+    Examples:
+        >>> df = pl.DataFrame({
+        ...     "xmin": [0.0, 1.0, 1.0, float("nan")],
+        ...     "ymin": [0.0, 0.0, 2.0, 0.0],
+        ...     "xmax": [1.0, 1.0, 1.0, 1.0],
+        ...     "ymax": [1.0, 3.0, 2.0, 1.0],
+        ... })
+        >>> df = df.select(geometry=st.box("xmin", "ymin", "xmax", "ymax"))
+        >>> df.st.to_wkt()
+        shape: (4, 1)
+        ┌─────────────────────────────────┐
+        │ geometry                        │
+        │ ---                             │
+        │ str                             │
+        ╞═════════════════════════════════╡
+        │ POLYGON ((0 0, 1 0, 1 1, 0 1, … │
+        │ LINESTRING (1 0, 1 3)           │
+        │ POINT (1 2)                     │
+        │ null                            │
+        └─────────────────────────────────┘
+    """
+    return register_plugin_function(
+        plugin_path=Path(__file__).parent,
+        function_name="box_",
+        args=[pl.concat_arr(xmin, ymin, xmax, ymax).cast(pl.Array(pl.Float64, 4)), srid],
+        is_elementwise=True,
+    ).pipe(lambda e: cast("GeoExpr", e))
+
+
+def from_wkb(expr: IntoExprColumn, srid: int | None = None) -> GeoExpr:
     """Parse geometries from Well-Known Binary (WKB) representation.
 
+    Args:
+        expr: Expression with the WKB or EWKB data.
+        srid: SRID to assign to the parsed geometries. By default, the SRID from EWKB
+            input is kept, and other geometries get an SRID of 0.
+
     Examples:
         >>> df = pl.read_database(
         ...     query="SELECT ST_AsEWKB(geom) AS geometry FROM test_data",
@@ -283,49 +374,129 @@ def from_wkb(expr: IntoExprColumn) -> GeoExpr:
         plugin_path=Path(__file__).parent,
         function_name="from_wkb",
         args=[expr],
+        kwargs={"srid": srid},
         is_elementwise=True,
     ).pipe(lambda e: cast("GeoExpr", e))
 
 
-def from_wkt(expr: IntoExprColumn) -> GeoExpr:
+def from_wkb_base64(
+    expr: IntoExprColumn,
+    srid: int | None = None,
+    alphabet: Literal["standard", "url_safe"] = "standard",
+) -> GeoExpr:
+    """Parse geometries from base64 encoded Well-Known Binary (WKB) representation.
+
+    Args:
+        expr: Expression with the base64 encoded WKB or EWKB data.
+        srid: SRID to assign to the parsed geometries. By default, the SRID from EWKB
+            input is kept, and other geometries get an SRID of 0.
+        alphabet: Base64 alphabet of the input, either the standard one or the URL-safe
+            one using `-` and `_`. Padding is optional.
+
+    Examples:
+        >>> df = pl.DataFrame({"geometry": ["AQEAAAAAAAAAAADwPwAAAAAAAABA"]})
+        >>> df.select(st.from_wkb_base64("geometry")).select(st.to_wkt()).item()
+        'POINT (1 2)'
+    """
+    return register_plugin_function(
+        plugin_path=Path(__file__).parent,
+        function_name="from_wkb_base64",
+        args=[expr],
+        kwargs={"srid": srid, "alphabet": alphabet},
+        is_elementwise=True,
+    ).pipe(lambda e: cast("GeoExpr", e))
+
+
+def from_wkt(expr: IntoExprColumn, srid: int | None = None) -> GeoExpr:
     """Parse geometries from Well-Known Text (WKT) representation.
 
+    Args:
+        expr: Expression with the WKT data.
+        srid: SRID to assign to the parsed geometries. Defaults to 0.
+
     Examples:
         >>> df = pl.Series("geometry", [
         ...     "POINT(0 0)",
         ...     "POINT(1 2)",
         ... ]).to_frame()
-        >>> gdf = df.select(st.from_wkt("geometry"))
+        >>> gdf = df.select(st.from_wkt("geometry", srid=4326))
+        >>> gdf.select(st.srid())
+        shape: (2, 1)
+        ┌──────────┐
+        │ geometry │
+        │ ---      │
+        │ i32      │
+        ╞══════════╡
+        │ 4326     │
+        │ 4326     │
+        └──────────┘
     """
     return register_plugin_function(
         plugin_path=Path(__file__).parent,
         function_name="from_wkt",
         args=[expr],
+        kwargs={"srid": srid},
         is_elementwise=True,
     ).pipe(lambda e: cast("GeoExpr", e))
 
 
-def from_ewkt(expr: IntoExprColumn) -> GeoExpr:
+def from_ewkt(expr: IntoExprColumn, srid: int | None = None) -> GeoExpr:
     """Parse geometries from Extended Well-Known Text (EWKT) representation.
 
+    The `SRID=<srid>;` prefix is optional: geometries without it are parsed as
+    plain WKT, with an SRID of 0.
+
+    Args:
+        expr: Expression with the EWKT data.
+        srid: SRID to assign to the parsed geometries, overriding the SRID prefix
+            when set.
+
     Examples:
         >>> df = pl.Series("geometry", [
         ...     "SRID=4326;POINT(0 0)",
-        ...     "SRID=3857;POINT(1 2)",
+        ...     "POINT(1 2)",
         ... ]).to_frame()
         >>> gdf = df.select(st.from_ewkt("geometry"))
+        >>> gdf.select(st.srid())
+        shape: (2, 1)
+        ┌──────────┐
+        │ geometry │
+        │ ---      │
+        │ i32      │
+        ╞══════════╡
+        │ 4326     │
+        │ 0        │
+        └──────────┘
     """
     return register_plugin_function(
         plugin_path=Path(__file__).parent,
         function_name="from_ewkt",
         args=[expr],
+        kwargs={"srid": srid},
         is_elementwise=True,
     ).pipe(lambda e: cast("GeoExpr", e))
 
 
-def from_geojson(expr: IntoExprColumn) -> GeoExpr:
+def from_geojson(
+    expr: IntoExprColumn,
+    srid: int | None = None,
+    on_feature_collection: Literal["raise", "collect", "explode"] = "raise",
+) -> GeoExpr:
     """Parse geometries from GeoJSON representation.
 
+    Geometries and Features are supported. For a Feature, its `"geometry"` member is
+    parsed.
+
+    Args:
+        expr: Expression with the GeoJSON data.
+        srid: SRID to assign to the parsed geometries. Defaults to 0.
+        on_feature_collection: What to do with rows holding a FeatureCollection:
+
+            - `"raise"`: raise an error.
+            - `"collect"`: return a GeometryCollection of the features geometries.
+            - `"explode"`: return one row per feature, in order. The result may
+                then have more rows than the input.
+
     Examples:
         >>> df = pl.Series("geometry", [
         ...     '{"type": "Point", "coordinates": [0, 0]}',
@@ -343,10 +514,75 @@ def from_geojson(expr: IntoExprColumn) -> GeoExpr:
         │ POINT (1 2) │
         └─────────────┘
     """
-    return register_plugin_function(
+    parsed = register_plugin_function(
         plugin_path=Path(__file__).parent,
         function_name="from_geojson",
         args=[expr],
+        kwargs={
+            "srid": srid,
+            "allow_feature_collection": on_feature_collection != "raise",
+        },
+        is_elementwise=True,
+    )
+    if on_feature_collection == "explode":
+        is_feature_collection = (
+            wrap_expr(parse_into_expression(expr)).str.json_path_match("$.type")
+            == "FeatureCollection"
+        )
+        parsed = (
+            pl.when(is_feature_collection)
+            .then(cast("GeoExpr", parsed).st.parts())
+            .otherwise(pl.concat_list(parsed))
+            .explode()
+        )
+    return cast("GeoExpr", parsed)
+
+
+def geohash_decode(expr: IntoExprColumn) -> GeoExpr:
+    """Parse geohash strings as the Point at the center of their cell, with SRID 4326.
+
+    Examples:
+        >>> df = pl.Series("geometry", ["ezs42", "u09tv"]).to_frame()
+        >>> gdf = df.select(st.geohash_decode("geometry"))
+        >>> gdf.select(st.srid(), st.x().round(2).alias("x"), st.y().round(2).alias("y"))
+        shape: (2, 3)
+        ┌──────────┬──────┬───────┐
+        │ geometry ┆ x    ┆ y     │
+        │ ---      ┆ ---  ┆ ---   │
+        │ i32      ┆ f64  ┆ f64   │
+        ╞══════════╪══════╪═══════╡
+        │ 4326     ┆ -5.6 ┆ 42.6  │
+        │ 4326     ┆ 2.35 ┆ 48.85 │
+        └──────────┴──────┴───────┘
+    """
+    return register_plugin_function(
+        plugin_path=Path(__file__).parent,
+        function_name="geohash_decode",
+        args=[expr],
+        is_elementwise=True,
+    ).pipe(lambda e: cast("GeoExpr", e))
+
+
+def h3_to_polygon(expr: IntoExprColumn) -> GeoExpr:
+    """Create the Polygon boundary of H3 cells from their index, with SRID 4326.
+
+    Examples:
+        >>> df = pl.DataFrame({"cell": [0x872830828FFFFFF]}, schema={"cell": pl.UInt64})
+        >>> gdf = df.select(st.h3_to_polygon("cell"))
+        >>> gdf.select(st.srid(), st.geometry_type().alias("type"))
+        shape: (1, 2)
+        ┌──────────┬─────────┐
+        │ geometry ┆ type    │
+        │ ---      ┆ ---     │
+        │ i32      ┆ enum    │
+        ╞══════════╪═════════╡
+        │ 4326     ┆ Polygon │
+        └──────────┴─────────┘
+    """
+    return register_plugin_function(
+        plugin_path=Path(__file__).parent,
+        function_name="h3_to_polygon",
+        args=[expr],
         is_elementwise=True,
     ).pipe(lambda e: cast("GeoExpr", e))
 
@@ -5,7 +5,7 @@ from typing import TYPE_CHECKING, Literal
 from polars_st.selectors import geom
 
 if TYPE_CHECKING:
-    from collections.abc import Sequence
+    from collections.abc import Callable, Sequence
 
     import polars as pl
 
@@ -21,29 +21,43 @@ if TYPE_CHECKING:
 
 __all__ = [
     "affine_transform",
+    "apply_coordinates",
     "area",
     "boundary",
     "bounds",
     "buffer",
+    "buffer_with_columns",
     "build_area",
+    "build_area_full",
     "cast",
     "center",
     "centroid",
     "clip_by_rect",
     "collect",
+    "collect_line",
+    "collect_polygon",
     "concave_hull",
+    "concave_hull_by_length",
+    "constrained_delaunay",
+    "constrained_delaunay_all",
     "convex_hull",
     "coordinate_dimension",
     "coordinates",
+    "coordinates_struct",
     "count_coordinates",
+    "count_coordinates_per_part",
     "count_geometries",
     "count_interior_rings",
     "count_points",
+    "coverage_is_valid",
+    "coverage_simplify",
     "coverage_union",
     "coverage_union_all",
     "delaunay_triangles",
+    "densify_geodesic",
     "difference_all",
     "dimensions",
+    "end_point",
     "envelope",
     "exterior_ring",
     "extract_unique_points",
@@ -53,11 +67,15 @@ __all__ = [
     "geometry_type",
     "get_geometry",
     "get_interior_ring",
+    "geohash_encode",
     "get_point",
+    "h3_cell",
     "has_m",
+    "has_srid",
     "has_z",
     "interior_rings",
     "interpolate",
+    "interpolate_many",
     "intersection_all",
     "is_ccw",
     "is_closed",
@@ -65,21 +83,33 @@ __all__ = [
     "is_ring",
     "is_simple",
     "is_valid",
+    "is_valid_detail",
     "is_valid_reason",
+    "largest_part_centroid",
     "length",
+    "length_3d",
     "line_merge",
     "m",
     "make_valid",
+    "minimum_bounding_radius",
     "minimum_clearance",
+    "minimum_clearance_line",
     "minimum_rotated_rectangle",
+    "minimum_width",
     "multi",
     "node",
+    "node_prec",
     "normalize",
+    "num_vertices",
     "offset_curve",
+    "orient",
     "parts",
     "point_on_surface",
+    "points_on_surface",
     "polygonize",
+    "polygonize_full",
     "precision",
+    "remove_holes",
     "remove_repeated_points",
     "reverse",
     "rotate",
@@ -88,16 +118,28 @@ __all__ = [
     "set_precision",
     "set_srid",
     "simplify",
+    "simplify_vw",
     "skew",
+    "snap_to_grid",
     "srid",
+    "srid_authority",
+    "start_point",
+    "strip_srid",
+    "subdivide",
     "substring",
     "symmetric_difference_all",
+    "to_crs",
     "to_dict",
     "to_ewkt",
+    "to_geojson_feature_collection",
     "to_geojson",
+    "to_gml",
+    "to_kml",
     "to_shapely",
     "to_srid",
+    "to_svg",
     "to_wkb",
+    "to_wkb_base64",
     "to_wkt",
     "total_bounds",
     "translate",
@@ -140,11 +182,21 @@ def length(*columns: str) -> pl.Expr:
     return geom(*columns).st.length()
 
 
+def length_3d(*columns: str) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[length_3d()][polars_st.GeoExprNameSpace.length_3d]</code>."""  # noqa: E501
+    return geom(*columns).st.length_3d()
+
+
 def minimum_clearance(*columns: str) -> pl.Expr:
     """Syntactic sugar for <code>st.geom(columns).st.[minimum_clearance()][polars_st.GeoExprNameSpace.minimum_clearance]</code>."""  # noqa: E501
     return geom(*columns).st.minimum_clearance()
 
 
+def minimum_bounding_radius(*columns: str) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[minimum_bounding_radius()][polars_st.GeoExprNameSpace.minimum_bounding_radius]</code>."""  # noqa: E501
+    return geom(*columns).st.minimum_bounding_radius()
+
+
 def x(*columns: str) -> pl.Expr:
     """Syntactic sugar for <code>st.geom(columns).st.[x()][polars_st.GeoExprNameSpace.x]</code>."""
     return geom(*columns).st.x()
@@ -170,9 +222,28 @@ def count_coordinates(*columns: str) -> pl.Expr:
     return geom(*columns).st.count_coordinates()
 
 
-def coordinates(*columns: str, output_dimension: Literal[2, 3] | None = None) -> pl.Expr:
+def num_vertices(*columns: str) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[num_vertices()][polars_st.GeoExprNameSpace.num_vertices]</code>."""  # noqa: E501
+    return geom(*columns).st.num_vertices()
+
+
+def count_coordinates_per_part(*columns: str) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[count_coordinates_per_part()][polars_st.GeoExprNameSpace.count_coordinates_per_part]</code>."""  # noqa: E501
+    return geom(*columns).st.count_coordinates_per_part()
+
+
+def coordinates(
+    *columns: str,
+    output_dimension: Literal[2, 3] | None = None,
+    structured: bool = False,
+) -> pl.Expr:
     """Syntactic sugar for <code>st.geom(columns).st.[coordinates(...)][polars_st.GeoExprNameSpace.coordinates]</code>."""  # noqa: E501
-    return geom(*columns).st.coordinates(output_dimension)
+    return geom(*columns).st.coordinates(output_dimension, structured)
+
+
+def coordinates_struct(*columns: str) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[coordinates_struct()][polars_st.GeoExprNameSpace.coordinates_struct]</code>."""  # noqa: E501
+    return geom(*columns).st.coordinates_struct()
 
 
 def exterior_ring(*columns: str) -> GeoExpr:
@@ -215,6 +286,16 @@ def get_point(*columns: str, index: IntoIntegerExpr) -> GeoExpr:
     return geom(*columns).st.get_point(index)
 
 
+def start_point(*columns: str) -> GeoExpr:
+    """Syntactic sugar for <code>st.geom(columns).st.[start_point()][polars_st.GeoExprNameSpace.start_point]</code>."""  # noqa: E501
+    return geom(*columns).st.start_point()
+
+
+def end_point(*columns: str) -> GeoExpr:
+    """Syntactic sugar for <code>st.geom(columns).st.[end_point()][polars_st.GeoExprNameSpace.end_point]</code>."""  # noqa: E501
+    return geom(*columns).st.end_point()
+
+
 def parts(*columns: str) -> pl.Expr:
     """Syntactic sugar for <code>st.geom(columns).st.[parts()][polars_st.GeoExprNameSpace.parts]</code>."""  # noqa: E501
     return geom(*columns).st.parts()
@@ -239,14 +320,39 @@ def srid(*columns: str) -> pl.Expr:
     return geom(*columns).st.srid()
 
 
+def srid_authority(*columns: str) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[srid_authority()][polars_st.GeoExprNameSpace.srid_authority]</code>."""  # noqa: E501
+    return geom(*columns).st.srid_authority()
+
+
+def has_srid(*columns: str) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[has_srid()][polars_st.GeoExprNameSpace.has_srid]</code>."""  # noqa: E501
+    return geom(*columns).st.has_srid()
+
+
+def strip_srid(*columns: str) -> GeoExpr:
+    """Syntactic sugar for <code>st.geom(columns).st.[strip_srid()][polars_st.GeoExprNameSpace.strip_srid]</code>."""  # noqa: E501
+    return geom(*columns).st.strip_srid()
+
+
 def set_srid(*columns: str, srid: IntoIntegerExpr) -> GeoExpr:
     """Syntactic sugar for <code>st.geom(columns).st.[set_srid(...)][polars_st.GeoExprNameSpace.set_srid]</code>."""  # noqa: E501
     return geom(*columns).st.set_srid(srid)
 
 
-def to_srid(*columns: str, srid: IntoIntegerExpr) -> GeoExpr:
+def to_srid(
+    *columns: str,
+    srid: IntoIntegerExpr,
+    always_xy: bool = True,
+    on_error: Literal["raise", "null"] = "raise",
+) -> GeoExpr:
     """Syntactic sugar for <code>st.geom(columns).st.[to_srid(...)][polars_st.GeoExprNameSpace.to_srid]</code>."""  # noqa: E501
-    return geom(*columns).st.to_srid(srid)
+    return geom(*columns).st.to_srid(srid, always_xy, on_error)
+
+
+def to_crs(*columns: str, source: str, target: str, always_xy: bool = True) -> GeoExpr:
+    """Syntactic sugar for <code>st.geom(columns).st.[to_crs(...)][polars_st.GeoExprNameSpace.to_crs]</code>."""  # noqa: E501
+    return geom(*columns).st.to_crs(source, target, always_xy)
 
 
 def to_wkt(
@@ -281,9 +387,47 @@ def to_wkb(
     return geom(*columns).st.to_wkb(output_dimension, byte_order, include_srid)
 
 
-def to_geojson(*columns: str, indent: int | None = None) -> pl.Expr:
+def to_wkb_base64(
+    *columns: str,
+    alphabet: Literal["standard", "url_safe"] = "standard",
+) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[to_wkb_base64(...)][polars_st.GeoExprNameSpace.to_wkb_base64]</code>."""  # noqa: E501
+    return geom(*columns).st.to_wkb_base64(alphabet)
+
+
+def to_geojson(
+    *columns: str,
+    indent: int | None = None,
+    output_dimension: Literal[2, 3] = 3,
+    rounding_precision: int | None = None,
+) -> pl.Expr:
     """Syntactic sugar for <code>st.geom(columns).st.[to_geojson(...)][polars_st.GeoExprNameSpace.to_geojson]</code>."""  # noqa: E501
-    return geom(*columns).st.to_geojson(indent)
+    return geom(*columns).st.to_geojson(indent, output_dimension, rounding_precision)
+
+
+def to_kml(*columns: str) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[to_kml(...)][polars_st.GeoExprNameSpace.to_kml]</code>."""  # noqa: E501
+    return geom(*columns).st.to_kml()
+
+
+def to_gml(*columns: str, version: Literal[2, 3] = 3) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[to_gml(...)][polars_st.GeoExprNameSpace.to_gml]</code>."""  # noqa: E501
+    return geom(*columns).st.to_gml(version)
+
+
+def to_svg(*columns: str, rounding_precision: int | None = None) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[to_svg(...)][polars_st.GeoExprNameSpace.to_svg]</code>."""  # noqa: E501
+    return geom(*columns).st.to_svg(rounding_precision)
+
+
+def geohash_encode(*columns: str, precision: int = 12, use_centroid: bool = False) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[geohash_encode(...)][polars_st.GeoExprNameSpace.geohash_encode]</code>."""  # noqa: E501
+    return geom(*columns).st.geohash_encode(precision, use_centroid)
+
+
+def h3_cell(*columns: str, resolution: int) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[h3_cell(...)][polars_st.GeoExprNameSpace.h3_cell]</code>."""  # noqa: E501
+    return geom(*columns).st.h3_cell(resolution)
 
 
 def to_shapely(*columns: str) -> pl.Expr:
@@ -351,6 +495,11 @@ def is_valid_reason(*columns: str) -> pl.Expr:
     return geom(*columns).st.is_valid_reason()
 
 
+def is_valid_detail(*columns: str) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[is_valid_detail()][polars_st.GeoExprNameSpace.is_valid_detail]</code>."""  # noqa: E501
+    return geom(*columns).st.is_valid_detail()
+
+
 def unary_union(*columns: str, grid_size: float | None = None) -> GeoExpr:
     """Syntactic sugar for <code>st.geom(columns).st.[unary_union(...)][polars_st.GeoExprNameSpace.unary_union]</code>."""  # noqa: E501
     return geom(*columns).st.unary_union(grid_size)
@@ -361,9 +510,23 @@ def coverage_union(*columns: str) -> GeoExpr:
     return geom(*columns).st.coverage_union()
 
 
-def boundary(*columns: str) -> GeoExpr:
-    """Syntactic sugar for <code>st.geom(columns).st.[boundary()][polars_st.GeoExprNameSpace.boundary]</code>."""  # noqa: E501
-    return geom(*columns).st.boundary()
+def coverage_is_valid(*columns: str, gap_width: float = 0.0) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[coverage_is_valid(...)][polars_st.GeoExprNameSpace.coverage_is_valid]</code>."""  # noqa: E501
+    return geom(*columns).st.coverage_is_valid(gap_width)
+
+
+def coverage_simplify(
+    *columns: str,
+    tolerance: float,
+    simplify_boundary: bool = True,
+) -> GeoExpr:
+    """Syntactic sugar for <code>st.geom(columns).st.[coverage_simplify(...)][polars_st.GeoExprNameSpace.coverage_simplify]</code>."""  # noqa: E501
+    return geom(*columns).st.coverage_simplify(tolerance, simplify_boundary)
+
+
+def boundary(*columns: str, grid_size: float | None = None) -> GeoExpr:
+    """Syntactic sugar for <code>st.geom(columns).st.[boundary(...)][polars_st.GeoExprNameSpace.boundary]</code>."""  # noqa: E501
+    return geom(*columns).st.boundary(grid_size)
 
 
 def buffer(
@@ -374,6 +537,8 @@ def buffer(
     join_style: Literal["round", "mitre", "bevel"] = "round",
     mitre_limit: float = 5.0,
     single_sided: bool = False,
+    on_empty: Literal["empty", "null", "original"] = "empty",
+    output_precision: float | None = None,
 ) -> GeoExpr:
     """Syntactic sugar for <code>st.geom(columns).st.[buffer(...)][polars_st.GeoExprNameSpace.buffer]</code>."""  # noqa: E501
     return geom(*columns).st.buffer(
@@ -383,6 +548,26 @@ def buffer(
         join_style,
         mitre_limit,
         single_sided,
+        on_empty,
+        output_precision,
+    )
+
+
+def buffer_with_columns(
+    *columns: str,
+    distance: IntoNumericExpr,
+    quad_segs: IntoIntegerExpr = 8,
+    cap_style: IntoExprColumn | None = None,
+    join_style: IntoExprColumn | None = None,
+    mitre_limit: IntoNumericExpr = 5.0,
+) -> GeoExpr:
+    """Syntactic sugar for <code>st.geom(columns).st.[buffer_with_columns(...)][polars_st.GeoExprNameSpace.buffer_with_columns]</code>."""  # noqa: E501
+    return geom(*columns).st.buffer_with_columns(
+        distance,
+        quad_segs,
+        cap_style,
+        join_style,
+        mitre_limit,
     )
 
 
@@ -392,9 +577,18 @@ def offset_curve(
     quad_segs: int = 8,
     join_style: Literal["round", "mitre", "bevel"] = "round",
     mitre_limit: float = 5.0,
+    boundary: bool = False,
+    single_sided: bool = False,
 ) -> GeoExpr:
     """Syntactic sugar for <code>st.geom(columns).st.[offset_curve(...)][polars_st.GeoExprNameSpace.offset_curve]</code>."""  # noqa: E501
-    return geom(*columns).st.offset_curve(distance, quad_segs, join_style, mitre_limit)
+    return geom(*columns).st.offset_curve(
+        distance,
+        quad_segs,
+        join_style,
+        mitre_limit,
+        boundary,
+        single_sided,
+    )
 
 
 def centroid(*columns: str) -> GeoExpr:
@@ -402,14 +596,23 @@ def centroid(*columns: str) -> GeoExpr:
     return geom(*columns).st.centroid()
 
 
+def largest_part_centroid(*columns: str) -> GeoExpr:
+    """Syntactic sugar for <code>st.geom(columns).st.[largest_part_centroid()][polars_st.GeoExprNameSpace.largest_part_centroid]</code>."""  # noqa: E501
+    return geom(*columns).st.largest_part_centroid()
+
+
 def center(*columns: str) -> GeoExpr:
     """Syntactic sugar for <code>st.geom(columns).st.[center()][polars_st.GeoExprNameSpace.center]</code>."""  # noqa: E501
     return geom(*columns).st.center()
 
 
-def clip_by_rect(*columns: str, bounds: IntoExprColumn) -> GeoExpr:
+def clip_by_rect(
+    *columns: str,
+    bounds: IntoExprColumn,
+    grid_size: float | None = None,
+) -> GeoExpr:
     """Syntactic sugar for <code>st.geom(columns).st.[clip_by_rect(...)][polars_st.GeoExprNameSpace.clip_by_rect]</code>."""  # noqa: E501
-    return geom(*columns).st.clip_by_rect(bounds)
+    return geom(*columns).st.clip_by_rect(bounds, grid_size)
 
 
 def convex_hull(*columns: str) -> GeoExpr:
@@ -422,19 +625,41 @@ def concave_hull(*columns: str, ratio: float = 0.0, allow_holes: bool = False) -
     return geom(*columns).st.concave_hull(ratio, allow_holes)
 
 
+def concave_hull_by_length(
+    *columns: str,
+    max_length: IntoNumericExpr,
+    allow_holes: bool = False,
+) -> GeoExpr:
+    """Syntactic sugar for <code>st.geom(columns).st.[concave_hull_by_length(...)][polars_st.GeoExprNameSpace.concave_hull_by_length]</code>."""  # noqa: E501
+    return geom(*columns).st.concave_hull_by_length(max_length, allow_holes)
+
+
 def segmentize(*columns: str, max_segment_length: IntoNumericExpr) -> GeoExpr:
     """Syntactic sugar for <code>st.geom(columns).st.[segmentize(max_segment_length)][polars_st.GeoExprNameSpace.segmentize]</code>."""  # noqa: E501
     return geom(*columns).st.segmentize(max_segment_length)
 
 
+def densify_geodesic(*columns: str, max_segment_length: IntoNumericExpr) -> GeoExpr:
+    """Syntactic sugar for <code>st.geom(columns).st.[densify_geodesic(max_segment_length)][polars_st.GeoExprNameSpace.densify_geodesic]</code>."""  # noqa: E501
+    return geom(*columns).st.densify_geodesic(max_segment_length)
+
+
+def subdivide(*columns: str, max_vertices: int = 256) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[subdivide(...)][polars_st.GeoExprNameSpace.subdivide]</code>."""  # noqa: E501
+    return geom(*columns).st.subdivide(max_vertices)
+
+
 def envelope(*columns: str) -> GeoExpr:
     """Syntactic sugar for <code>st.geom(columns).st.[envelope()][polars_st.GeoExprNameSpace.envelope]</code>."""  # noqa: E501
     return geom(*columns).st.envelope()
 
 
-def extract_unique_points(*columns: str) -> GeoExpr:
-    """Syntactic sugar for <code>st.geom(columns).st.[extract_unique_points()][polars_st.GeoExprNameSpace.extract_unique_points]</code>."""  # noqa: E501
-    return geom(*columns).st.extract_unique_points()
+def extract_unique_points(
+    *columns: str,
+    tolerance: IntoNumericExpr | None = None,
+) -> GeoExpr:
+    """Syntactic sugar for <code>st.geom(columns).st.[extract_unique_points(...)][polars_st.GeoExprNameSpace.extract_unique_points]</code>."""  # noqa: E501
+    return geom(*columns).st.extract_unique_points(tolerance)
 
 
 def build_area(*columns: str) -> GeoExpr:
@@ -442,9 +667,18 @@ def build_area(*columns: str) -> GeoExpr:
     return geom(*columns).st.build_area()
 
 
-def make_valid(*columns: str) -> GeoExpr:
-    """Syntactic sugar for <code>st.geom(columns).st.[make_valid()][polars_st.GeoExprNameSpace.make_valid]</code>."""  # noqa: E501
-    return geom(*columns).st.make_valid()
+def build_area_full(*columns: str) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[build_area_full()][polars_st.GeoExprNameSpace.build_area_full]</code>."""  # noqa: E501
+    return geom(*columns).st.build_area_full()
+
+
+def make_valid(
+    *columns: str,
+    method: Literal["linework", "structure"] = "linework",
+    keep_collapsed: bool = True,
+) -> GeoExpr:
+    """Syntactic sugar for <code>st.geom(columns).st.[make_valid(...)][polars_st.GeoExprNameSpace.make_valid]</code>."""  # noqa: E501
+    return geom(*columns).st.make_valid(method, keep_collapsed)
 
 
 def normalize(*columns: str) -> GeoExpr:
@@ -457,28 +691,58 @@ def node(*columns: str) -> GeoExpr:
     return geom(*columns).st.node()
 
 
+def node_prec(*columns: str, grid_size: IntoNumericExpr) -> GeoExpr:
+    """Syntactic sugar for <code>st.geom(columns).st.[node_prec(...)][polars_st.GeoExprNameSpace.node_prec]</code>."""  # noqa: E501
+    return geom(*columns).st.node_prec(grid_size)
+
+
 def point_on_surface(*columns: str) -> GeoExpr:
     """Syntactic sugar for <code>st.geom(columns).st.[point_on_surface()][polars_st.GeoExprNameSpace.point_on_surface]</code>."""  # noqa: E501
     return geom(*columns).st.point_on_surface()
 
 
+def points_on_surface(*columns: str) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[points_on_surface()][polars_st.GeoExprNameSpace.points_on_surface]</code>."""  # noqa: E501
+    return geom(*columns).st.points_on_surface()
+
+
+def orient(*columns: str, exterior_cw: bool = False) -> GeoExpr:
+    """Syntactic sugar for <code>st.geom(columns).st.[orient(...)][polars_st.GeoExprNameSpace.orient]</code>."""  # noqa: E501
+    return geom(*columns).st.orient(exterior_cw)
+
+
+def remove_holes(*columns: str) -> GeoExpr:
+    """Syntactic sugar for <code>st.geom(columns).st.[remove_holes()][polars_st.GeoExprNameSpace.remove_holes]</code>."""  # noqa: E501
+    return geom(*columns).st.remove_holes()
+
+
 def remove_repeated_points(*columns: str, tolerance: IntoNumericExpr = 0.0) -> GeoExpr:
     """Syntactic sugar for <code>st.geom(columns).st.[remove_repeated_points(...)][polars_st.GeoExprNameSpace.remove_repeated_points]</code>."""  # noqa: E501
     return geom(*columns).st.remove_repeated_points(tolerance)
 
 
-def reverse(*columns: str) -> GeoExpr:
-    """Syntactic sugar for <code>st.geom(columns).st.[reverse()][polars_st.GeoExprNameSpace.reverse]</code>."""  # noqa: E501
-    return geom(*columns).st.reverse()
+def reverse(*columns: str, lines_only: bool = False) -> GeoExpr:
+    """Syntactic sugar for <code>st.geom(columns).st.[reverse(...)][polars_st.GeoExprNameSpace.reverse]</code>."""  # noqa: E501
+    return geom(*columns).st.reverse(lines_only)
 
 
 def simplify(
     *columns: str,
     tolerance: IntoNumericExpr,
     preserve_topology: bool = True,
+    preserve_endpoints: bool = False,
 ) -> GeoExpr:
     """Syntactic sugar for <code>st.geom(columns).st.[simplify(...)][polars_st.GeoExprNameSpace.simplify]</code>."""  # noqa: E501
-    return geom(*columns).st.simplify(tolerance, preserve_topology)
+    return geom(*columns).st.simplify(tolerance, preserve_topology, preserve_endpoints)
+
+
+def simplify_vw(
+    *columns: str,
+    tolerance: IntoNumericExpr,
+    preserve_topology: bool = True,
+) -> GeoExpr:
+    """Syntactic sugar for <code>st.geom(columns).st.[simplify_vw(...)][polars_st.GeoExprNameSpace.simplify_vw]</code>."""  # noqa: E501
+    return geom(*columns).st.simplify_vw(tolerance, preserve_topology)
 
 
 def force_2d(*columns: str) -> GeoExpr:
@@ -486,9 +750,9 @@ def force_2d(*columns: str) -> GeoExpr:
     return geom(*columns).st.force_2d()
 
 
-def force_3d(*columns: str, z: IntoNumericExpr = 0.0) -> GeoExpr:
+def force_3d(*columns: str, z: IntoNumericExpr = 0.0, fill_nan: bool = False) -> GeoExpr:
     """Syntactic sugar for <code>st.geom(columns).st.[force_3d(...)][polars_st.GeoExprNameSpace.force_3d]</code>."""  # noqa: E501
-    return geom(*columns).st.force_3d(z)
+    return geom(*columns).st.force_3d(z, fill_nan)
 
 
 def flip_coordinates(*columns: str) -> GeoExpr:
@@ -501,9 +765,33 @@ def minimum_rotated_rectangle(*columns: str) -> GeoExpr:
     return geom(*columns).st.minimum_rotated_rectangle()
 
 
-def affine_transform(*columns: str, matrix: IntoExprColumn | Sequence[float]) -> GeoExpr:
+def minimum_width(*columns: str) -> GeoExpr:
+    """Syntactic sugar for <code>st.geom(columns).st.[minimum_width()][polars_st.GeoExprNameSpace.minimum_width]</code>."""  # noqa: E501
+    return geom(*columns).st.minimum_width()
+
+
+def minimum_clearance_line(*columns: str) -> GeoExpr:
+    """Syntactic sugar for <code>st.geom(columns).st.[minimum_clearance_line()][polars_st.GeoExprNameSpace.minimum_clearance_line]</code>."""  # noqa: E501
+    return geom(*columns).st.minimum_clearance_line()
+
+
+def apply_coordinates(
+    *columns: str,
+    function: Callable[[float, float, float], tuple[float, float, float]]
+    | Callable[[pl.Series, pl.Series, pl.Series], tuple[Sequence[float], ...]],
+    vectorized: bool = False,
+) -> GeoExpr:
+    """Syntactic sugar for <code>st.geom(columns).st.[apply_coordinates(...)][polars_st.GeoExprNameSpace.apply_coordinates]</code>."""  # noqa: E501
+    return geom(*columns).st.apply_coordinates(function, vectorized=vectorized)
+
+
+def affine_transform(
+    *columns: str,
+    matrix: IntoExprColumn | Sequence[float],
+    keep_srid: bool = True,
+) -> GeoExpr:
     """Syntactic sugar for <code>st.geom(columns).st.[affine_transform(...)][polars_st.GeoExprNameSpace.affine_transform]</code>."""  # noqa: E501
-    return geom(*columns).st.affine_transform(matrix)
+    return geom(*columns).st.affine_transform(matrix, keep_srid=keep_srid)
 
 
 def translate(
@@ -519,7 +807,7 @@ def translate(
 def rotate(
     *columns: str,
     angle: IntoNumericExpr,
-    origin: Literal["center", "centroid"] | Sequence[float] = "center",
+    origin: Literal["center", "centroid"] | Sequence[float] | pl.Expr | pl.Series = "center",
 ) -> GeoExpr:
     """Syntactic sugar for <code>st.geom(columns).st.[rotate(...)][polars_st.GeoExprNameSpace.rotate]</code>."""  # noqa: E501
     return geom(*columns).st.rotate(angle, origin)
@@ -530,7 +818,7 @@ def scale(
     x: IntoNumericExpr = 1.0,
     y: IntoNumericExpr = 1.0,
     z: IntoNumericExpr = 1.0,
-    origin: Literal["center", "centroid"] | Sequence[float] = "center",
+    origin: Literal["center", "centroid"] | Sequence[float] | pl.Expr | pl.Series = "center",
 ) -> GeoExpr:
     """Syntactic sugar for <code>st.geom(columns).st.[scale(...)][polars_st.GeoExprNameSpace.scale]</code>."""  # noqa: E501
     return geom(*columns).st.scale(x, y, z, origin)
@@ -541,12 +829,25 @@ def skew(
     x: IntoNumericExpr = 0.0,
     y: IntoNumericExpr = 0.0,
     z: IntoNumericExpr = 0.0,
-    origin: Literal["center", "centroid"] | Sequence[float] = "center",
+    origin: Literal["center", "centroid"] | Sequence[float] | pl.Expr | pl.Series = "center",
 ) -> GeoExpr:
     """Syntactic sugar for <code>st.geom(columns).st.[skew(...)][polars_st.GeoExprNameSpace.skew]</code>."""  # noqa: E501
     return geom(*columns).st.skew(x, y, z, origin)
 
 
+def snap_to_grid(
+    *columns: str,
+    size_x: IntoNumericExpr,
+    size_y: IntoNumericExpr | None = None,
+    size_z: IntoNumericExpr = 0.0,
+    origin_x: IntoNumericExpr = 0.0,
+    origin_y: IntoNumericExpr = 0.0,
+    origin_z: IntoNumericExpr = 0.0,
+) -> GeoExpr:
+    """Syntactic sugar for <code>st.geom(columns).st.[snap_to_grid(...)][polars_st.GeoExprNameSpace.snap_to_grid]</code>."""  # noqa: E501
+    return geom(*columns).st.snap_to_grid(size_x, size_y, size_z, origin_x, origin_y, origin_z)
+
+
 def interpolate(
     *columns: str,
     distance: IntoNumericExpr,
@@ -556,18 +857,32 @@ def interpolate(
     return geom(*columns).st.interpolate(distance, normalized)
 
 
+def interpolate_many(
+    *columns: str,
+    distances: IntoExprColumn | Sequence[float],
+    normalized: bool = False,
+) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[interpolate_many(...)][polars_st.GeoExprNameSpace.interpolate_many]</code>."""  # noqa: E501
+    return geom(*columns).st.interpolate_many(distances, normalized)
+
+
 def substring(
     *columns: str,
     start: IntoNumericExpr,
     end: IntoNumericExpr,
+    normalized: bool = True,
 ) -> GeoExpr:
     """Syntactic sugar for <code>st.geom(columns).st.[substring(...)][polars_st.GeoExprNameSpace.substring]</code>."""  # noqa: E501
-    return geom(*columns).st.substring(start, end)
+    return geom(*columns).st.substring(start, end, normalized)
 
 
-def line_merge(*columns: str, directed: bool = False) -> GeoExpr:
-    """Syntactic sugar for <code>st.geom(columns).st.[line_merge()][polars_st.GeoExprNameSpace.line_merge]</code>."""  # noqa: E501
-    return geom(*columns).st.line_merge(directed)
+def line_merge(
+    *columns: str,
+    directed: bool = False,
+    tolerance: IntoNumericExpr | None = None,
+) -> GeoExpr:
+    """Syntactic sugar for <code>st.geom(columns).st.[line_merge(...)][polars_st.GeoExprNameSpace.line_merge]</code>."""  # noqa: E501
+    return geom(*columns).st.line_merge(directed, tolerance)
 
 
 def total_bounds(*columns: str) -> pl.Expr:
@@ -575,9 +890,39 @@ def total_bounds(*columns: str) -> pl.Expr:
     return geom(*columns).st.total_bounds()
 
 
-def collect(*columns: str, into: GeometryType | None = None) -> GeoExpr:
-    """Syntactic sugar for <code>st.geom(columns).st.[collect()][polars_st.GeoExprNameSpace.collect]</code>."""  # noqa: E501
-    return geom(*columns).st.collect(into)
+def collect(
+    *columns: str,
+    into: GeometryType | None = None,
+    on_mismatch: Literal["raise", "skip", "wrap"] = "raise",
+) -> GeoExpr:
+    """Syntactic sugar for <code>st.geom(columns).st.[collect(...)][polars_st.GeoExprNameSpace.collect]</code>."""  # noqa: E501
+    return geom(*columns).st.collect(into, on_mismatch)
+
+
+def collect_line(*columns: str) -> GeoExpr:
+    """Syntactic sugar for <code>st.geom(columns).st.[collect_line()][polars_st.GeoExprNameSpace.collect_line]</code>."""  # noqa: E501
+    return geom(*columns).st.collect_line()
+
+
+def collect_polygon(*columns: str) -> GeoExpr:
+    """Syntactic sugar for <code>st.geom(columns).st.[collect_polygon()][polars_st.GeoExprNameSpace.collect_polygon]</code>."""  # noqa: E501
+    return geom(*columns).st.collect_polygon()
+
+
+def to_geojson_feature_collection(
+    *columns: str,
+    properties: IntoExprColumn | Sequence[IntoExprColumn] | None = None,
+    indent: int | None = None,
+    output_dimension: Literal[2, 3] = 3,
+    rounding_precision: int | None = None,
+) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[to_geojson_feature_collection(...)][polars_st.GeoExprNameSpace.to_geojson_feature_collection]</code>."""  # noqa: E501
+    return geom(*columns).st.to_geojson_feature_collection(
+        properties,
+        indent,
+        output_dimension,
+        rounding_precision,
+    )
 
 
 def union_all(*columns: str, grid_size: float | None = None) -> GeoExpr:
@@ -610,14 +955,20 @@ def polygonize(*columns: str) -> GeoExpr:
     return geom(*columns).st.polygonize()
 
 
+def polygonize_full(*columns: str) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[polygonize_full()][polars_st.GeoExprNameSpace.polygonize_full]</code>."""  # noqa: E501
+    return geom(*columns).st.polygonize_full()
+
+
 def voronoi_polygons(
     *columns: str,
     tolerance: float = 0.0,
     extend_to: bytes | None = None,
     only_edges: bool = False,
+    ordered: bool = False,
 ) -> GeoExpr:
     """Syntactic sugar for <code>st.geom(columns).st.[voronoi_polygons(...)][polars_st.GeoExprNameSpace.voronoi_polygons]</code>."""  # noqa: E501
-    return geom(*columns).st.voronoi_polygons(tolerance, extend_to, only_edges)
+    return geom(*columns).st.voronoi_polygons(tolerance, extend_to, only_edges, ordered)
 
 
 def delaunay_triangles(
@@ -627,3 +978,13 @@ def delaunay_triangles(
 ) -> GeoExpr:
     """Syntactic sugar for <code>st.geom(columns).st.[delaunay_triangles(...)][polars_st.GeoExprNameSpace.delaunay_triangles]</code>."""  # noqa: E501
     return geom(*columns).st.delaunay_triangles(tolerance, only_edges)
+
+
+def constrained_delaunay(*columns: str, only_edges: bool = False) -> GeoExpr:
+    """Syntactic sugar for <code>st.geom(columns).st.[constrained_delaunay(...)][polars_st.GeoExprNameSpace.constrained_delaunay]</code>."""  # noqa: E501
+    return geom(*columns).st.constrained_delaunay(only_edges)
+
+
+def constrained_delaunay_all(*columns: str, only_edges: bool = False) -> GeoExpr:
+    """Syntactic sugar for <code>st.geom(columns).st.[constrained_delaunay_all(...)][polars_st.GeoExprNameSpace.constrained_delaunay_all]</code>."""  # noqa: E501
+    return geom(*columns).st.constrained_delaunay_all(only_edges)
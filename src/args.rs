@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::wkb::WKBGeometryType;
+use crate::wkb::{WKBFlavor, WKBGeometryType};
 
 #[derive(Deserialize)]
 pub struct ToWktKwargs {
@@ -17,9 +17,88 @@ pub struct ToWkbKwargs {
     pub include_srid: bool,
 }
 
+#[derive(Deserialize)]
+pub struct NormalizeWkbKwargs {
+    pub byte_order: i32,
+    pub flavor: WKBFlavor,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Base64Alphabet {
+    Standard,
+    UrlSafe,
+}
+
+impl From<Base64Alphabet> for base64::engine::GeneralPurpose {
+    #[inline]
+    fn from(val: Base64Alphabet) -> Self {
+        use base64::{alphabet, engine};
+        let alphabet = match val {
+            Base64Alphabet::Standard => &alphabet::STANDARD,
+            Base64Alphabet::UrlSafe => &alphabet::URL_SAFE,
+        };
+        // Padding is written, but not required when decoding
+        let config = engine::GeneralPurposeConfig::new()
+            .with_decode_padding_mode(engine::DecodePaddingMode::Indifferent);
+        Self::new(alphabet, config)
+    }
+}
+
+#[derive(Deserialize)]
+pub struct ToWkbBase64Kwargs {
+    pub alphabet: Base64Alphabet,
+}
+
+#[derive(Deserialize)]
+pub struct FromWkbBase64Kwargs {
+    pub srid: Option<i32>,
+    pub alphabet: Base64Alphabet,
+}
+
 #[derive(Deserialize)]
 pub struct ToGeoJsonKwargs {
     pub indent: Option<i32>,
+    pub rounding_precision: Option<u32>,
+    pub output_dimension: i32,
+}
+
+#[derive(Deserialize)]
+pub struct ToGmlKwargs {
+    pub version: u8,
+}
+
+#[derive(Deserialize)]
+pub struct ToSvgKwargs {
+    pub rounding_precision: Option<u32>,
+}
+
+#[derive(Deserialize)]
+pub struct GeohashKwargs {
+    pub precision: usize,
+    pub use_centroid: bool,
+}
+
+#[derive(Deserialize)]
+pub struct H3CellKwargs {
+    pub resolution: u8,
+}
+
+#[derive(Deserialize)]
+pub struct PointsFromCoordsKwargs {
+    pub has_z: bool,
+    pub has_m: bool,
+}
+
+#[derive(Deserialize)]
+pub struct FromKwargs {
+    pub srid: Option<i32>,
+}
+
+#[derive(Deserialize)]
+pub struct FromGeoJsonKwargs {
+    pub srid: Option<i32>,
+    pub allow_feature_collection: bool,
 }
 
 #[derive(Deserialize, Clone, Copy)]
@@ -46,9 +125,41 @@ pub struct SetPrecisionKwargs {
     pub mode: PrecisionMode,
 }
 
+#[derive(Deserialize)]
+pub struct ReverseKwargs {
+    pub lines_only: bool,
+}
+
 #[derive(Deserialize)]
 pub struct SimplifyKwargs {
     pub preserve_topology: bool,
+    pub preserve_endpoints: bool,
+}
+
+#[derive(Deserialize)]
+pub struct Force3DKwargs {
+    pub fill_nan: bool,
+}
+
+#[derive(Deserialize)]
+pub struct OrientKwargs {
+    pub exterior_cw: bool,
+}
+
+#[derive(Deserialize)]
+pub struct SimplifyVwKwargs {
+    pub preserve_topology: bool,
+}
+
+#[derive(Deserialize)]
+pub struct CoverageIsValidKwargs {
+    pub gap_width: f64,
+}
+
+#[derive(Deserialize)]
+pub struct CoverageSimplifyKwargs {
+    pub tolerance: f64,
+    pub simplify_boundary: bool,
 }
 
 #[derive(Deserialize)]
@@ -94,6 +205,14 @@ impl From<JoinStyle> for geos::JoinStyle {
     }
 }
 
+#[derive(Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BufferOnEmpty {
+    Empty,
+    Null,
+    Original,
+}
+
 #[derive(Deserialize)]
 pub struct BufferKwargs {
     quad_segs: i32,
@@ -101,6 +220,8 @@ pub struct BufferKwargs {
     join_style: JoinStyle,
     mitre_limit: f64,
     single_sided: bool,
+    pub on_empty: BufferOnEmpty,
+    pub output_precision: Option<f64>,
 }
 
 impl TryInto<geos::BufferParams> for &BufferKwargs {
@@ -118,11 +239,63 @@ impl TryInto<geos::BufferParams> for &BufferKwargs {
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MakeValidMethod {
+    Linework,
+    Structure,
+}
+
+impl From<MakeValidMethod> for geos::MakeValidMethod {
+    #[inline]
+    fn from(val: MakeValidMethod) -> Self {
+        match val {
+            MakeValidMethod::Linework => Self::Linework,
+            MakeValidMethod::Structure => Self::Structure,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct MakeValidKwargs {
+    method: MakeValidMethod,
+    keep_collapsed: bool,
+}
+
+impl TryInto<geos::MakeValidParams> for &MakeValidKwargs {
+    type Error = geos::Error;
+
+    #[inline]
+    fn try_into(self) -> Result<geos::MakeValidParams, Self::Error> {
+        let mut params = geos::MakeValidParams::new()?;
+        params.set_method(self.method.into())?;
+        params.set_keep_collapsed(self.keep_collapsed)?;
+        Ok(params)
+    }
+}
+
 #[derive(Deserialize)]
 pub struct OffsetCurveKwargs {
     pub quad_segs: i32,
     pub join_style: JoinStyle,
     pub mitre_limit: f64,
+    pub boundary: bool,
+    pub single_sided: bool,
+}
+
+impl TryInto<geos::BufferParams> for &OffsetCurveKwargs {
+    type Error = geos::Error;
+
+    #[inline]
+    fn try_into(self) -> Result<geos::BufferParams, Self::Error> {
+        geos::BufferParams::builder()
+            .quadrant_segments(self.quad_segs)
+            .end_cap_style(geos::CapStyle::Flat)
+            .join_style(self.join_style.into())
+            .mitre_limit(self.mitre_limit)
+            .single_sided(true)
+            .build()
+    }
 }
 
 #[derive(Deserialize)]
@@ -131,19 +304,39 @@ pub struct ConcaveHullKwargs {
     pub allow_holes: bool,
 }
 
+#[derive(Deserialize)]
+pub struct ConcaveHullByLengthKwargs {
+    pub allow_holes: bool,
+}
+
+#[derive(Deserialize)]
+pub struct AffineTransformKwargs {
+    pub keep_srid: bool,
+}
+
 #[derive(Deserialize)]
 pub struct InterpolateKwargs {
     pub normalized: bool,
 }
 
+#[derive(Deserialize)]
+pub struct EditPointKwargs {
+    pub strict: bool,
+}
+
 #[derive(Deserialize)]
 pub struct SetOperationKwargs {
     pub grid_size: Option<f64>,
 }
 
 #[derive(Deserialize)]
-pub struct EqualsExactKwargs {
-    pub tolerance: f64,
+pub struct UniqueGeometriesKwargs {
+    pub grid_size: Option<f64>,
+}
+
+#[derive(Deserialize)]
+pub struct GeometryHashKwargs {
+    pub grid_size: Option<f64>,
 }
 
 #[derive(Deserialize)]
@@ -152,11 +345,23 @@ pub struct DelaunayTrianlesKwargs {
     pub tolerance: f64,
 }
 
+#[derive(Deserialize)]
+pub struct ConstrainedDelaunayKwargs {
+    pub only_edges: bool,
+}
+
 #[derive(Deserialize)]
 pub struct VoronoiKwargs {
     pub tolerance: f64,
     pub extend_to: Option<Vec<u8>>,
     pub only_edges: bool,
+    pub ordered: bool,
+}
+
+#[derive(Deserialize)]
+pub struct VoronoiWithIndexKwargs {
+    pub tolerance: f64,
+    pub extend_to: Option<Vec<u8>>,
 }
 
 #[derive(Deserialize)]
@@ -181,19 +386,62 @@ pub enum SjoinPredicate {
     Dwithin(f64),
 }
 
+#[derive(Deserialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum SjoinHow {
+    Inner,
+    Left,
+    Right,
+    Full,
+}
+
 #[derive(Deserialize)]
 pub struct SjoinKwargs {
     pub predicate: SjoinPredicate,
+    pub how: SjoinHow,
+}
+
+#[derive(Deserialize)]
+pub struct SelfSjoinKwargs {
+    pub predicate: SjoinPredicate,
+    pub distinct: bool,
 }
 
 #[derive(Deserialize)]
 pub struct GetCoordinatesKwargs {
     pub output_dimension: Option<usize>,
+    pub structured: bool,
 }
 
 #[derive(Deserialize)]
 pub struct RelatePatternKwargs {
-    pub pattern: String,
+    pub boundary_node_rule: BoundaryNodeRule,
+}
+
+#[derive(Deserialize)]
+pub struct RelateKwargs {
+    pub boundary_node_rule: BoundaryNodeRule,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BoundaryNodeRule {
+    Mod2,
+    Endpoint,
+    MultivalentEndpoint,
+    MonovalentEndpoint,
+}
+
+impl From<BoundaryNodeRule> for geos::BoundaryNodeRule {
+    #[inline]
+    fn from(val: BoundaryNodeRule) -> Self {
+        match val {
+            BoundaryNodeRule::Mod2 => Self::Mod2,
+            BoundaryNodeRule::Endpoint => Self::Endpoint,
+            BoundaryNodeRule::MultivalentEndpoint => Self::MultivalentEndpoint,
+            BoundaryNodeRule::MonovalentEndpoint => Self::MonovalentEndpoint,
+        }
+    }
 }
 
 #[derive(Deserialize, Clone, Copy)]
@@ -218,12 +466,59 @@ impl TryInto<(f64, f64, f64)> for TransformOrigin {
     }
 }
 
+#[derive(Deserialize)]
+pub struct SubdivideKwargs {
+    pub max_vertices: usize,
+}
+
+#[derive(Deserialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum ToSridOnError {
+    Raise,
+    Null,
+}
+
+#[derive(Deserialize)]
+pub struct ToSridKwargs {
+    pub always_xy: bool,
+    pub on_error: ToSridOnError,
+}
+
+#[derive(Deserialize)]
+pub struct ToCrsKwargs {
+    pub source: String,
+    pub target: String,
+    pub always_xy: bool,
+}
+
 #[derive(Deserialize)]
 pub struct TransformKwargs {
     pub origin: TransformOrigin,
 }
 
+#[derive(Deserialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum SharedPathsDirection {
+    Both,
+    Forward,
+    Backward,
+}
+
+#[derive(Deserialize)]
+pub struct SharedPathsKwargs {
+    pub direction: SharedPathsDirection,
+}
+
+#[derive(Deserialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum CollectOnMismatch {
+    Raise,
+    Skip,
+    Wrap,
+}
+
 #[derive(Deserialize)]
 pub struct CollectKwargs {
     pub into: Option<WKBGeometryType>,
+    pub on_mismatch: CollectOnMismatch,
 }
@@ -1,5 +1,8 @@
+use proj4rs::Proj;
 use proj4wkt::builder::{Builder, Node};
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
+use serde_json::{json, Map, Value};
 
 fn wkt_to_authority(i: &str) -> Option<(&str, &str)> {
     match Builder::new().parse(i) {
@@ -25,3 +28,275 @@ pub fn get_crs_from_code(srid: i64) -> Option<&'static str> {
         .and_then(crs_definitions::from_code)
         .map(|def| def.wkt)
 }
+
+/// Return the EPSG code of a CRS given as an authority string or WKT, if any.
+pub fn get_epsg_code(definition: &str) -> Option<u16> {
+    match get_crs_authority(definition) {
+        Some(("EPSG", code)) => code.parse().ok(),
+        _ => None,
+    }
+}
+
+/// Build a projection from an authority string (`EPSG:4326`), a WKT definition with an
+/// EPSG authority, or a proj4 string.
+pub fn proj_from_definition(definition: &str) -> Option<Proj> {
+    match get_epsg_code(definition) {
+        Some(code) => Proj::from_epsg_code(code).ok(),
+        None => Proj::from_proj_string(definition).ok(),
+    }
+}
+
+/// Value of a WKT1 node: a quoted string, a number, a bare keyword such as `NORTH`, or a
+/// nested node.
+enum WktValue<'a> {
+    Text(&'a str),
+    Number(f64),
+    Keyword(&'a str),
+    Node(WktNode<'a>),
+}
+
+struct WktNode<'a> {
+    keyword: &'a str,
+    values: Vec<WktValue<'a>>,
+}
+
+impl<'a> WktNode<'a> {
+    /// Parse the node at the start of `input` and advance past it.
+    fn parse(input: &mut &'a str) -> Option<Self> {
+        let start = input.trim_start();
+        let open = start.find(['[', '('])?;
+        let keyword = start[..open].trim();
+        *input = &start[open + 1..];
+        let mut values = vec![];
+        loop {
+            *input = input.trim_start();
+            let value = if let Some(rest) = input.strip_prefix('"') {
+                let end = rest.find('"')?;
+                *input = &rest[end + 1..];
+                WktValue::Text(&rest[..end])
+            } else {
+                let end = input.find([',', '[', '(', ']', ')'])?;
+                let token = input[..end].trim();
+                if matches!(input.as_bytes()[end], b'[' | b'(') {
+                    WktValue::Node(Self::parse(input)?)
+                } else {
+                    *input = &input[end..];
+                    token
+                        .parse()
+                        .map_or(WktValue::Keyword(token), WktValue::Number)
+                }
+            };
+            values.push(value);
+            *input = input.trim_start();
+            let (separator, rest) = input.split_at_checked(1)?;
+            *input = rest;
+            if separator != "," {
+                return Some(Self { keyword, values });
+            }
+        }
+    }
+
+    fn text(&self, index: usize) -> Option<&'a str> {
+        match self.values.get(index)? {
+            WktValue::Text(text) | WktValue::Keyword(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    fn number(&self, index: usize) -> Option<f64> {
+        match self.values.get(index)? {
+            WktValue::Number(number) => Some(*number),
+            _ => None,
+        }
+    }
+
+    fn children(&self, keyword: &'a str) -> impl Iterator<Item = &WktNode<'a>> {
+        self.values.iter().filter_map(move |value| match value {
+            WktValue::Node(node) if node.keyword == keyword => Some(node),
+            _ => None,
+        })
+    }
+
+    fn child(&self, keyword: &'a str) -> Option<&WktNode<'a>> {
+        self.children(keyword).next()
+    }
+}
+
+fn projjson_unit(unit: Option<&WktNode>, kind: &str) -> Option<Value> {
+    let unit = unit?;
+    Some(json!({
+        "type": kind,
+        "name": unit.text(0)?,
+        "conversion_factor": unit.number(1)?,
+    }))
+}
+
+/// Axes of a WKT1 CRS, or `defaults` as (name, direction) when it doesn't list any.
+fn projjson_axes(crs: &WktNode, defaults: [(&str, &str); 2], unit: &Value) -> Value {
+    let axes = crs
+        .children("AXIS")
+        .filter_map(|axis| Some((axis.text(0)?, axis.text(1)?)))
+        .collect::<Vec<_>>();
+    let axes = if axes.is_empty() {
+        defaults.to_vec()
+    } else {
+        axes
+    };
+    let axes = axes.into_iter().map(|(name, direction)| {
+        let abbreviation = match name.to_lowercase().as_str() {
+            "latitude" | "lat" => "Lat".to_owned(),
+            "longitude" | "lon" | "long" => "Lon".to_owned(),
+            name => name.chars().take(1).collect::<String>().to_uppercase(),
+        };
+        json!({
+            "name": name,
+            "abbreviation": abbreviation,
+            "direction": direction.to_lowercase(),
+            "unit": unit,
+        })
+    });
+    Value::Array(axes.collect())
+}
+
+fn projjson_geographic(geogcs: &WktNode) -> Option<Value> {
+    let datum = geogcs.child("DATUM")?;
+    let spheroid = datum.child("SPHEROID")?;
+    let (semi_major_axis, inverse_flattening) = (spheroid.number(1)?, spheroid.number(2)?);
+    let ellipsoid = if inverse_flattening > 0.0 {
+        json!({
+            "name": spheroid.text(0)?,
+            "semi_major_axis": semi_major_axis,
+            "inverse_flattening": inverse_flattening,
+        })
+    } else {
+        json!({"name": spheroid.text(0)?, "radius": semi_major_axis})
+    };
+    let mut datum = json!({
+        "type": "GeodeticReferenceFrame",
+        "name": datum.text(0)?,
+        "ellipsoid": ellipsoid,
+    });
+    if let Some(primem) = geogcs.child("PRIMEM") {
+        datum["prime_meridian"] = json!({"name": primem.text(0)?, "longitude": primem.number(1)?});
+    }
+    let unit = projjson_unit(geogcs.child("UNIT"), "AngularUnit")?;
+    let defaults = [("Latitude", "north"), ("Longitude", "east")];
+    Some(json!({
+        "type": "GeographicCRS",
+        "name": geogcs.text(0)?,
+        "datum": datum,
+        "coordinate_system": {
+            "subtype": "ellipsoidal",
+            "axis": projjson_axes(geogcs, defaults, &unit),
+        },
+    }))
+}
+
+fn projjson_projected(projcs: &WktNode) -> Option<Value> {
+    let geogcs = projcs.child("GEOGCS")?;
+    let angular_unit = projjson_unit(geogcs.child("UNIT"), "AngularUnit")?;
+    let linear_unit = projjson_unit(projcs.child("UNIT"), "LinearUnit")?;
+    let method = projcs.child("PROJECTION")?.text(0)?;
+    let parameters = projcs
+        .children("PARAMETER")
+        .map(|parameter| {
+            let name = parameter.text(0)?;
+            let unit = if name.contains("scale") {
+                json!("unity")
+            } else if name.starts_with("false_") {
+                linear_unit.clone()
+            } else {
+                angular_unit.clone()
+            };
+            Some(json!({"name": name, "value": parameter.number(1)?, "unit": unit}))
+        })
+        .collect::<Option<Vec<_>>>()?;
+    let defaults = [("Easting", "east"), ("Northing", "north")];
+    Some(json!({
+        "type": "ProjectedCRS",
+        "name": projcs.text(0)?,
+        "base_crs": projjson_geographic(geogcs)?,
+        "conversion": {
+            "name": method,
+            "method": {"name": method},
+            "parameters": parameters,
+        },
+        "coordinate_system": {
+            "subtype": "Cartesian",
+            "axis": projjson_axes(projcs, defaults, &linear_unit),
+        },
+    }))
+}
+
+/// PROJJSON for an EPSG code, converted from its WKT1 definition. CRS other than
+/// geographic and projected ones, such as compound CRS, are written as unknown (`null`).
+fn projjson_from_code(srid: i64) -> PyResult<Value> {
+    let Some(wkt) = get_crs_from_code(srid) else {
+        let msg = format!("Couldn't find CRS information for SRID {srid}");
+        return Err(PyValueError::new_err(msg));
+    };
+    let crs = WktNode::parse(&mut &*wkt).and_then(|node| match node.keyword {
+        "GEOGCS" => projjson_geographic(&node),
+        "PROJCS" => projjson_projected(&node),
+        _ => None,
+    });
+    let Some(Value::Object(crs)) = crs else {
+        return Ok(Value::Null);
+    };
+    let mut projjson = Map::new();
+    let schema = "https://proj.org/schemas/v0.7/projjson.schema.json";
+    projjson.insert("$schema".into(), json!(schema));
+    projjson.extend(crs);
+    projjson.insert("id".into(), json!({"authority": "EPSG", "code": srid}));
+    Ok(Value::Object(projjson))
+}
+
+#[pyfunction]
+#[pyo3(signature = (column_name, srid, bounds=None, geometry_types=vec![]))]
+pub fn geoparquet_metadata(
+    column_name: &str,
+    srid: i64,
+    bounds: Option<[f64; 4]>,
+    geometry_types: Vec<String>,
+) -> PyResult<String> {
+    let mut column = json!({
+        "encoding": "WKB",
+        "geometry_types": geometry_types,
+        "crs": match srid {
+            0 => Value::Null,
+            srid => projjson_from_code(srid)?,
+        },
+    });
+    if let Some(bounds) = bounds {
+        column["bbox"] = json!(bounds);
+    }
+    let metadata = json!({
+        "version": "1.1.0",
+        "primary_column": column_name,
+        "columns": {column_name: column},
+    });
+    Ok(metadata.to_string())
+}
+
+#[pyfunction]
+pub fn parse_geoparquet_metadata(metadata: &str) -> PyResult<(String, i64)> {
+    let metadata: Value = serde_json::from_str(metadata)
+        .map_err(|e| PyValueError::new_err(format!("Invalid GeoParquet metadata: {e}")))?;
+    let Some(column_name) = metadata["primary_column"].as_str() else {
+        let msg = "GeoParquet metadata is missing the primary column";
+        return Err(PyValueError::new_err(msg));
+    };
+    // Per the specification, a missing CRS means OGC:CRS84 and an explicit null an unknown CRS.
+    let srid = match metadata["columns"][column_name].get("crs") {
+        None => 4326,
+        Some(crs) => match &crs["id"] {
+            Value::Object(id) if id.get("authority") == Some(&json!("EPSG")) => match &id["code"] {
+                Value::Number(code) => code.as_i64().unwrap_or_default(),
+                Value::String(code) => code.parse().unwrap_or_default(),
+                _ => 0,
+            },
+            _ => 0,
+        },
+    };
+    Ok((column_name.to_owned(), srid))
+}
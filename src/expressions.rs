@@ -4,9 +4,8 @@ use crate::{
     utils::try_reduce,
 };
 use geos::{Geom, Geometry};
-use polars::{datatypes::DataType as D, prelude::array::ArrayNameSpace};
+use polars::datatypes::DataType as D;
 use polars::{error::to_compute_err, prelude::*};
-use polars_arrow::array::{Array, FixedSizeListArray, Float64Array};
 use polars_python::{error::PyPolarsErr, PySeries};
 use pyo3::prelude::*;
 use pyo3_polars::derive::polars_expr;
@@ -25,10 +24,47 @@ fn output_type_bounds(input_fields: &[Field]) -> PolarsResult<Field> {
     ))
 }
 
-fn output_type_coordinates(input_fields: &[Field]) -> PolarsResult<Field> {
+fn coordinates_dtype(structured: bool) -> D {
+    let coordinates = D::List(D::List(D::Float64.into()).into());
+    match structured {
+        true => D::List(D::List(coordinates.into()).into()),
+        false => coordinates,
+    }
+}
+
+fn output_type_coordinates(
+    input_fields: &[Field],
+    kwargs: args::GetCoordinatesKwargs,
+) -> PolarsResult<Field> {
+    Ok(Field::new(
+        first_field_name(input_fields)?.clone(),
+        coordinates_dtype(kwargs.structured),
+    ))
+}
+
+fn coordinates_struct_dtype() -> D {
+    let fields = ["x", "y", "z", "m"].map(|name| Field::new(name.into(), D::Float64));
+    D::List(D::Struct(fields.into()).into())
+}
+
+fn output_type_coordinates_struct(input_fields: &[Field]) -> PolarsResult<Field> {
+    Ok(Field::new(
+        first_field_name(input_fields)?.clone(),
+        coordinates_struct_dtype(),
+    ))
+}
+
+fn output_type_float_list(input_fields: &[Field]) -> PolarsResult<Field> {
     Ok(Field::new(
         first_field_name(input_fields)?.clone(),
-        D::List(D::List(D::Float64.into()).into()),
+        D::List(D::Float64.into()),
+    ))
+}
+
+fn output_type_count_list(input_fields: &[Field]) -> PolarsResult<Field> {
+    Ok(Field::new(
+        first_field_name(input_fields)?.clone(),
+        D::List(D::UInt32.into()),
     ))
 }
 
@@ -79,6 +115,81 @@ fn output_type_sjoin(input_fields: &[Field]) -> PolarsResult<Field> {
     ))
 }
 
+fn output_type_geometry_with_index(input_fields: &[Field]) -> PolarsResult<Field> {
+    let name = first_field_name(input_fields)?;
+    Ok(Field::new(
+        name.clone(),
+        D::Struct(vec![
+            Field::new(name.clone(), D::Binary),
+            Field::new("index".into(), D::UInt32),
+        ]),
+    ))
+}
+
+fn output_type_nearest_points(input_fields: &[Field]) -> PolarsResult<Field> {
+    let name = first_field_name(input_fields)?;
+    Ok(Field::new(
+        name.clone(),
+        D::Struct(vec![
+            Field::new(name.clone(), D::Binary),
+            Field::new("distance".into(), D::Float64),
+        ]),
+    ))
+}
+
+fn output_type_dump_points(input_fields: &[Field]) -> PolarsResult<Field> {
+    let name = first_field_name(input_fields)?;
+    Ok(Field::new(
+        name.clone(),
+        D::Struct(vec![
+            Field::new(name.clone(), D::Binary),
+            Field::new("index".into(), D::UInt32),
+            Field::new("part".into(), D::UInt32),
+            Field::new("ring".into(), D::UInt32),
+        ]),
+    ))
+}
+
+fn output_type_build_area_full(input_fields: &[Field]) -> PolarsResult<Field> {
+    Ok(Field::new(
+        first_field_name(input_fields)?.clone(),
+        D::Struct(vec![
+            Field::new("area".into(), D::Binary),
+            Field::new("remainder".into(), D::Binary),
+        ]),
+    ))
+}
+
+fn output_type_polygonize_full(input_fields: &[Field]) -> PolarsResult<Field> {
+    Ok(Field::new(
+        first_field_name(input_fields)?.clone(),
+        D::Struct(vec![
+            Field::new("polygons".into(), D::Binary),
+            Field::new("dangles".into(), D::Binary),
+            Field::new("cut_edges".into(), D::Binary),
+            Field::new("invalid_rings".into(), D::Binary),
+        ]),
+    ))
+}
+
+fn output_type_validity_detail(input_fields: &[Field]) -> PolarsResult<Field> {
+    let name = first_field_name(input_fields)?;
+    Ok(Field::new(
+        name.clone(),
+        D::Struct(vec![
+            Field::new("is_valid".into(), D::Boolean),
+            Field::new("reason".into(), D::String),
+            Field::new("location".into(), D::Binary),
+        ]),
+    ))
+}
+
+fn geometry_with_index(geometries: BinaryChunked, indices: UInt32Chunked) -> PolarsResult<Series> {
+    let name = geometries.name().clone();
+    let fields = [geometries.into_series(), indices.into_series()];
+    StructChunked::from_series(name, fields[1].len(), fields.iter()).map(IntoSeries::into_series)
+}
+
 fn validate_inputs_length<const M: usize>(inputs: &[Series]) -> PolarsResult<&[Series; M]> {
     inputs
         .try_into()
@@ -107,28 +218,62 @@ macro_rules! wrap {
 }
 
 #[polars_expr(output_type=Binary)]
-fn from_wkb(inputs: &[Series]) -> PolarsResult<Series> {
+fn from_wkb(inputs: &[Series], kwargs: args::FromKwargs) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<1>(inputs)?;
     let wkb = validate_wkb(&inputs[0])?;
-    wrap!(from_wkb(wkb))
+    wrap!(from_wkb(wkb, kwargs.srid))
+}
+
+#[polars_expr(output_type=Binary)]
+fn from_wkb_base64(inputs: &[Series], kwargs: args::FromWkbBase64Kwargs) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    wrap!(from_wkb_base64(inputs[0].str()?, &kwargs))
 }
 
 #[polars_expr(output_type=Binary)]
-fn from_wkt(inputs: &[Series]) -> PolarsResult<Series> {
+fn from_wkt(inputs: &[Series], kwargs: args::FromKwargs) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<1>(inputs)?;
-    wrap!(from_wkt(inputs[0].str()?))
+    wrap!(from_wkt(inputs[0].str()?, kwargs.srid))
 }
 
 #[polars_expr(output_type=Binary)]
-fn from_ewkt(inputs: &[Series]) -> PolarsResult<Series> {
+fn from_ewkt(inputs: &[Series], kwargs: args::FromKwargs) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<1>(inputs)?;
-    wrap!(from_ewkt(inputs[0].str()?))
+    wrap!(from_ewkt(inputs[0].str()?, kwargs.srid))
 }
 
 #[polars_expr(output_type=Binary)]
-fn from_geojson(inputs: &[Series]) -> PolarsResult<Series> {
+fn from_geojson(inputs: &[Series], kwargs: args::FromGeoJsonKwargs) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<1>(inputs)?;
-    wrap!(from_geojson(inputs[0].str()?))
+    wrap!(from_geojson(inputs[0].str()?, &kwargs))
+}
+
+#[polars_expr(output_type=Binary)]
+fn geohash_decode(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    wrap!(geohash_decode(inputs[0].str()?))
+}
+
+#[polars_expr(output_type=Binary)]
+fn h3_to_polygon(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    extract!(cell, inputs[0], D::UInt64, u64);
+    wrap!(h3_to_polygon(cell))
+}
+
+#[polars_expr(output_type=Binary)]
+fn points_from_coords(
+    inputs: &[Series],
+    kwargs: args::PointsFromCoordsKwargs,
+) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<5>(inputs)?;
+    extract!(x, inputs[0], D::Float64, f64);
+    extract!(y, inputs[1], D::Float64, f64);
+    extract!(z, inputs[2], D::Float64, f64);
+    extract!(m, inputs[3], D::Float64, f64);
+    extract!(srid, inputs[4], D::Int32, i32);
+    let (z, m) = (kwargs.has_z.then_some(z), kwargs.has_m.then_some(m));
+    wrap!(points_from_coords(x, y, z, m, srid))
 }
 
 #[polars_expr(output_type=Binary)]
@@ -139,6 +284,14 @@ fn rectangle(inputs: &[Series]) -> PolarsResult<Series> {
     wrap!(rectangle(rect, srid))
 }
 
+#[polars_expr(output_type=Binary)]
+fn box_(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<2>(inputs)?;
+    extract!(bounds, inputs[0], D::Array(D::Float64.into(), 4), array);
+    extract!(srid, inputs[1], D::Int32, i32);
+    wrap!(box_(bounds, srid))
+}
+
 macro_rules! create_geometry {
     ($name:ident, $cast_type:expr) => {
         #[polars_expr(output_type = Binary)]
@@ -183,13 +336,25 @@ fn coordinate_dimension(inputs: &[Series]) -> PolarsResult<Series> {
     wrap!(get_coordinate_dimension(wkb))
 }
 
-#[polars_expr(output_type_func=output_type_coordinates)]
+#[polars_expr(output_type_func_with_kwargs=output_type_coordinates)]
 fn coordinates(inputs: &[Series], kwargs: args::GetCoordinatesKwargs) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<1>(inputs)?;
     let wkb = validate_wkb(&inputs[0])?;
-    wrap!(get_coordinates(wkb, kwargs.output_dimension))?
+    match kwargs.structured {
+        true => wrap!(get_structured_coordinates(wkb, kwargs.output_dimension)),
+        false => wrap!(get_coordinates(wkb, kwargs.output_dimension)),
+    }?
+    .with_name(wkb.name().clone())
+    .strict_cast(&coordinates_dtype(kwargs.structured))
+}
+
+#[polars_expr(output_type_func=output_type_coordinates_struct)]
+fn coordinates_struct(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    wrap!(get_coordinates_struct(wkb))?
         .with_name(wkb.name().clone())
-        .strict_cast(&D::List(D::List(D::Float64.into()).into()))
+        .strict_cast(&coordinates_struct_dtype())
 }
 
 #[polars_expr(output_type=Int32)]
@@ -199,6 +364,27 @@ fn srid(inputs: &[Series]) -> PolarsResult<Series> {
     wrap!(get_srid(wkb))
 }
 
+#[polars_expr(output_type=Boolean)]
+fn has_srid(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    wrap!(has_srid(wkb))
+}
+
+#[polars_expr(output_type=Binary)]
+fn strip_srid(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    wrap!(strip_srid(wkb))
+}
+
+#[polars_expr(output_type=String)]
+fn srid_authority(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    wrap!(get_srid_authority(wkb))
+}
+
 #[polars_expr(output_type=Binary)]
 fn set_srid(inputs: &[Series]) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<2>(inputs)?;
@@ -277,6 +463,13 @@ fn count_coordinates(inputs: &[Series]) -> PolarsResult<Series> {
     wrap!(get_num_coordinates(wkb))
 }
 
+#[polars_expr(output_type_func=output_type_count_list)]
+fn count_coordinates_per_part(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    wrap!(get_num_coordinates_per_part(wkb))
+}
+
 #[polars_expr(output_type=Binary)]
 fn get_point(inputs: &[Series]) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<2>(inputs)?;
@@ -285,6 +478,46 @@ fn get_point(inputs: &[Series]) -> PolarsResult<Series> {
     wrap!(get_point_n(wkb, index))
 }
 
+#[polars_expr(output_type=Binary)]
+fn start_point(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    wrap!(get_start_point(wkb))
+}
+
+#[polars_expr(output_type=Binary)]
+fn end_point(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    wrap!(get_end_point(wkb))
+}
+
+#[polars_expr(output_type=Binary)]
+fn set_point(inputs: &[Series], kwargs: args::EditPointKwargs) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<3>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    extract!(index, inputs[1], D::UInt32, u32);
+    let point = validate_wkb(&inputs[2])?;
+    wrap!(set_point_n(wkb, index, point, kwargs.strict))
+}
+
+#[polars_expr(output_type=Binary)]
+fn add_point(inputs: &[Series], kwargs: args::EditPointKwargs) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<3>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    let point = validate_wkb(&inputs[1])?;
+    extract!(index, inputs[2], D::UInt32, u32);
+    wrap!(add_point_n(wkb, point, index, kwargs.strict))
+}
+
+#[polars_expr(output_type=Binary)]
+fn remove_point(inputs: &[Series], kwargs: args::EditPointKwargs) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<2>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    extract!(index, inputs[1], D::UInt32, u32);
+    wrap!(remove_point_n(wkb, index, kwargs.strict))
+}
+
 #[polars_expr(output_type=Binary)]
 fn get_interior_ring(inputs: &[Series]) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<2>(inputs)?;
@@ -308,6 +541,31 @@ fn parts(inputs: &[Series]) -> PolarsResult<Series> {
     wrap!(get_parts(wkb))
 }
 
+#[polars_expr(output_type_func=output_type_geometry_with_index)]
+fn explode(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    functions::explode(wkb)
+        .map_err(to_compute_err)
+        .and_then(|(geometries, indices)| geometry_with_index(geometries, indices))
+}
+
+#[polars_expr(output_type_func=output_type_dump_points)]
+fn dump_points(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    let (geometries, indices, parts, rings) =
+        functions::dump_points(wkb).map_err(to_compute_err)?;
+    let name = geometries.name().clone();
+    let fields = [
+        geometries.into_series(),
+        indices.into_series(),
+        parts.into_series(),
+        rings.into_series(),
+    ];
+    StructChunked::from_series(name, fields[1].len(), fields.iter()).map(IntoSeries::into_series)
+}
+
 #[polars_expr(output_type=Float64)]
 fn precision(inputs: &[Series]) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<1>(inputs)?;
@@ -344,6 +602,27 @@ fn to_wkb(inputs: &[Series], kwargs: args::ToWkbKwargs) -> PolarsResult<Series>
     wrap!(to_wkb(wkb, &kwargs))
 }
 
+#[polars_expr(output_type=String)]
+fn wkb_flavor(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    wrap!(wkb_flavor(wkb))
+}
+
+#[polars_expr(output_type=Binary)]
+fn normalize_wkb(inputs: &[Series], kwargs: args::NormalizeWkbKwargs) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    wrap!(normalize_wkb(wkb, &kwargs))
+}
+
+#[polars_expr(output_type=String)]
+fn to_wkb_base64(inputs: &[Series], kwargs: args::ToWkbBase64Kwargs) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    wrap!(to_wkb_base64(wkb, &kwargs))
+}
+
 #[polars_expr(output_type=String)]
 fn to_geojson(inputs: &[Series], kwargs: args::ToGeoJsonKwargs) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<1>(inputs)?;
@@ -351,6 +630,41 @@ fn to_geojson(inputs: &[Series], kwargs: args::ToGeoJsonKwargs) -> PolarsResult<
     wrap!(to_geojson(wkb, &kwargs))
 }
 
+#[polars_expr(output_type=String)]
+fn to_kml(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    wrap!(to_kml(wkb))
+}
+
+#[polars_expr(output_type=String)]
+fn to_gml(inputs: &[Series], kwargs: args::ToGmlKwargs) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    wrap!(to_gml(wkb, &kwargs))
+}
+
+#[polars_expr(output_type=String)]
+fn to_svg(inputs: &[Series], kwargs: args::ToSvgKwargs) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    wrap!(to_svg(wkb, &kwargs))
+}
+
+#[polars_expr(output_type=String)]
+fn geohash_encode(inputs: &[Series], kwargs: args::GeohashKwargs) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    wrap!(geohash_encode(wkb, &kwargs))
+}
+
+#[polars_expr(output_type=UInt64)]
+fn h3_cell(inputs: &[Series], kwargs: args::H3CellKwargs) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    wrap!(h3_cell(wkb, &kwargs))
+}
+
 #[pyfunction]
 pub fn to_python_dict(
     py: Python,
@@ -364,6 +678,25 @@ pub fn to_python_dict(
         .map_err(Into::into)
 }
 
+#[pyfunction]
+pub fn clear_sjoin_cache() {
+    functions::clear_sjoin_cache();
+}
+
+#[pyfunction]
+pub fn apply_coordinates(
+    capsule: &Bound<'_, PyAny>,
+    function: &Bound<'_, PyAny>,
+) -> Result<Vec<Option<PyObject>>, PyPolarsErr> {
+    let py = capsule.py();
+    let pyseries = PySeries::from_arrow_c_stream(&py.get_type::<pyo3::types::PyNone>(), capsule)?;
+    let series = pyseries.series.read();
+    let wkb = validate_wkb(&series)?;
+    functions::apply_coordinates(wkb, function)
+        .map_err(to_compute_err)
+        .map_err(Into::into)
+}
+
 #[polars_expr(output_type=Binary)]
 fn cast(inputs: &[Series]) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<2>(inputs)?;
@@ -397,17 +730,7 @@ fn bounds(inputs: &[Series]) -> PolarsResult<Series> {
 fn total_bounds(inputs: &[Series]) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<1>(inputs)?;
     let wkb = validate_wkb(&inputs[0])?;
-    let bounds = functions::bounds(wkb).map_err(to_compute_err)?;
-    let arrow_dt = bounds.dtype().to_arrow(CompatLevel::newest());
-    let i = |i| Int64Chunked::new("".into(), [i]);
-    let total: Box<dyn Array> = Box::new(Float64Array::from_slice([
-        bounds.array_get(&i(0), false)?.min()?.unwrap_or(f64::NAN),
-        bounds.array_get(&i(1), false)?.min()?.unwrap_or(f64::NAN),
-        bounds.array_get(&i(2), false)?.max()?.unwrap_or(f64::NAN),
-        bounds.array_get(&i(3), false)?.max()?.unwrap_or(f64::NAN),
-    ]));
-    let total = FixedSizeListArray::new(arrow_dt, 1, total, None);
-    Ok(ArrayChunked::from_chunk_iter(wkb.name().clone(), [total]).into_series())
+    wrap!(total_bounds(wkb))
 }
 
 #[polars_expr(output_type=Float64)]
@@ -417,6 +740,13 @@ fn length(inputs: &[Series]) -> PolarsResult<Series> {
     wrap!(length(wkb))
 }
 
+#[polars_expr(output_type=Float64)]
+fn length_3d(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    wrap!(length_3d(wkb))
+}
+
 #[polars_expr(output_type=Float64)]
 fn distance(inputs: &[Series]) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<2>(inputs)?;
@@ -425,6 +755,22 @@ fn distance(inputs: &[Series]) -> PolarsResult<Series> {
     wrap!(distance(left, right))
 }
 
+#[polars_expr(output_type_func=output_type_float_list)]
+fn distance_matrix(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<2>(inputs)?;
+    let left = validate_wkb(&inputs[0])?;
+    let right = validate_wkb(&inputs[1])?;
+    wrap!(distance_matrix(left, right))
+}
+
+#[polars_expr(output_type=Float64)]
+fn distance_3d(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<2>(inputs)?;
+    let left = validate_wkb(&inputs[0])?;
+    let right = validate_wkb(&inputs[1])?;
+    wrap!(distance_3d(left, right))
+}
+
 #[polars_expr(output_type=Float64)]
 fn hausdorff_distance(
     inputs: &[Series],
@@ -433,10 +779,7 @@ fn hausdorff_distance(
     let inputs = validate_inputs_length::<2>(inputs)?;
     let left = validate_wkb(&inputs[0])?;
     let right = validate_wkb(&inputs[1])?;
-    match kwargs.densify {
-        Some(densify) => wrap!(hausdorff_distance_densify(left, right, densify)),
-        None => wrap!(hausdorff_distance(left, right)),
-    }
+    wrap!(hausdorff_distance(left, right, kwargs.densify))
 }
 
 #[polars_expr(output_type=Float64)]
@@ -447,10 +790,7 @@ fn frechet_distance(
     let inputs = validate_inputs_length::<2>(inputs)?;
     let left = validate_wkb(&inputs[0])?;
     let right = validate_wkb(&inputs[1])?;
-    match kwargs.densify {
-        Some(densify) => wrap!(frechet_distance_densify(left, right, densify)),
-        None => wrap!(frechet_distance(left, right)),
-    }
+    wrap!(frechet_distance(left, right, kwargs.densify))
 }
 
 #[polars_expr(output_type=Float64)]
@@ -460,6 +800,13 @@ fn minimum_clearance(inputs: &[Series]) -> PolarsResult<Series> {
     wrap!(minimum_clearance(wkb))
 }
 
+#[polars_expr(output_type=Float64)]
+fn minimum_bounding_radius(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    wrap!(minimum_bounding_radius(wkb))
+}
+
 // Predicates
 
 #[polars_expr(output_type=Boolean)]
@@ -525,6 +872,23 @@ fn is_valid_reason(inputs: &[Series]) -> PolarsResult<Series> {
     wrap!(is_valid_reason(wkb))
 }
 
+#[polars_expr(output_type_func=output_type_validity_detail)]
+fn is_valid_detail(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    let (valid, reasons, locations) = functions::is_valid_detail(wkb).map_err(to_compute_err)?;
+    let fields = [
+        valid.into_series(),
+        reasons.into_series(),
+        locations.into_series(),
+    ];
+    let detail =
+        StructChunked::from_series(wkb.name().clone(), wkb.len(), fields.iter())?.into_series();
+    // Null geometries give a null struct rather than a struct of nulls
+    let nulls = Series::full_null(detail.name().clone(), detail.len(), detail.dtype());
+    detail.zip_with(&wkb.is_not_null(), &nulls)
+}
+
 #[polars_expr(output_type=Boolean)]
 fn crosses(inputs: &[Series]) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<2>(inputs)?;
@@ -541,6 +905,14 @@ fn contains(inputs: &[Series]) -> PolarsResult<Series> {
     wrap!(contains(left, right))
 }
 
+#[polars_expr(output_type=Boolean)]
+fn contains_points(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<2>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    let points = validate_wkb(&inputs[1])?;
+    wrap!(contains_points(wkb, points))
+}
+
 #[polars_expr(output_type=Boolean)]
 fn contains_properly(inputs: &[Series]) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<2>(inputs)?;
@@ -590,6 +962,14 @@ fn intersects(inputs: &[Series]) -> PolarsResult<Series> {
     wrap!(intersects(left, right))
 }
 
+#[polars_expr(output_type=Boolean)]
+fn intersects_points(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<2>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    let points = validate_wkb(&inputs[1])?;
+    wrap!(intersects_points(wkb, points))
+}
+
 #[polars_expr(output_type=Boolean)]
 fn overlaps(inputs: &[Series]) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<2>(inputs)?;
@@ -622,6 +1002,14 @@ fn equals(inputs: &[Series]) -> PolarsResult<Series> {
     wrap!(equals(left, right))
 }
 
+#[polars_expr(output_type=Boolean)]
+fn equal_boundary(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<2>(inputs)?;
+    let left = validate_wkb(&inputs[0])?;
+    let right = validate_wkb(&inputs[1])?;
+    wrap!(equal_boundary(left, right))
+}
+
 #[polars_expr(output_type=Boolean)]
 fn equals_identical(inputs: &[Series]) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<2>(inputs)?;
@@ -631,27 +1019,34 @@ fn equals_identical(inputs: &[Series]) -> PolarsResult<Series> {
 }
 
 #[polars_expr(output_type=Boolean)]
-fn equals_exact(inputs: &[Series], kwargs: args::EqualsExactKwargs) -> PolarsResult<Series> {
-    let inputs = validate_inputs_length::<2>(inputs)?;
+fn equals_exact(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<3>(inputs)?;
     let left = validate_wkb(&inputs[0])?;
     let right = validate_wkb(&inputs[1])?;
-    wrap!(equals_exact(left, right, kwargs.tolerance))
+    extract!(tolerance, inputs[2], D::Float64, f64);
+    wrap!(equals_exact(left, right, tolerance))
 }
 
 #[polars_expr(output_type=String)]
-fn relate(inputs: &[Series]) -> PolarsResult<Series> {
+fn relate(inputs: &[Series], kwargs: args::RelateKwargs) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<2>(inputs)?;
     let left = validate_wkb(&inputs[0])?;
     let right = validate_wkb(&inputs[1])?;
-    wrap!(relate(left, right))
+    wrap!(relate(left, right, kwargs.boundary_node_rule))
 }
 
 #[polars_expr(output_type=Boolean)]
 fn relate_pattern(inputs: &[Series], kwargs: args::RelatePatternKwargs) -> PolarsResult<Series> {
-    let inputs = validate_inputs_length::<2>(inputs)?;
+    let inputs = validate_inputs_length::<3>(inputs)?;
     let left = validate_wkb(&inputs[0])?;
     let right = validate_wkb(&inputs[1])?;
-    wrap!(relate_pattern(left, right, &kwargs.pattern))
+    extract!(pattern, inputs[2], D::String, str);
+    wrap!(relate_pattern(
+        left,
+        right,
+        pattern,
+        kwargs.boundary_node_rule
+    ))
 }
 
 #[polars_expr(output_type=Binary)]
@@ -695,15 +1090,7 @@ fn intersection(inputs: &[Series], kwargs: args::SetOperationKwargs) -> PolarsRe
 fn intersection_all(inputs: &[Series], kwargs: args::SetOperationKwargs) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<1>(inputs)?;
     let wkb = validate_wkb(&inputs[0])?;
-    let it = wkb.into_iter().flatten().map(Geometry::new_from_wkb);
-    match kwargs.grid_size {
-        Some(g) => try_reduce(it.flatten(), |a, b| a.intersection_prec(&b, g)),
-        None => try_reduce(it.flatten(), |a, b| a.intersection(&b)),
-    }
-    .map(|geom| geom.unwrap_or_else(|| Geometry::new_from_wkt("GEOMETRYCOLLECTION EMPTY").unwrap()))
-    .and_then(|geom| geom.to_ewkb())
-    .map_err(to_compute_err)
-    .map(|res| Series::new(wkb.name().clone(), [res]))
+    wrap!(intersection_all(wkb, kwargs.grid_size))
 }
 
 #[polars_expr(output_type=Binary)]
@@ -769,16 +1156,8 @@ fn union(inputs: &[Series], kwargs: args::SetOperationKwargs) -> PolarsResult<Se
 #[polars_expr(output_type=Binary)]
 fn union_all(inputs: &[Series], kwargs: args::SetOperationKwargs) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<1>(inputs)?;
-    let geom = validate_wkb(&inputs[0])?;
-    let it = geom.into_iter().flatten().map(Geometry::new_from_wkb);
-    match kwargs.grid_size {
-        Some(g) => try_reduce(it.flatten(), |a, b| a.union_prec(&b, g)),
-        None => try_reduce(it.flatten(), |a, b| a.union(&b)),
-    }
-    .map(|geom| geom.unwrap_or_else(|| Geometry::new_from_wkt("GEOMETRYCOLLECTION EMPTY").unwrap()))
-    .and_then(|geom| geom.to_ewkb())
-    .map_err(to_compute_err)
-    .map(|wkb| Series::new(geom.name().clone(), [wkb]))
+    let wkb = validate_wkb(&inputs[0])?;
+    wrap!(union_all(wkb, kwargs.grid_size))
 }
 
 #[polars_expr(output_type=Binary)]
@@ -788,6 +1167,30 @@ fn coverage_union(inputs: &[Series]) -> PolarsResult<Series> {
     wrap!(coverage_union(wkb))
 }
 
+#[polars_expr(output_type=Boolean)]
+fn coverage_is_valid(
+    inputs: &[Series],
+    kwargs: args::CoverageIsValidKwargs,
+) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    wrap!(coverage_is_valid(wkb, kwargs.gap_width))
+}
+
+#[polars_expr(output_type=Binary)]
+fn coverage_simplify(
+    inputs: &[Series],
+    kwargs: args::CoverageSimplifyKwargs,
+) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    wrap!(coverage_simplify(
+        wkb,
+        kwargs.tolerance,
+        kwargs.simplify_boundary
+    ))
+}
+
 #[polars_expr(output_type=Binary)]
 fn coverage_union_all(inputs: &[Series]) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<1>(inputs)?;
@@ -802,18 +1205,77 @@ fn polygonize(inputs: &[Series]) -> PolarsResult<Series> {
     wrap!(polygonize(wkb))
 }
 
+#[polars_expr(output_type_func=output_type_polygonize_full)]
+fn polygonize_full(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    let (polygons, dangles, cut_edges, invalid_rings) =
+        functions::polygonize_full(wkb).map_err(to_compute_err)?;
+    let fields = [
+        polygons.into_series(),
+        dangles.into_series(),
+        cut_edges.into_series(),
+        invalid_rings.into_series(),
+    ];
+    StructChunked::from_series(wkb.name().clone(), 1, fields.iter()).map(IntoSeries::into_series)
+}
+
 #[polars_expr(output_type=Binary)]
 fn collect(inputs: &[Series], kwargs: args::CollectKwargs) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<1>(inputs)?;
     let wkb = validate_wkb(&inputs[0])?;
-    wrap!(collect(wkb, kwargs.into))
+    wrap!(collect(wkb, kwargs.into, kwargs.on_mismatch))
 }
 
 #[polars_expr(output_type=Binary)]
-fn boundary(inputs: &[Series]) -> PolarsResult<Series> {
+fn collect_line(inputs: &[Series]) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<1>(inputs)?;
     let wkb = validate_wkb(&inputs[0])?;
-    wrap!(boundary(wkb))
+    wrap!(collect_line(wkb))
+}
+
+#[polars_expr(output_type=Binary)]
+fn collect_polygon(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    wrap!(collect_polygon(wkb))
+}
+
+#[polars_expr(output_type=String)]
+fn to_geojson_feature_collection(
+    inputs: &[Series],
+    kwargs: args::ToGeoJsonKwargs,
+) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<2>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    extract!(properties, inputs[1], D::String, str);
+    wrap!(to_geojson_feature_collection(wkb, properties, &kwargs))
+}
+
+#[polars_expr(output_type_func=output_type_geometry_with_index)]
+fn unique_geometries(
+    inputs: &[Series],
+    kwargs: args::UniqueGeometriesKwargs,
+) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    functions::unique_geometries(wkb, kwargs.grid_size)
+        .map_err(to_compute_err)
+        .and_then(|(geometries, indices)| geometry_with_index(geometries, indices))
+}
+
+#[polars_expr(output_type=UInt64)]
+fn geometry_hash(inputs: &[Series], kwargs: args::GeometryHashKwargs) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    wrap!(geometry_hash(wkb, kwargs.grid_size))
+}
+
+#[polars_expr(output_type=Binary)]
+fn boundary(inputs: &[Series], kwargs: args::SetOperationKwargs) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    wrap!(boundary(wkb, kwargs.grid_size))
 }
 
 #[polars_expr(output_type=Binary)]
@@ -824,6 +1286,25 @@ fn buffer(inputs: &[Series], kwargs: args::BufferKwargs) -> PolarsResult<Series>
     wrap!(buffer(wkb, distance, &kwargs))
 }
 
+#[polars_expr(output_type=Binary)]
+fn buffer_with_columns(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<6>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    extract!(distance, inputs[1], D::Float64, f64);
+    extract!(quad_segs, inputs[2], D::Int32, i32);
+    extract!(cap_style, inputs[3], D::String, str);
+    extract!(join_style, inputs[4], D::String, str);
+    extract!(mitre_limit, inputs[5], D::Float64, f64);
+    wrap!(buffer_with_columns(
+        wkb,
+        distance,
+        quad_segs,
+        cap_style,
+        join_style,
+        mitre_limit
+    ))
+}
+
 #[polars_expr(output_type=Binary)]
 fn offset_curve(inputs: &[Series], kwargs: args::OffsetCurveKwargs) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<2>(inputs)?;
@@ -845,11 +1326,30 @@ fn concave_hull(inputs: &[Series], kwargs: args::ConcaveHullKwargs) -> PolarsRes
 }
 
 #[polars_expr(output_type=Binary)]
-fn clip_by_rect(inputs: &[Series]) -> PolarsResult<Series> {
+fn concave_hull_by_length(
+    inputs: &[Series],
+    kwargs: args::ConcaveHullByLengthKwargs,
+) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<2>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    extract!(max_length, inputs[1], D::Float64, f64);
+    wrap!(concave_hull_by_length(wkb, max_length, kwargs.allow_holes))
+}
+
+#[polars_expr(output_type=Binary)]
+fn clip_by_rect(inputs: &[Series], kwargs: args::SetOperationKwargs) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<2>(inputs)?;
     let wkb = validate_wkb(&inputs[0])?;
     extract!(rect, inputs[1], D::Array(D::Float64.into(), 4), array);
-    wrap!(clip_by_rect(wkb, rect))
+    wrap!(clip_by_rect(wkb, rect, kwargs.grid_size))
+}
+
+#[polars_expr(output_type=Binary)]
+fn clip(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<2>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    let mask = validate_wkb(&inputs[1])?;
+    wrap!(clip(wkb, mask))
 }
 
 #[polars_expr(output_type=Binary)]
@@ -859,6 +1359,13 @@ fn centroid(inputs: &[Series]) -> PolarsResult<Series> {
     wrap!(get_centroid(wkb))
 }
 
+#[polars_expr(output_type=Binary)]
+fn largest_part_centroid(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    wrap!(largest_part_centroid(wkb))
+}
+
 #[polars_expr(output_type=Binary)]
 fn center(inputs: &[Series]) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<1>(inputs)?;
@@ -876,12 +1383,47 @@ fn delaunay_triangles(
     wrap!(delaunay_triangulation(wkb, &kwargs))
 }
 
+#[polars_expr(output_type=Binary)]
+fn constrained_delaunay(
+    inputs: &[Series],
+    kwargs: args::ConstrainedDelaunayKwargs,
+) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    wrap!(constrained_delaunay(wkb, &kwargs))
+}
+
+#[polars_expr(output_type=Binary)]
+fn constrained_delaunay_all(
+    inputs: &[Series],
+    kwargs: args::ConstrainedDelaunayKwargs,
+) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    wrap!(constrained_delaunay_all(wkb, &kwargs))
+}
+
 #[polars_expr(output_type=Binary)]
 fn segmentize(inputs: &[Series]) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<2>(inputs)?;
     let wkb = validate_wkb(&inputs[0])?;
-    extract!(tolerance, inputs[1], D::Float64, f64);
-    wrap!(densify(wkb, tolerance))
+    extract!(max_segment_length, inputs[1], D::Float64, f64);
+    wrap!(segmentize(wkb, max_segment_length))
+}
+
+#[polars_expr(output_type=Binary)]
+fn densify_geodesic(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<2>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    extract!(max_segment_length, inputs[1], D::Float64, f64);
+    wrap!(densify_geodesic(wkb, max_segment_length))
+}
+
+#[polars_expr(output_type_func=output_type_geometry_list)]
+fn subdivide(inputs: &[Series], kwargs: args::SubdivideKwargs) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    wrap!(subdivide(wkb, kwargs.max_vertices))
 }
 
 #[polars_expr(output_type=Binary)]
@@ -893,9 +1435,10 @@ fn envelope(inputs: &[Series]) -> PolarsResult<Series> {
 
 #[polars_expr(output_type=Binary)]
 fn extract_unique_points(inputs: &[Series]) -> PolarsResult<Series> {
-    let inputs = validate_inputs_length::<1>(inputs)?;
+    let inputs = validate_inputs_length::<2>(inputs)?;
     let wkb = validate_wkb(&inputs[0])?;
-    wrap!(extract_unique_points(wkb))
+    extract!(tolerance, inputs[1], D::Float64, f64);
+    wrap!(extract_unique_points(wkb, tolerance))
 }
 
 #[polars_expr(output_type=Binary)]
@@ -905,11 +1448,23 @@ fn build_area(inputs: &[Series]) -> PolarsResult<Series> {
     wrap!(build_area(wkb))
 }
 
+#[polars_expr(output_type_func=output_type_build_area_full)]
+fn build_area_full(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    let (areas, remainders) = functions::build_area_full(wkb).map_err(to_compute_err)?;
+    let fields = [areas.into_series(), remainders.into_series()];
+    let result =
+        StructChunked::from_series(wkb.name().clone(), wkb.len(), fields.iter())?.into_series();
+    let nulls = Series::full_null(result.name().clone(), result.len(), result.dtype());
+    result.zip_with(&wkb.is_not_null(), &nulls)
+}
+
 #[polars_expr(output_type=Binary)]
-pub fn make_valid(inputs: &[Series]) -> PolarsResult<Series> {
+pub fn make_valid(inputs: &[Series], kwargs: args::MakeValidKwargs) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<1>(inputs)?;
     let wkb = validate_wkb(&inputs[0])?;
-    wrap!(make_valid(wkb))
+    wrap!(make_valid(wkb, &kwargs))
 }
 
 #[polars_expr(output_type=Binary)]
@@ -926,6 +1481,14 @@ pub fn node(inputs: &[Series]) -> PolarsResult<Series> {
     wrap!(node(wkb))
 }
 
+#[polars_expr(output_type=Binary)]
+pub fn node_prec(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<2>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    extract!(grid_size, inputs[1], D::Float64, f64);
+    wrap!(node_prec(wkb, grid_size))
+}
+
 #[polars_expr(output_type=Binary)]
 pub fn point_on_surface(inputs: &[Series]) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<1>(inputs)?;
@@ -933,6 +1496,27 @@ pub fn point_on_surface(inputs: &[Series]) -> PolarsResult<Series> {
     wrap!(point_on_surface(wkb))
 }
 
+#[polars_expr(output_type_func=output_type_geometry_list)]
+fn points_on_surface(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    wrap!(points_on_surface(wkb))
+}
+
+#[polars_expr(output_type=Binary)]
+pub fn remove_holes(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    wrap!(remove_holes(wkb))
+}
+
+#[polars_expr(output_type=Binary)]
+pub fn orient(inputs: &[Series], kwargs: args::OrientKwargs) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    wrap!(orient(wkb, &kwargs))
+}
+
 #[polars_expr(output_type=Binary)]
 pub fn remove_repeated_points(inputs: &[Series]) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<2>(inputs)?;
@@ -942,10 +1526,10 @@ pub fn remove_repeated_points(inputs: &[Series]) -> PolarsResult<Series> {
 }
 
 #[polars_expr(output_type=Binary)]
-pub fn reverse(inputs: &[Series]) -> PolarsResult<Series> {
+pub fn reverse(inputs: &[Series], kwargs: args::ReverseKwargs) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<1>(inputs)?;
     let wkb = validate_wkb(&inputs[0])?;
-    wrap!(reverse(wkb))
+    wrap!(reverse(wkb, &kwargs))
 }
 
 #[polars_expr(output_type=Binary)]
@@ -953,12 +1537,25 @@ pub fn simplify(inputs: &[Series], kwargs: args::SimplifyKwargs) -> PolarsResult
     let inputs = validate_inputs_length::<2>(inputs)?;
     let wkb = validate_wkb(&inputs[0])?;
     extract!(tolerance, inputs[1], D::Float64, f64);
+    let preserve_endpoints = kwargs.preserve_endpoints;
     match kwargs.preserve_topology {
-        true => wrap!(topology_preserve_simplify(wkb, tolerance)),
-        false => wrap!(simplify(wkb, tolerance)),
+        true => wrap!(topology_preserve_simplify(
+            wkb,
+            tolerance,
+            preserve_endpoints
+        )),
+        false => wrap!(simplify(wkb, tolerance, preserve_endpoints)),
     }
 }
 
+#[polars_expr(output_type=Binary)]
+pub fn simplify_vw(inputs: &[Series], kwargs: args::SimplifyVwKwargs) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<2>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    extract!(tolerance, inputs[1], D::Float64, f64);
+    wrap!(simplify_vw(wkb, tolerance, kwargs.preserve_topology))
+}
+
 #[polars_expr(output_type=Binary)]
 pub fn force_2d(inputs: &[Series]) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<1>(inputs)?;
@@ -967,20 +1564,20 @@ pub fn force_2d(inputs: &[Series]) -> PolarsResult<Series> {
 }
 
 #[polars_expr(output_type=Binary)]
-pub fn force_3d(inputs: &[Series]) -> PolarsResult<Series> {
+pub fn force_3d(inputs: &[Series], kwargs: args::Force3DKwargs) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<2>(inputs)?;
     let wkb = validate_wkb(&inputs[0])?;
     extract!(z, inputs[1], D::Float64, f64);
-    wrap!(force_3d(wkb, z))
+    wrap!(force_3d(wkb, z, &kwargs))
 }
 
 #[polars_expr(output_type=Binary)]
-pub fn snap(inputs: &[Series]) -> PolarsResult<Series> {
+pub fn snap(inputs: &[Series], kwargs: args::SetOperationKwargs) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<3>(inputs)?;
     let left = validate_wkb(&inputs[0])?;
     let right = validate_wkb(&inputs[1])?;
     extract!(tolerance, inputs[2], D::Float64, f64);
-    wrap!(snap(left, right, tolerance))
+    wrap!(snap(left, right, tolerance, kwargs.grid_size))
 }
 
 #[polars_expr(output_type=Binary)]
@@ -990,6 +1587,18 @@ pub fn voronoi_polygons(inputs: &[Series], kwargs: args::VoronoiKwargs) -> Polar
     wrap!(voronoi_polygons(wkb, &kwargs))
 }
 
+#[polars_expr(output_type_func=output_type_geometry_with_index)]
+pub fn voronoi_with_index(
+    inputs: &[Series],
+    kwargs: args::VoronoiWithIndexKwargs,
+) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    functions::voronoi_with_index(wkb, &kwargs)
+        .map_err(to_compute_err)
+        .and_then(|(geometries, indices)| geometry_with_index(geometries, indices))
+}
+
 #[polars_expr(output_type=Binary)]
 pub fn minimum_rotated_rectangle(inputs: &[Series]) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<1>(inputs)?;
@@ -997,6 +1606,20 @@ pub fn minimum_rotated_rectangle(inputs: &[Series]) -> PolarsResult<Series> {
     wrap!(minimum_rotated_rectangle(wkb))
 }
 
+#[polars_expr(output_type=Binary)]
+pub fn minimum_width(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    wrap!(minimum_width(wkb))
+}
+
+#[polars_expr(output_type=Binary)]
+pub fn minimum_clearance_line(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    wrap!(minimum_clearance_line(wkb))
+}
+
 #[polars_expr(output_type=Binary)]
 pub fn translate(inputs: &[Series]) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<2>(inputs)?;
@@ -1045,23 +1668,52 @@ pub fn skew(inputs: &[Series], kwargs: args::TransformKwargs) -> PolarsResult<Se
 }
 
 #[polars_expr(output_type=Binary)]
-pub fn affine_transform(inputs: &[Series]) -> PolarsResult<Series> {
+pub fn rotate_around_geom(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<3>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    extract!(angle, inputs[1], D::Float64, f64);
+    let origin = validate_wkb(&inputs[2])?;
+    wrap!(rotate_around_geom(wkb, angle, origin))
+}
+
+#[polars_expr(output_type=Binary)]
+pub fn scale_from_geom(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<3>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    extract!(factors, inputs[1], D::Array(D::Float64.into(), 3), array);
+    let origin = validate_wkb(&inputs[2])?;
+    wrap!(scale_from_geom(wkb, factors, origin))
+}
+
+#[polars_expr(output_type=Binary)]
+pub fn skew_from_geom(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<3>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    extract!(factors, inputs[1], D::Array(D::Float64.into(), 3), array);
+    let origin = validate_wkb(&inputs[2])?;
+    wrap!(skew_from_geom(wkb, factors, origin))
+}
+
+#[polars_expr(output_type=Binary)]
+pub fn snap_to_grid(inputs: &[Series]) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<2>(inputs)?;
     let wkb = validate_wkb(&inputs[0])?;
-    let matrix = &inputs[1];
-    match matrix.dtype() {
-        D::Array(.., 6) => {
-            extract!(matrix, matrix, D::Array(D::Float64.into(), 6), array);
-            wrap!(affine_transform_2d(wkb, matrix))
-        }
-        D::Array(.., 12) => {
-            extract!(matrix, matrix, D::Array(D::Float64.into(), 12), array);
-            wrap!(affine_transform_3d(wkb, matrix))
-        }
-        _ => Err(to_compute_err(
-            "matrix parameter should be of type array with shape (6 | 12)",
-        )),
-    }
+    extract!(grid, inputs[1], D::Array(D::Float64.into(), 6), array);
+    wrap!(snap_to_grid(wkb, grid))
+}
+
+#[polars_expr(output_type=Binary)]
+pub fn affine_transform(
+    inputs: &[Series],
+    kwargs: args::AffineTransformKwargs,
+) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<2>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    let matrix = match inputs[1].dtype() {
+        D::Array(..) | D::List(..) => inputs[1].strict_cast(&D::List(D::Float64.into()))?,
+        dtype => polars_bail!(InvalidOperation: "matrix should be a list of floats, got {dtype}"),
+    };
+    wrap!(affine_transform(wkb, matrix.list()?, &kwargs))
 }
 
 #[polars_expr(output_type=Binary)]
@@ -1075,6 +1727,17 @@ pub fn interpolate(inputs: &[Series], kwargs: args::InterpolateKwargs) -> Polars
     }
 }
 
+#[polars_expr(output_type_func=output_type_geometry_list)]
+pub fn interpolate_many(
+    inputs: &[Series],
+    kwargs: args::InterpolateKwargs,
+) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<2>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    extract!(distances, inputs[1], D::List(D::Float64.into()), list);
+    wrap!(interpolate_many(wkb, distances, kwargs.normalized))
+}
+
 #[polars_expr(output_type=Float64)]
 pub fn project(inputs: &[Series], kwargs: args::InterpolateKwargs) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<2>(inputs)?;
@@ -1087,30 +1750,36 @@ pub fn project(inputs: &[Series], kwargs: args::InterpolateKwargs) -> PolarsResu
 }
 
 #[polars_expr(output_type=Binary)]
-pub fn substring(inputs: &[Series]) -> PolarsResult<Series> {
+pub fn substring(inputs: &[Series], kwargs: args::InterpolateKwargs) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<3>(inputs)?;
     let wkb = validate_wkb(&inputs[0])?;
     extract!(start, inputs[1], D::Float64, f64);
     extract!(end, inputs[2], D::Float64, f64);
-    wrap!(substring(wkb, start, end))
+    wrap!(line_substring(wkb, start, end, kwargs.normalized))
 }
 
 #[polars_expr(output_type=Binary)]
 pub fn line_merge(inputs: &[Series], kwargs: args::LineMergeKwargs) -> PolarsResult<Series> {
-    let inputs = validate_inputs_length::<1>(inputs)?;
+    let inputs = validate_inputs_length::<2>(inputs)?;
     let wkb = validate_wkb(&inputs[0])?;
-    match kwargs.directed {
-        true => wrap!(line_merge_directed(wkb)),
-        false => wrap!(line_merge(wkb)),
-    }
+    extract!(tolerance, inputs[1], D::Float64, f64);
+    wrap!(line_merge_snap(wkb, tolerance, kwargs.directed))
 }
 
 #[polars_expr(output_type=Binary)]
-pub fn shared_paths(inputs: &[Series]) -> PolarsResult<Series> {
+pub fn shared_paths(inputs: &[Series], kwargs: args::SharedPathsKwargs) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<2>(inputs)?;
     let left = validate_wkb(&inputs[0])?;
     let right = validate_wkb(&inputs[1])?;
-    wrap!(shared_paths(left, right))
+    wrap!(shared_paths(left, right, kwargs.direction))
+}
+
+#[polars_expr(output_type=Binary)]
+pub fn split(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<2>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    let blade = validate_wkb(&inputs[1])?;
+    wrap!(split(wkb, blade))
 }
 
 #[polars_expr(output_type=Binary)]
@@ -1121,6 +1790,31 @@ pub fn shortest_line(inputs: &[Series]) -> PolarsResult<Series> {
     wrap!(shortest_line(left, right))
 }
 
+#[polars_expr(output_type_func=output_type_nearest_points)]
+pub fn nearest_points(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<2>(inputs)?;
+    let left = validate_wkb(&inputs[0])?;
+    let right = validate_wkb(&inputs[1])?;
+    let (lines, distances) = functions::nearest_points(left, right).map_err(to_compute_err)?;
+    let valid = lines.is_not_null();
+    let fields = [lines.into_series(), distances.into_series()];
+    let nearest = StructChunked::from_series(left.name().clone(), fields[0].len(), fields.iter())?
+        .into_series();
+    // Null geometries give a null struct rather than a struct of nulls
+    let nulls = Series::full_null(nearest.name().clone(), nearest.len(), nearest.dtype());
+    nearest.zip_with(&valid, &nulls)
+}
+
+#[polars_expr(output_type_func=output_type_geometry_with_index)]
+pub fn nearest_point_in_set(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<2>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    let candidates = validate_wkb(&inputs[1])?;
+    functions::nearest_point_in_set(wkb, candidates)
+        .map_err(to_compute_err)
+        .and_then(|(geometries, indices)| geometry_with_index(geometries, indices))
+}
+
 #[polars_expr(output_type_func=output_type_sjoin)]
 pub fn sjoin(inputs: &[Series], kwargs: args::SjoinKwargs) -> PolarsResult<Series> {
     use args::SjoinPredicate::Dwithin;
@@ -1131,15 +1825,28 @@ pub fn sjoin(inputs: &[Series], kwargs: args::SjoinKwargs) -> PolarsResult<Serie
         Dwithin(distance) => functions::sjoin_dwithin(left, right, distance),
         predicate => functions::sjoin(left, right, predicate),
     }
+    .map(|matches| functions::sjoin_with_unmatched(matches, left.len(), right.len(), kwargs.how))
     .map(|(left, right)| {
-        let left = Series::from_vec("left_index".into(), left);
-        let right = Series::from_vec("right_index".into(), right);
+        let left = Series::new("left_index".into(), left);
+        let right = Series::new("right_index".into(), right);
         StructChunked::from_series("".into(), left.len(), [left, right].iter())
     })
     .map_err(to_compute_err)?
     .map(IntoSeries::into_series)
 }
 
+#[polars_expr(output_type_func=output_type_sjoin)]
+pub fn self_sjoin(inputs: &[Series], kwargs: args::SelfSjoinKwargs) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    let (left, right) =
+        functions::self_sjoin(wkb, kwargs.predicate, kwargs.distinct).map_err(to_compute_err)?;
+    let left = Series::new("left_index".into(), left);
+    let right = Series::new("right_index".into(), right);
+    StructChunked::from_series("".into(), left.len(), [left, right].iter())
+        .map(IntoSeries::into_series)
+}
+
 #[polars_expr(output_type=Binary)]
 pub fn flip_coordinates(inputs: &[Series]) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<1>(inputs)?;
@@ -1148,9 +1855,21 @@ pub fn flip_coordinates(inputs: &[Series]) -> PolarsResult<Series> {
 }
 
 #[polars_expr(output_type=Binary)]
-pub fn to_srid(inputs: &[Series]) -> PolarsResult<Series> {
+pub fn to_srid(inputs: &[Series], kwargs: args::ToSridKwargs) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<2>(inputs)?;
     let wkb = validate_wkb(&inputs[0])?;
     extract!(srid, inputs[1], D::Int64, i64);
-    wrap!(to_srid(wkb, srid))
+    wrap!(to_srid(wkb, srid, &kwargs))
+}
+
+#[polars_expr(output_type=Binary)]
+pub fn to_crs(inputs: &[Series], kwargs: args::ToCrsKwargs) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    wrap!(to_crs(
+        wkb,
+        &kwargs.source,
+        &kwargs.target,
+        kwargs.always_xy
+    ))
 }
@@ -1,33 +1,57 @@
-use std::collections::HashMap;
+use std::cell::Cell;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::rc::Rc;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
 
 use crate::{
     args::{
-        BufferKwargs, ConcaveHullKwargs, DelaunayTrianlesKwargs, OffsetCurveKwargs,
-        SetPrecisionKwargs, SjoinPredicate, ToGeoJsonKwargs, ToWkbKwargs, ToWktKwargs,
-        VoronoiKwargs,
+        AffineTransformKwargs, BoundaryNodeRule, BufferKwargs, BufferOnEmpty, CapStyle,
+        CollectOnMismatch, ConcaveHullKwargs, ConstrainedDelaunayKwargs, DelaunayTrianlesKwargs,
+        Force3DKwargs, FromGeoJsonKwargs, FromWkbBase64Kwargs, GeohashKwargs, H3CellKwargs,
+        JoinStyle, MakeValidKwargs, NormalizeWkbKwargs, OffsetCurveKwargs, OrientKwargs,
+        ReverseKwargs, SetPrecisionKwargs, SharedPathsDirection, SjoinHow, SjoinPredicate,
+        ToGeoJsonKwargs, ToGmlKwargs, ToSridKwargs, ToSridOnError, ToSvgKwargs, ToWkbBase64Kwargs,
+        ToWkbKwargs, ToWktKwargs, VoronoiKwargs, VoronoiWithIndexKwargs,
     },
     arity::{
-        broadcast_try_binary_elementwise_values, broadcast_try_ternary_elementwise_values,
-        try_unary_elementwise_values_with_dtype,
+        broadcast_try_binary_elementwise_values, broadcast_try_ternary_elementwise,
+        broadcast_try_ternary_elementwise_values, try_unary_elementwise_values_with_dtype,
     },
-    wkb::{WKBGeometryType, WKBHeader},
+    crs, geohash,
+    utils::{
+        box_distance_3d, linearize_arcs, minimum_enclosing_circle, point_distance_3d,
+        segment_distance_3d, segmentize_coords, split_segments, visvalingam_whyatt, PlanarPolygon,
+    },
+    wkb::{
+        is_trivially_valid, strip_ewkb_srid, transcode_wkb, wkb_encoding, WKBGeometryType,
+        WKBHeader,
+    },
+    xml,
 };
+use base64::{engine::GeneralPurpose, Engine};
 use geo_index::rtree::{sort::STRSort, RTree, RTreeBuilder, RTreeIndex};
+use geographiclib_rs::{DirectGeodesic, Geodesic, InverseGeodesic};
 use geos::{
     BufferParams, CoordSeq, Error as GError, GResult, GeoJSONWriter, Geom, Geometry,
     GeometryTypes::{self, *},
-    PreparedGeometry, WKBWriter, WKTWriter,
+    MakeValidParams, PreparedGeometry, WKBWriter, WKTWriter,
 };
 use polars::prelude::arity::{broadcast_try_binary_elementwise, try_unary_elementwise};
 use polars::prelude::*;
-use polars_arrow::array::{Array, BinaryViewArray, Float64Array, StaticArray};
-use proj4rs::errors::Error as ProjError;
+use polars_arrow::array::{
+    Array, BinaryViewArray, FixedSizeListArray, Float64Array, StaticArray, UInt32Array,
+};
 use proj4rs::Proj;
 use pyo3::{
     prelude::*,
-    types::{PyDict, PyList, PyListMethods},
+    types::{PyBytes, PyDict, PyList, PyListMethods},
 };
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use serde::de::{value::StrDeserializer, DeserializeOwned};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, ser::PrettyFormatter, Value};
 
 pub trait GeometryUtils {
     fn to_ewkb(&self) -> GResult<Vec<u8>>;
@@ -255,23 +279,58 @@ impl<T: Geom> GeometryUtils for T {
     }
 }
 
-pub fn from_wkb(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
-    wkb.try_apply_nonnull_values_generic(|wkb| Geometry::new_from_wkb(wkb)?.to_ewkb())
+fn to_ewkb_with_srid(mut geom: Geometry, srid: Option<i32>) -> GResult<Vec<u8>> {
+    if let Some(srid) = srid {
+        geom.set_srid(srid);
+    }
+    geom.to_ewkb()
+}
+
+pub fn from_wkb(wkb: &BinaryChunked, srid: Option<i32>) -> GResult<BinaryChunked> {
+    wkb.try_apply_nonnull_values_generic(|wkb| {
+        to_ewkb_with_srid(Geometry::new_from_wkb(wkb)?, srid)
+    })
+}
+
+pub fn from_wkb_base64(
+    data: &StringChunked,
+    params: &FromWkbBase64Kwargs,
+) -> GResult<BinaryChunked> {
+    let engine = GeneralPurpose::from(params.alphabet);
+    let mut row = 0;
+    try_unary_elementwise(data, |data| {
+        let index = row;
+        row += 1;
+        let Some(data) = data else {
+            return Ok(None);
+        };
+        let wkb = engine
+            .decode(data)
+            .map_err(|e| GError::GenericError(format!("Invalid base64 WKB at row {index}: {e}")))?;
+        to_ewkb_with_srid(Geometry::new_from_wkb(&wkb)?, params.srid).map(Some)
+    })
 }
 
-pub fn from_wkt(wkt: &StringChunked) -> GResult<BinaryChunked> {
-    wkt.try_apply_nonnull_values_generic(|wkt| Geometry::new_from_wkt(wkt)?.to_ewkb())
+pub fn from_wkt(wkt: &StringChunked, srid: Option<i32>) -> GResult<BinaryChunked> {
+    wkt.try_apply_nonnull_values_generic(|wkt| {
+        to_ewkb_with_srid(Geometry::new_from_wkt(wkt)?, srid)
+    })
 }
 
-pub fn from_ewkt(wkt: &StringChunked) -> GResult<BinaryChunked> {
+pub fn from_ewkt(wkt: &StringChunked, srid: Option<i32>) -> GResult<BinaryChunked> {
     wkt.try_apply_nonnull_values_generic(|wkt| {
-        let geom = if wkt.starts_with("SRID=") {
-            let srid_end = wkt
-                .find(';')
-                .ok_or_else(|| GError::GenericError("Invalid EWKT".to_string()))?;
-            let srid: i32 = wkt[5..srid_end]
-                .parse()
-                .map_err(|_| GError::GenericError("Invalid SRID".to_string()))?;
+        let wkt = wkt.trim_start();
+        let geom = if wkt
+            .get(..5)
+            .is_some_and(|p| p.eq_ignore_ascii_case("SRID="))
+        {
+            let srid_end = wkt.find(';').ok_or_else(|| {
+                GError::GenericError("Invalid EWKT: missing `;` after the SRID prefix".into())
+            })?;
+            let srid: i32 = wkt[5..srid_end].trim().parse().map_err(|_| {
+                let msg = format!("Invalid EWKT: invalid SRID `{}`", &wkt[5..srid_end]);
+                GError::GenericError(msg)
+            })?;
             let wkt = &wkt[(srid_end + 1)..];
             let mut geom = Geometry::new_from_wkt(wkt)?;
             geom.set_srid(srid);
@@ -279,12 +338,32 @@ pub fn from_ewkt(wkt: &StringChunked) -> GResult<BinaryChunked> {
         } else {
             Geometry::new_from_wkt(wkt)?
         };
-        geom.to_ewkb()
+        to_ewkb_with_srid(geom, srid)
     })
 }
 
-pub fn from_geojson(json: &StringChunked) -> GResult<BinaryChunked> {
-    json.try_apply_nonnull_values_generic(|json| Geometry::new_from_geojson(json)?.to_ewkb())
+/// The `type` member of a GeoJSON object. The other members are skipped without being
+/// collected.
+#[derive(Deserialize)]
+struct GeoJsonObjectType {
+    #[serde(rename = "type")]
+    kind: Option<String>,
+}
+
+fn is_feature_collection(json: &str) -> bool {
+    serde_json::from_str::<GeoJsonObjectType>(json)
+        .is_ok_and(|object| object.kind.as_deref() == Some("FeatureCollection"))
+}
+
+pub fn from_geojson(json: &StringChunked, params: &FromGeoJsonKwargs) -> GResult<BinaryChunked> {
+    json.try_apply_nonnull_values_generic(|json| {
+        if !params.allow_feature_collection && is_feature_collection(json) {
+            return Err(GError::GenericError(
+                "Cannot parse a GeoJSON FeatureCollection as a single geometry".into(),
+            ));
+        }
+        to_ewkb_with_srid(Geometry::new_from_geojson(json)?, params.srid)
+    })
 }
 
 pub fn rectangle(bounds: &ArrayChunked, srid: &Int32Chunked) -> GResult<BinaryChunked> {
@@ -300,6 +379,36 @@ pub fn rectangle(bounds: &ArrayChunked, srid: &Int32Chunked) -> GResult<BinaryCh
     })
 }
 
+pub fn box_(bounds: &ArrayChunked, srid: &Int32Chunked) -> GResult<BinaryChunked> {
+    broadcast_try_binary_elementwise(bounds, srid, |bounds, srid| {
+        let (Some(bounds), Some(srid)) = (bounds, srid) else {
+            return Ok(None);
+        };
+        let bounds = bounds.as_any().downcast_ref::<Float64Array>().unwrap();
+        let Some([x1, y1, x2, y2]) = bounds
+            .iter()
+            .map(|v| v.copied().filter(|v| !v.is_nan()))
+            .collect::<Option<Vec<_>>>()
+            .and_then(|bounds| <[f64; 4]>::try_from(bounds).ok())
+        else {
+            return Ok(None);
+        };
+        let (xmin, xmax) = (x1.min(x2), x1.max(x2));
+        let (ymin, ymax) = (y1.min(y2), y1.max(y2));
+        let mut geom = match (xmin == xmax, ymin == ymax) {
+            (true, true) => {
+                Geometry::create_point(CoordSeq::new_from_buffer(&[xmin, ymin], 1, false, false)?)?
+            }
+            (true, false) | (false, true) => Geometry::create_line_string(
+                CoordSeq::new_from_buffer(&[xmin, ymin, xmax, ymax], 2, false, false)?,
+            )?,
+            (false, false) => Geometry::create_rectangle(xmin, ymin, xmax, ymax)?,
+        };
+        geom.set_srid(srid);
+        geom.to_ewkb().map(Some)
+    })
+}
+
 fn get_coordinate_type(dimension: usize) -> GResult<(bool, bool)> {
     match dimension {
         2 => Ok((false, false)),
@@ -311,10 +420,10 @@ fn get_coordinate_type(dimension: usize) -> GResult<(bool, bool)> {
     }
 }
 
-fn get_coordinate_seq_from_array(a: Box<dyn Array>) -> GResult<CoordSeq> {
-    let coords = a.as_any().downcast_ref::<LargeListArray>().unwrap();
+/// Return the flat coordinates buffer of a list of coordinates, with its dimension.
+fn get_coordinate_buffer_from_array(coords: &LargeListArray) -> GResult<(&[f64], usize)> {
     if coords.len() - coords.null_count() == 0 {
-        return CoordSeq::new(0, geos::CoordDimensions::TwoD);
+        return Ok((&[], 2));
     }
     let offsets = coords.offsets();
     let lengths: Vec<usize> = offsets.lengths().collect();
@@ -324,7 +433,6 @@ fn get_coordinate_seq_from_array(a: Box<dyn Array>) -> GResult<CoordSeq> {
         return Err(GError::GenericError(msg));
     }
     let dimension = lengths[0];
-    let (has_z, has_m) = get_coordinate_type(dimension)?;
     let start = (*offsets.first()).try_into().unwrap();
     let values = &coords
         .values()
@@ -333,6 +441,16 @@ fn get_coordinate_seq_from_array(a: Box<dyn Array>) -> GResult<CoordSeq> {
         .unwrap()
         .as_slice()
         .unwrap()[start..(start + coords.len() * dimension)];
+    Ok((values, dimension))
+}
+
+fn get_coordinate_seq_from_array(a: Box<dyn Array>) -> GResult<CoordSeq> {
+    let coords = a.as_any().downcast_ref::<LargeListArray>().unwrap();
+    let (values, dimension) = get_coordinate_buffer_from_array(coords)?;
+    if values.is_empty() {
+        return CoordSeq::new(0, geos::CoordDimensions::TwoD);
+    }
+    let (has_z, has_m) = get_coordinate_type(dimension)?;
     CoordSeq::new_from_buffer(values, values.len() / dimension, has_z, has_m)
 }
 
@@ -349,15 +467,72 @@ pub fn point(coords: &ListChunked, srid: &Int32Chunked) -> GResult<BinaryChunked
     })
 }
 
+pub fn points_from_coords(
+    x: &Float64Chunked,
+    y: &Float64Chunked,
+    z: Option<&Float64Chunked>,
+    m: Option<&Float64Chunked>,
+    srid: &Int32Chunked,
+) -> GResult<BinaryChunked> {
+    let lengths = [
+        Some(x.len()),
+        Some(y.len()),
+        z.map(ChunkedArray::len),
+        m.map(ChunkedArray::len),
+        Some(srid.len()),
+    ];
+    let len = lengths
+        .iter()
+        .flatten()
+        .copied()
+        .find(|&len| len != 1)
+        .unwrap_or(1);
+    if lengths
+        .iter()
+        .flatten()
+        .any(|&other| other != 1 && other != len)
+    {
+        return Err(GError::GenericError(
+            "Coordinates columns must have the same length".into(),
+        ));
+    }
+    let (has_z, has_m) = (z.is_some(), m.is_some());
+    let points = (0..len)
+        .map(|row| {
+            // Absent ordinates are skipped, while null ones give a null geometry
+            let ordinate = |ca: Option<&Float64Chunked>| match ca {
+                Some(ca) => broadcast_get(ca, row).map(Some),
+                None => Some(None),
+            };
+            let (Some(x), Some(y), Some(z), Some(m), Some(srid)) = (
+                broadcast_get(x, row),
+                broadcast_get(y, row),
+                ordinate(z),
+                ordinate(m),
+                broadcast_get(srid, row),
+            ) else {
+                return Ok(None);
+            };
+            let coord: Vec<f64> = [Some(x), Some(y), z, m].into_iter().flatten().collect();
+            let coord_seq = CoordSeq::new_from_buffer(&coord, 1, has_z, has_m)?;
+            let mut geom = Geometry::create_point(coord_seq)?;
+            geom.set_srid(srid);
+            geom.to_ewkb().map(Some)
+        })
+        .collect::<GResult<Vec<_>>>()?;
+    Ok(BinaryChunked::from_iter_options(
+        x.name().clone(),
+        points.into_iter(),
+    ))
+}
+
 pub fn multipoint(coords: &ListChunked, srid: &Int32Chunked) -> GResult<BinaryChunked> {
     broadcast_try_binary_elementwise_values(coords, srid, |coords, srid| {
-        let coord_seq = get_coordinate_seq_from_array(coords)?;
-        let dims: u32 = coord_seq.dimensions()?.into();
-        let has_z = dims > 2;
-        let has_m = dims > 3;
-        let coords = coord_seq.as_buffer(Some(dims as usize))?;
-        let mut geom = coords
-            .chunks_exact(dims as usize)
+        let coords = coords.as_any().downcast_ref::<LargeListArray>().unwrap();
+        let (values, dimension) = get_coordinate_buffer_from_array(coords)?;
+        let (has_z, has_m) = get_coordinate_type(dimension)?;
+        let mut geom = values
+            .chunks_exact(dimension)
             .map(|chunk| CoordSeq::new_from_buffer(chunk, 1, has_z, has_m))
             .map(|seq| Geometry::create_point(seq?))
             .collect::<GResult<_>>()
@@ -450,6 +625,26 @@ pub fn get_srid(wkb: &BinaryChunked) -> GResult<Int32Chunked> {
     wkb.try_apply_nonnull_values_generic(|wkb| WKBHeader::try_from(wkb).map(|header| header.srid))
 }
 
+pub fn has_srid(wkb: &BinaryChunked) -> GResult<BooleanChunked> {
+    wkb.try_apply_nonnull_values_generic(|wkb| {
+        WKBHeader::try_from(wkb).map(|header| header.srid != 0)
+    })
+}
+
+pub fn strip_srid(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
+    wkb.try_apply_nonnull_values_generic(strip_ewkb_srid)
+}
+
+pub fn get_srid_authority(wkb: &BinaryChunked) -> GResult<StringChunked> {
+    try_unary_elementwise(wkb, |wkb| {
+        let Some(wkb) = wkb else {
+            return Ok(None);
+        };
+        let srid = WKBHeader::try_from(wkb)?.srid;
+        Ok(crs::get_crs_from_code(srid.into()).map(|_| format!("EPSG:{srid}")))
+    })
+}
+
 pub fn set_srid(wkb: &BinaryChunked, srid: &Int32Chunked) -> GResult<BinaryChunked> {
     broadcast_try_binary_elementwise_values(wkb, srid, |wkb, srid| {
         let mut geom = Geometry::new_from_wkb(wkb)?;
@@ -569,47 +764,70 @@ pub fn get_num_coordinates(wkb: &BinaryChunked) -> GResult<UInt32Chunked> {
     })
 }
 
-pub fn get_coordinates(
-    wkb_array: &BinaryChunked,
-    dimension: Option<usize>,
-) -> GResult<ListChunked> {
-    fn get_coords_sequence<T>(
-        geom: &T,
-        dimension: usize,
-        builder: &mut ListPrimitiveChunkedBuilder<Float64Type>,
-    ) -> GResult<()>
-    where
-        T: Geom,
-    {
-        match geom.geometry_type()? {
-            _ if geom.is_empty()? => Ok(()),
-            Point | LineString | LinearRing | CircularString => {
-                let coord_seq = geom.get_coord_seq()?.as_buffer(Some(dimension))?;
-                for coord in coord_seq.chunks_exact(dimension) {
-                    builder.append_slice(coord);
-                }
-                Ok(())
+/// Append the number of coordinates of each part of a geometry to `counts`, with one
+/// count per ring for polygons.
+fn collect_coordinate_counts<T: Geom>(geom: &T, counts: &mut Vec<u32>) -> GResult<()> {
+    match geom.geometry_type()? {
+        Point | LineString | LinearRing | CircularString | CompoundCurve => {
+            counts.push(geom.get_num_coordinates()? as u32);
+        }
+        Polygon | CurvePolygon if geom.is_empty()? => {}
+        Polygon | CurvePolygon => {
+            collect_coordinate_counts(&geom.get_exterior_ring()?, counts)?;
+            for n in 0..geom.get_num_interior_rings()? {
+                collect_coordinate_counts(&geom.get_interior_ring_n(n)?, counts)?;
             }
-            Polygon | CurvePolygon => {
-                let coord_seq = geom
-                    .get_exterior_ring()?
-                    .get_coord_seq()?
-                    .as_buffer(Some(dimension))?;
-                for coord in coord_seq.chunks_exact(dimension) {
-                    builder.append_slice(coord);
-                }
-                (0..geom.get_num_interior_rings()?).try_for_each(|n| {
-                    get_coords_sequence(&geom.get_interior_ring_n(n)?, dimension, builder)
-                })
+        }
+        MultiPoint | MultiLineString | MultiCurve | MultiPolygon | MultiSurface
+        | GeometryCollection => {
+            for n in 0..geom.get_num_geometries()? {
+                collect_coordinate_counts(&geom.get_geometry_n(n)?, counts)?;
             }
-            MultiPoint | MultiLineString | MultiCurve | CompoundCurve | MultiPolygon
-            | MultiSurface | GeometryCollection => {
-                (0..geom.get_num_geometries()?).try_for_each(|n| {
-                    get_coords_sequence(&geom.get_geometry_n(n)?, dimension, builder)
-                })
+        }
+    }
+    Ok(())
+}
+
+pub fn get_num_coordinates_per_part(wkb: &BinaryChunked) -> GResult<ListChunked> {
+    let dt = DataType::List(Box::new(DataType::UInt32));
+    try_unary_elementwise_values_with_dtype(wkb, dt, |wkb| {
+        let mut counts = vec![];
+        collect_coordinate_counts(&Geometry::new_from_wkb(wkb)?, &mut counts)?;
+        Ok(Box::new(UInt32Array::from_vec(counts)) as Box<dyn Array>)
+    })
+}
+
+/// Call `append` on each coordinate of a geometry, with `dimension` ordinates.
+fn for_each_coordinate<T, F>(geom: &T, dimension: usize, append: &mut F) -> GResult<()>
+where
+    T: Geom,
+    F: FnMut(&[f64]),
+{
+    match geom.geometry_type()? {
+        _ if geom.is_empty()? => Ok(()),
+        Point | LineString | LinearRing | CircularString => {
+            let coord_seq = geom.get_coord_seq()?.as_buffer(Some(dimension))?;
+            for coord in coord_seq.chunks_exact(dimension) {
+                append(coord);
             }
+            Ok(())
+        }
+        Polygon | CurvePolygon => {
+            for_each_coordinate(&geom.get_exterior_ring()?, dimension, append)?;
+            (0..geom.get_num_interior_rings()?).try_for_each(|n| {
+                for_each_coordinate(&geom.get_interior_ring_n(n)?, dimension, append)
+            })
         }
+        MultiPoint | MultiLineString | MultiCurve | CompoundCurve | MultiPolygon | MultiSurface
+        | GeometryCollection => (0..geom.get_num_geometries()?)
+            .try_for_each(|n| for_each_coordinate(&geom.get_geometry_n(n)?, dimension, append)),
     }
+}
+
+pub fn get_coordinates(
+    wkb_array: &BinaryChunked,
+    dimension: Option<usize>,
+) -> GResult<ListChunked> {
     fn get_coordinates(wkb: &[u8], dimension: Option<usize>) -> GResult<Series> {
         let geom = Geometry::new_from_wkb(wkb)?;
         if geom.is_empty()? {
@@ -618,17 +836,120 @@ pub fn get_coordinates(
         let geom_dimension: u32 = geom.get_coordinate_dimension()?.into();
         let geom_dimension = geom_dimension as usize;
         let output_dimension = dimension.unwrap_or(geom_dimension);
-        let component_count = wkb.len() / 8; // rough estimate
-        let coordinates_count = component_count / geom_dimension;
+        let coordinates_count = geom.get_num_coordinates()?;
         let mut builder = ListPrimitiveChunkedBuilder::<Float64Type>::new(
             "".into(),
             coordinates_count,
             coordinates_count * output_dimension,
             DataType::Float64,
         );
-        get_coords_sequence(&geom, output_dimension, &mut builder)?;
+        for_each_coordinate(&geom, output_dimension, &mut |coord| {
+            builder.append_slice(coord)
+        })?;
+        Ok(builder.finish().into_series())
+    }
+
+    wkb_array
+        .iter()
+        .map(|wkb| wkb.map(|wkb| get_coordinates(wkb, dimension)).transpose())
+        .collect()
+}
+
+pub fn get_coordinates_struct(wkb_array: &BinaryChunked) -> GResult<ListChunked> {
+    fn get_coordinates_struct(wkb: &[u8]) -> GResult<Series> {
+        let header = WKBHeader::try_from(wkb)?;
+        let geom = Geometry::new_from_wkb(wkb)?;
+        let mut buffer = Vec::with_capacity(geom.get_num_coordinates()? * 4);
+        for_each_coordinate(&geom, 4, &mut |coord| buffer.extend_from_slice(coord))?;
+        let ordinate = |name: &str, offset: usize, present: bool| {
+            let values = buffer.iter().skip(offset).step_by(4);
+            Float64Chunked::from_iter_options(
+                name.into(),
+                values.map(|value| present.then_some(*value)),
+            )
+            .into_series()
+        };
+        let fields = [
+            ordinate("x", 0, true),
+            ordinate("y", 1, true),
+            ordinate("z", 2, header.has_z),
+            ordinate("m", 3, header.has_m),
+        ];
+        StructChunked::from_series("".into(), buffer.len() / 4, fields.iter())
+            .map(IntoSeries::into_series)
+            .map_err(|e| GError::GenericError(e.to_string()))
+    }
+
+    wkb_array
+        .iter()
+        .map(|wkb| wkb.map(get_coordinates_struct).transpose())
+        .collect()
+}
+
+pub fn get_structured_coordinates(
+    wkb_array: &BinaryChunked,
+    dimension: Option<usize>,
+) -> GResult<ListChunked> {
+    fn get_ring_coordinates<T: Geom>(geom: &T, dimension: usize) -> GResult<Series> {
+        let mut builder = ListPrimitiveChunkedBuilder::<Float64Type>::new(
+            "".into(),
+            geom.get_num_coordinates()?,
+            geom.get_num_coordinates()? * dimension,
+            DataType::Float64,
+        );
+        let mut append = |buffer: Vec<f64>, skip: usize| {
+            for coord in buffer.chunks_exact(dimension).skip(skip) {
+                builder.append_slice(coord);
+            }
+        };
+        if geom.geometry_type()? == CompoundCurve {
+            // Consecutive curves share their endpoints, which are only kept once.
+            for n in 0..geom.get_num_geometries()? {
+                let curve = geom.get_geometry_n(n)?;
+                append(
+                    curve.get_coord_seq()?.as_buffer(Some(dimension))?,
+                    usize::from(n > 0),
+                );
+            }
+        } else {
+            append(geom.get_coord_seq()?.as_buffer(Some(dimension))?, 0);
+        }
         Ok(builder.finish().into_series())
     }
+    fn get_parts<T: Geom>(geom: &T, dimension: usize, parts: &mut Vec<Series>) -> GResult<()> {
+        match geom.geometry_type()? {
+            _ if geom.is_empty()? => {}
+            Point | LineString | LinearRing | CircularString | CompoundCurve => {
+                let ring = get_ring_coordinates(geom, dimension)?;
+                parts.push(Series::new("".into(), [ring]));
+            }
+            Polygon | CurvePolygon => {
+                let mut rings = vec![get_ring_coordinates(&geom.get_exterior_ring()?, dimension)?];
+                for n in 0..geom.get_num_interior_rings()? {
+                    rings.push(get_ring_coordinates(
+                        &geom.get_interior_ring_n(n)?,
+                        dimension,
+                    )?);
+                }
+                parts.push(Series::new("".into(), rings));
+            }
+            MultiPoint | MultiLineString | MultiCurve | MultiPolygon | MultiSurface
+            | GeometryCollection => {
+                for n in 0..geom.get_num_geometries()? {
+                    get_parts(&geom.get_geometry_n(n)?, dimension, parts)?;
+                }
+            }
+        }
+        Ok(())
+    }
+    fn get_coordinates(wkb: &[u8], dimension: Option<usize>) -> GResult<Series> {
+        let geom = Geometry::new_from_wkb(wkb)?;
+        let geom_dimension: u32 = geom.get_coordinate_dimension()?.into();
+        let output_dimension = dimension.unwrap_or(geom_dimension as usize);
+        let mut parts = vec![];
+        get_parts(&geom, output_dimension, &mut parts)?;
+        Ok(Series::new("".into(), parts))
+    }
 
     wkb_array
         .iter()
@@ -658,6 +979,158 @@ pub fn get_point_n(wkb: &BinaryChunked, index: &UInt32Chunked) -> GResult<Binary
     })
 }
 
+pub fn get_start_point(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
+    try_unary_elementwise(wkb, |wkb| {
+        if let Some(wkb) = wkb {
+            let geom = Geometry::new_from_wkb(wkb)?;
+            if matches!(
+                geom.geometry_type()?,
+                LineString | LinearRing | CircularString
+            ) && !geom.is_empty()?
+            {
+                return Ok(Some(geom.get_start_point()?.to_ewkb()?));
+            }
+        }
+        Ok(None)
+    })
+}
+
+pub fn get_end_point(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
+    try_unary_elementwise(wkb, |wkb| {
+        if let Some(wkb) = wkb {
+            let geom = Geometry::new_from_wkb(wkb)?;
+            if matches!(
+                geom.geometry_type()?,
+                LineString | LinearRing | CircularString
+            ) && !geom.is_empty()?
+            {
+                return Ok(Some(geom.get_end_point()?.to_ewkb()?));
+            }
+        }
+        Ok(None)
+    })
+}
+
+fn edit_line_string<F>(wkb: &[u8], strict: bool, edit: F) -> GResult<Option<Vec<u8>>>
+where
+    F: FnOnce(&mut Vec<f64>, usize, (bool, bool)) -> GResult<bool>,
+{
+    let geom = Geometry::new_from_wkb(wkb)?;
+    if geom.geometry_type()? != LineString {
+        return Err(GError::GenericError("Geometry must be a LineString".into()));
+    }
+    let has_z = geom.has_z()?;
+    let has_m = geom.has_m()?;
+    let dimensions = 2 + usize::from(has_z) + usize::from(has_m);
+    let mut coords = match geom.is_empty()? {
+        true => vec![],
+        false => geom.get_coord_seq()?.as_buffer(Some(dimensions))?,
+    };
+    let msg = if !edit(&mut coords, dimensions, (has_z, has_m))? {
+        "Point index out of range"
+    } else if coords.len() == dimensions {
+        "LineString must have 0 or more than 1 points"
+    } else {
+        let size = coords.len() / dimensions;
+        let seq = CoordSeq::new_from_buffer(&coords, size, has_z, has_m)?;
+        let mut line = Geometry::create_line_string(seq)?;
+        line.set_srid(geom.get_srid()?);
+        return line.to_ewkb().map(Some);
+    };
+    match strict {
+        true => Err(GError::GenericError(msg.into())),
+        false => Ok(None),
+    }
+}
+
+/// Return the coordinate of a point with the ordinates of a line: Z and M are matched by
+/// name, missing ones are NaN and those the line lacks are dropped.
+fn get_point_coords(wkb: &[u8], (has_z, has_m): (bool, bool)) -> GResult<Vec<f64>> {
+    let point = Geometry::new_from_wkb(wkb)?;
+    if point.geometry_type()? != Point || point.is_empty()? {
+        return Err(GError::GenericError(
+            "Point must be a non-empty Point".into(),
+        ));
+    }
+    let point_has_z = point.has_z()?;
+    let point_has_m = point.has_m()?;
+    let point_dimensions = 2 + usize::from(point_has_z) + usize::from(point_has_m);
+    let point_coords = point.get_coord_seq()?.as_buffer(Some(point_dimensions))?;
+    let z = point_has_z.then(|| point_coords[2]);
+    let m = point_has_m.then(|| point_coords[point_dimensions - 1]);
+    let mut coords = point_coords[..2].to_vec();
+    if has_z {
+        coords.push(z.unwrap_or(f64::NAN));
+    }
+    if has_m {
+        coords.push(m.unwrap_or(f64::NAN));
+    }
+    Ok(coords)
+}
+
+pub fn set_point_n(
+    wkb: &BinaryChunked,
+    index: &UInt32Chunked,
+    point: &BinaryChunked,
+    strict: bool,
+) -> GResult<BinaryChunked> {
+    broadcast_try_ternary_elementwise(wkb, index, point, |wkb, index, point| {
+        let (Some(wkb), Some(index), Some(point)) = (wkb, index, point) else {
+            return Ok(None);
+        };
+        edit_line_string(wkb, strict, |coords, dimensions, ordinates| {
+            let start = index as usize * dimensions;
+            let Some(coord) = coords.get_mut(start..start + dimensions) else {
+                return Ok(false);
+            };
+            coord.copy_from_slice(&get_point_coords(point, ordinates)?);
+            Ok(true)
+        })
+    })
+}
+
+pub fn add_point_n(
+    wkb: &BinaryChunked,
+    point: &BinaryChunked,
+    index: &UInt32Chunked,
+    strict: bool,
+) -> GResult<BinaryChunked> {
+    broadcast_try_ternary_elementwise(wkb, point, index, |wkb, point, index| {
+        let (Some(wkb), Some(point)) = (wkb, point) else {
+            return Ok(None);
+        };
+        edit_line_string(wkb, strict, |coords, dimensions, ordinates| {
+            let start = index.map_or(coords.len(), |index| index as usize * dimensions);
+            if start > coords.len() {
+                return Ok(false);
+            }
+            let coord = get_point_coords(point, ordinates)?;
+            coords.splice(start..start, coord);
+            Ok(true)
+        })
+    })
+}
+
+pub fn remove_point_n(
+    wkb: &BinaryChunked,
+    index: &UInt32Chunked,
+    strict: bool,
+) -> GResult<BinaryChunked> {
+    broadcast_try_binary_elementwise(wkb, index, |wkb, index| {
+        let (Some(wkb), Some(index)) = (wkb, index) else {
+            return Ok(None);
+        };
+        edit_line_string(wkb, strict, |coords, dimensions, _| {
+            let start = index as usize * dimensions;
+            if start + dimensions > coords.len() {
+                return Ok(false);
+            }
+            coords.drain(start..start + dimensions);
+            Ok(true)
+        })
+    })
+}
+
 pub fn get_interior_ring_n(wkb: &BinaryChunked, index: &UInt32Chunked) -> GResult<BinaryChunked> {
     broadcast_try_binary_elementwise(wkb, index, |wkb, index| {
         if let (Some(wkb), Some(index)) = (wkb, index) {
@@ -700,6 +1173,110 @@ pub fn get_parts(wkb: &BinaryChunked) -> GResult<ListChunked> {
     })
 }
 
+pub fn explode(wkb: &BinaryChunked) -> GResult<(BinaryChunked, UInt32Chunked)> {
+    let mut geometries = BinaryChunkedBuilder::new(wkb.name().clone(), wkb.len());
+    let mut indices = Vec::with_capacity(wkb.len());
+    for (index, wkb) in wkb.iter().enumerate() {
+        let Some(wkb) = wkb else {
+            geometries.append_null();
+            indices.push(index as u32);
+            continue;
+        };
+        let geom = Geometry::new_from_wkb(wkb)?;
+        if geom.geometry_type()?.is_collection() {
+            for n in 0..geom.get_num_geometries()? {
+                geometries.append_value(geom.get_geometry_n(n)?.to_ewkb()?);
+                indices.push(index as u32);
+            }
+        } else {
+            geometries.append_value(wkb);
+            indices.push(index as u32);
+        }
+    }
+    Ok((
+        geometries.finish(),
+        UInt32Chunked::from_vec("index".into(), indices),
+    ))
+}
+
+fn dump_vertices<G: Geom>(
+    geom: &G,
+    srid: i32,
+    ring: u32,
+    vertices: &mut Vec<(Vec<u8>, u32)>,
+) -> GResult<()> {
+    match geom.geometry_type()? {
+        _ if geom.is_empty()? => Ok(()),
+        Point | LineString | LinearRing | CircularString => {
+            let has_z = geom.has_z()?;
+            let has_m = geom.has_m()?;
+            let dimensions = 2 + usize::from(has_z) + usize::from(has_m);
+            let buffer = geom.get_coord_seq()?.as_buffer(Some(dimensions))?;
+            for coord in buffer.chunks_exact(dimensions) {
+                let seq = CoordSeq::new_from_buffer(coord, 1, has_z, has_m)?;
+                let mut point = Geometry::create_point(seq)?;
+                point.set_srid(srid);
+                vertices.push((point.to_ewkb()?, ring));
+            }
+            Ok(())
+        }
+        Polygon | CurvePolygon => {
+            dump_vertices(&geom.get_exterior_ring()?, srid, 0, vertices)?;
+            (0..geom.get_num_interior_rings()?).try_for_each(|n| {
+                dump_vertices(&geom.get_interior_ring_n(n)?, srid, n as u32 + 1, vertices)
+            })
+        }
+        _ => (0..geom.get_num_geometries()?)
+            .try_for_each(|n| dump_vertices(&geom.get_geometry_n(n)?, srid, ring, vertices)),
+    }
+}
+
+type DumpedPoints = (BinaryChunked, UInt32Chunked, UInt32Chunked, UInt32Chunked);
+
+pub fn dump_points(wkb: &BinaryChunked) -> GResult<DumpedPoints> {
+    let mut geometries = BinaryChunkedBuilder::new(wkb.name().clone(), wkb.len());
+    let mut indices = Vec::with_capacity(wkb.len());
+    let mut parts = Vec::with_capacity(wkb.len());
+    let mut rings = Vec::with_capacity(wkb.len());
+    let mut vertices = Vec::new();
+    for (index, wkb) in wkb.iter().enumerate() {
+        let Some(wkb) = wkb else {
+            geometries.append_null();
+            indices.push(index as u32);
+            parts.push(None);
+            rings.push(None);
+            continue;
+        };
+        let geom = Geometry::new_from_wkb(wkb)?;
+        let srid = geom.get_srid()?;
+        let is_collection = geom.geometry_type()?.is_collection();
+        let num_parts = if is_collection {
+            geom.get_num_geometries()?
+        } else {
+            1
+        };
+        for part in 0..num_parts {
+            if is_collection {
+                dump_vertices(&geom.get_geometry_n(part)?, srid, 0, &mut vertices)?;
+            } else {
+                dump_vertices(&geom, srid, 0, &mut vertices)?;
+            }
+            for (point, ring) in vertices.drain(..) {
+                geometries.append_value(point);
+                indices.push(index as u32);
+                parts.push(Some(part as u32));
+                rings.push(Some(ring));
+            }
+        }
+    }
+    Ok((
+        geometries.finish(),
+        UInt32Chunked::from_vec("index".into(), indices),
+        UInt32Chunked::from_iter_options("part".into(), parts.into_iter()),
+        UInt32Chunked::from_iter_options("ring".into(), rings.into_iter()),
+    ))
+}
+
 pub fn get_precision(wkb: &BinaryChunked) -> GResult<Float64Chunked> {
     wkb.try_apply_nonnull_values_generic(|wkb| Geometry::new_from_wkb(wkb)?.get_precision())
 }
@@ -760,30 +1337,294 @@ pub fn to_wkb(wkb: &BinaryChunked, params: &ToWkbKwargs) -> GResult<BinaryChunke
     })
 }
 
+/// Parse a geometry for GeoJSON output, dropping Z when `output_dimension` is 2 and
+/// rounding coordinates to `rounding_precision` decimals.
+fn geojson_geometry(wkb: &[u8], params: &ToGeoJsonKwargs) -> GResult<Geometry> {
+    let geom = Geometry::new_from_wkb(wkb)?;
+    let geom = match params.output_dimension {
+        2 if geom.has_z()? => geometry_force_2d(&geom)?,
+        2 | 3 => geom,
+        _ => {
+            return Err(GError::GenericError(
+                "GeoJSON output dimension must be 2 or 3".into(),
+            ))
+        }
+    };
+    match params.rounding_precision {
+        Some(precision) if !geom.is_empty()? => {
+            let factor = 10f64.powi(precision as i32);
+            let round = |value: f64| match value * factor {
+                scaled if scaled.is_finite() => scaled.round() / factor,
+                _ => value,
+            };
+            geom.transform_xyz(|x, y, z| Ok((round(x), round(y), round(z))))
+        }
+        _ => Ok(geom),
+    }
+}
+
+pub fn wkb_flavor(wkb: &BinaryChunked) -> GResult<StringChunked> {
+    wkb.try_apply_nonnull_values_generic(wkb_encoding)
+}
+
+pub fn normalize_wkb(wkb: &BinaryChunked, params: &NormalizeWkbKwargs) -> GResult<BinaryChunked> {
+    let is_little_endian = match params.byte_order {
+        0 => false,
+        1 => true,
+        _ => return Err(GError::GenericError("byte_order must be 0 or 1".into())),
+    };
+    wkb.try_apply_nonnull_values_generic(|wkb| transcode_wkb(wkb, is_little_endian, params.flavor))
+}
+
+pub fn to_wkb_base64(wkb: &BinaryChunked, params: &ToWkbBase64Kwargs) -> GResult<StringChunked> {
+    let engine = GeneralPurpose::from(params.alphabet);
+    wkb.try_apply_nonnull_values_generic(|wkb| Ok::<_, GError>(engine.encode(wkb)))
+}
+
 pub fn to_geojson(wkb: &BinaryChunked, params: &ToGeoJsonKwargs) -> GResult<StringChunked> {
     let mut writer = GeoJSONWriter::new()?;
     wkb.try_apply_nonnull_values_generic(|wkb| {
-        let geom = Geometry::new_from_wkb(wkb)?;
+        let geom = geojson_geometry(wkb, params)?;
         writer.write_formatted(&geom, params.indent.unwrap_or(-1))
     })
 }
 
-pub fn to_python_dict(wkb: &BinaryChunked, py: Python) -> GResult<Vec<Option<PyObject>>> {
-    fn dict<'py, C>(py: Python<'py>, g: &str, v: C) -> PyObject
-    where
-        C: IntoPyObject<'py>,
-    {
-        let dict = PyDict::new(py);
-        dict.set_item("type", g).unwrap();
-        dict.set_item("coordinates", v).unwrap();
-        dict.into()
+pub fn to_geojson_feature_collection(
+    wkb: &BinaryChunked,
+    properties: &StringChunked,
+    params: &ToGeoJsonKwargs,
+) -> GResult<StringChunked> {
+    if properties.len() != 1 && properties.len() != wkb.len() {
+        return Err(GError::GenericError(
+            "Properties must have the same length as the geometries".into(),
+        ));
     }
-    fn coord_seq<T: Geom>(geom: &T) -> GResult<Vec<Vec<f64>>> {
-        if geom.is_empty()? {
-            return Ok(vec![]);
+    let to_err = |e: serde_json::Error| GError::GenericError(e.to_string());
+    let mut writer = GeoJSONWriter::new()?;
+    let features = wkb
+        .iter()
+        .enumerate()
+        .map(|(index, wkb)| {
+            let geometry = match wkb {
+                Some(wkb) => {
+                    let geometry = writer.write_formatted(&geojson_geometry(wkb, params)?, -1)?;
+                    serde_json::from_str(&geometry).map_err(to_err)?
+                }
+                None => Value::Null,
+            };
+            let invalid = |reason: String| {
+                GError::GenericError(format!("Invalid properties at row {index}: {reason}"))
+            };
+            let properties = match properties.get(if properties.len() == 1 { 0 } else { index }) {
+                Some(json) => serde_json::from_str(json).map_err(|e| invalid(e.to_string()))?,
+                None => Value::Null,
+            };
+            if !properties.is_object() && !properties.is_null() {
+                return Err(invalid("expected a JSON object".into()));
+            }
+            Ok(json!({"type": "Feature", "geometry": geometry, "properties": properties}))
+        })
+        .collect::<GResult<Vec<_>>>()?;
+    let collection = json!({"type": "FeatureCollection", "features": features});
+    let collection = match params
+        .indent
+        .and_then(|indent| usize::try_from(indent).ok())
+    {
+        Some(indent) => {
+            let indent = " ".repeat(indent);
+            let formatter = PrettyFormatter::with_indent(indent.as_bytes());
+            let mut serializer = serde_json::Serializer::with_formatter(vec![], formatter);
+            collection.serialize(&mut serializer).map_err(to_err)?;
+            String::from_utf8(serializer.into_inner())
+                .map_err(|e| GError::GenericError(e.to_string()))?
         }
-        let dims: u32 = geom.get_coordinate_dimension()?.into();
-        let buffer = geom.get_coord_seq()?.as_buffer(Some(dims as usize))?;
+        None => collection.to_string(),
+    };
+    Ok(StringChunked::from_slice(wkb.name().clone(), &[collection]))
+}
+
+pub fn to_kml(wkb: &BinaryChunked) -> GResult<StringChunked> {
+    wkb.try_apply_nonnull_values_generic(|wkb| xml::to_kml(&Geometry::new_from_wkb(wkb)?))
+}
+
+pub fn to_gml(wkb: &BinaryChunked, params: &ToGmlKwargs) -> GResult<StringChunked> {
+    if !matches!(params.version, 2 | 3) {
+        return Err(GError::GenericError("GML version must be 2 or 3".into()));
+    }
+    wkb.try_apply_nonnull_values_generic(|wkb| {
+        xml::to_gml(&Geometry::new_from_wkb(wkb)?, params.version)
+    })
+}
+
+pub fn to_svg(wkb: &BinaryChunked, params: &ToSvgKwargs) -> GResult<StringChunked> {
+    wkb.try_apply_nonnull_values_generic(|wkb| {
+        xml::to_svg(&Geometry::new_from_wkb(wkb)?, params.rounding_precision)
+    })
+}
+
+pub fn geohash_encode(wkb: &BinaryChunked, params: &GeohashKwargs) -> GResult<StringChunked> {
+    if !(1..=12).contains(&params.precision) {
+        return Err(GError::GenericError(
+            "Geohash precision must be between 1 and 12".into(),
+        ));
+    }
+    try_unary_elementwise(wkb, |wkb| {
+        let Some(wkb) = wkb else {
+            return Ok(None);
+        };
+        let geom = Geometry::new_from_wkb(wkb)?;
+        if geom.is_empty()? {
+            return Ok(None);
+        }
+        let point = match geom.geometry_type()? {
+            Point => geom,
+            _ if params.use_centroid => geom.get_centroid()?,
+            _ => return Err(GError::GenericError("Geometry must be a Point".into())),
+        };
+        geohash::encode(point.get_x()?, point.get_y()?, params.precision)
+            .map(Some)
+            .ok_or_else(|| GError::GenericError("Coordinates out of range for geohash".into()))
+    })
+}
+
+pub fn geohash_decode(hash: &StringChunked) -> GResult<BinaryChunked> {
+    hash.try_apply_nonnull_values_generic(|hash| {
+        let (lon, lat) = geohash::decode(hash)
+            .ok_or_else(|| GError::GenericError(format!("Invalid geohash `{hash}`")))?;
+        let mut point =
+            Geometry::create_point(CoordSeq::new_from_buffer(&[lon, lat], 1, false, false)?)?;
+        point.set_srid(4326);
+        point.to_ewkb()
+    })
+}
+
+pub fn h3_cell(wkb: &BinaryChunked, params: &H3CellKwargs) -> GResult<UInt64Chunked> {
+    let resolution = h3o::Resolution::try_from(params.resolution)
+        .map_err(|_| GError::GenericError("H3 resolution must be between 0 and 15".into()))?;
+    try_unary_elementwise(wkb, |wkb| {
+        let Some(wkb) = wkb else {
+            return Ok(None);
+        };
+        let geom = Geometry::new_from_wkb(wkb)?;
+        let srid = geom.get_srid()?;
+        if srid != 0 && srid != 4326 {
+            let msg = format!("H3 cells require EPSG:4326 coordinates, got SRID {srid}");
+            return Err(GError::GenericError(msg));
+        }
+        if geom.is_empty()? {
+            return Ok(None);
+        }
+        let point = match geom.geometry_type()? {
+            Point => geom,
+            _ => geom.get_centroid()?,
+        };
+        let lat_lng = h3o::LatLng::new(point.get_y()?, point.get_x()?)
+            .map_err(|e| GError::GenericError(format!("Invalid coordinates for H3: {e}")))?;
+        Ok(Some(u64::from(lat_lng.to_cell(resolution))))
+    })
+}
+
+pub fn h3_to_polygon(cell: &UInt64Chunked) -> GResult<BinaryChunked> {
+    cell.try_apply_nonnull_values_generic(|cell| {
+        let cell = h3o::CellIndex::try_from(cell)
+            .map_err(|_| GError::GenericError(format!("Invalid H3 cell index `{cell}`")))?;
+        let boundary = cell.boundary();
+        let mut coords = boundary
+            .iter()
+            .flat_map(|vertex| [vertex.lng(), vertex.lat()])
+            .collect::<Vec<_>>();
+        coords.extend([coords[0], coords[1]]);
+        let ring = CoordSeq::new_from_buffer(&coords, coords.len() / 2, false, false)?;
+        let mut polygon = Geometry::create_polygon(Geometry::create_linear_ring(ring)?, vec![])?;
+        polygon.set_srid(4326);
+        polygon.to_ewkb()
+    })
+}
+
+/// Apply a Python function to the coordinates of all geometries with a single call. The
+/// function receives lists of X, Y and Z, with NaN Z for 2D geometries, and must return
+/// three sequences of the same length.
+pub fn apply_coordinates(
+    wkb: &BinaryChunked,
+    function: &Bound<'_, PyAny>,
+) -> GResult<Vec<Option<PyObject>>> {
+    let py = function.py();
+    let to_err = |e: PyErr| GError::GenericError(e.to_string());
+    let geometries = wkb
+        .into_iter()
+        .map(|wkb| wkb.map(Geometry::new_from_wkb).transpose())
+        .collect::<GResult<Vec<_>>>()?;
+    let (mut xs, mut ys, mut zs) = (vec![], vec![], vec![]);
+    for geom in geometries.iter().flatten() {
+        for_each_coordinate(geom, 3, &mut |coord| {
+            xs.push(coord[0]);
+            ys.push(coord[1]);
+            zs.push(coord[2]);
+        })?;
+    }
+    let count = xs.len();
+    let (xs, ys, zs): (Vec<f64>, Vec<f64>, Vec<f64>) = match count {
+        0 => (xs, ys, zs),
+        _ => function
+            .call1((xs, ys, zs))
+            .and_then(|result| result.extract())
+            .map_err(to_err)?,
+    };
+    if xs.len() != count || ys.len() != count || zs.len() != count {
+        return Err(GError::GenericError(format!(
+            "Function must return {count} coordinates, got ({}, {}, {})",
+            xs.len(),
+            ys.len(),
+            zs.len()
+        )));
+    }
+    // GEOS must visit the coordinates in the order they were collected, which is checked
+    // rather than assumed so that a mismatch is an error instead of a panic
+    let mismatch = || {
+        let msg = "Coordinates were not visited in the order they were collected";
+        GError::GenericError(msg.into())
+    };
+    let index = Cell::new(0);
+    let result = geometries
+        .into_iter()
+        .map(|geom| {
+            let Some(geom) = geom else {
+                return Ok(None);
+            };
+            let mut result = geom.transform_xyz(|_, _, _| {
+                let n = index.get();
+                index.set(n + 1);
+                match (xs.get(n), ys.get(n), zs.get(n)) {
+                    (Some(&x), Some(&y), Some(&z)) => Ok((x, y, z)),
+                    _ => Err(mismatch()),
+                }
+            })?;
+            result.set_srid(geom.get_srid()?);
+            Ok(Some(PyBytes::new(py, &result.to_ewkb()?).into()))
+        })
+        .collect::<GResult<Vec<_>>>()?;
+    if index.get() != count {
+        return Err(mismatch());
+    }
+    Ok(result)
+}
+
+pub fn to_python_dict(wkb: &BinaryChunked, py: Python) -> GResult<Vec<Option<PyObject>>> {
+    fn dict<'py, C>(py: Python<'py>, g: &str, v: C) -> PyObject
+    where
+        C: IntoPyObject<'py>,
+    {
+        let dict = PyDict::new(py);
+        dict.set_item("type", g).unwrap();
+        dict.set_item("coordinates", v).unwrap();
+        dict.into()
+    }
+    fn coord_seq<T: Geom>(geom: &T) -> GResult<Vec<Vec<f64>>> {
+        if geom.is_empty()? {
+            return Ok(vec![]);
+        }
+        let dims: u32 = geom.get_coordinate_dimension()?.into();
+        let buffer = geom.get_coord_seq()?.as_buffer(Some(dims as usize))?;
         let coords = buffer
             .chunks_exact(dims as usize)
             .map(<[f64]>::to_vec)
@@ -903,10 +1744,174 @@ pub fn bounds(wkb: &BinaryChunked) -> GResult<ArrayChunked> {
     })
 }
 
+pub fn total_bounds(wkb: &BinaryChunked) -> GResult<ArrayChunked> {
+    let total = wkb
+        .iter()
+        .flatten()
+        .try_fold(None, |total: Option<[f64; 4]>, wkb| {
+            let geom = Geometry::new_from_wkb(wkb)?;
+            if geom.is_empty()? {
+                return Ok(total);
+            }
+            let x_min = geom.get_x_min()?;
+            let y_min = geom.get_y_min()?;
+            let x_max = geom.get_x_max()?;
+            let y_max = geom.get_y_max()?;
+            Ok::<_, GError>(Some(match total {
+                Some([a, b, c, d]) => [a.min(x_min), b.min(y_min), c.max(x_max), d.max(y_max)],
+                None => [x_min, y_min, x_max, y_max],
+            }))
+        })?
+        .unwrap_or([f64::NAN; 4]);
+    let dt = DataType::Array(Box::new(DataType::Float64), 4).to_arrow(CompatLevel::newest());
+    let values = Box::new(Float64Array::from_slice(total)) as Box<dyn Array>;
+    let total = FixedSizeListArray::new(dt, 1, values, None);
+    Ok(ArrayChunked::from_chunk_iter(wkb.name().clone(), [total]))
+}
+
 pub fn length(wkb: &BinaryChunked) -> GResult<Float64Chunked> {
     wkb.try_apply_nonnull_values_generic(|wkb| Geometry::new_from_wkb(wkb)?.length())
 }
 
+/// Collect the vertices of each point, line and ring of a geometry, with a Z of 0 when
+/// missing.
+fn components_3d<G: Geom>(geom: &G, components: &mut Vec<Vec<[f64; 3]>>) -> GResult<()> {
+    if geom.is_empty()? {
+        return Ok(());
+    }
+    match geom.geometry_type()? {
+        Point | LineString | LinearRing => {
+            let has_z = geom.has_z()?;
+            let dims = 2 + usize::from(has_z) + usize::from(geom.has_m()?);
+            let buffer = geom.get_coord_seq()?.as_buffer(Some(dims))?;
+            let coords = buffer.chunks_exact(dims).map(|coord| {
+                let z = if has_z && !coord[2].is_nan() {
+                    coord[2]
+                } else {
+                    0.0
+                };
+                [coord[0], coord[1], z]
+            });
+            components.push(coords.collect());
+        }
+        Polygon => {
+            components_3d(&geom.get_exterior_ring()?, components)?;
+            for n in 0..geom.get_num_interior_rings()? {
+                components_3d(&geom.get_interior_ring_n(n)?, components)?;
+            }
+        }
+        MultiPoint | MultiLineString | MultiPolygon | GeometryCollection => {
+            for n in 0..geom.get_num_geometries()? {
+                components_3d(&geom.get_geometry_n(n)?, components)?;
+            }
+        }
+        t => {
+            let msg = format!("Unsupported geometry type for 3D measurement: {t:?}");
+            return Err(GError::GenericError(msg));
+        }
+    }
+    Ok(())
+}
+
+pub fn length_3d(wkb: &BinaryChunked) -> GResult<Float64Chunked> {
+    wkb.try_apply_nonnull_values_generic(|wkb| {
+        let mut components = vec![];
+        components_3d(&Geometry::new_from_wkb(wkb)?, &mut components)?;
+        let length: f64 = components
+            .iter()
+            .flat_map(|coords| coords.windows(2))
+            .map(|segment| point_distance_3d(segment[0], segment[1]))
+            .sum();
+        Ok::<_, GError>(length)
+    })
+}
+
+fn planar_polygons_3d<G: Geom>(geom: &G, polygons: &mut Vec<PlanarPolygon>) -> GResult<()> {
+    match geom.geometry_type()? {
+        _ if geom.is_empty()? => {}
+        Polygon => {
+            let mut rings = vec![];
+            components_3d(geom, &mut rings)?;
+            polygons.extend(PlanarPolygon::new(&rings));
+        }
+        MultiPolygon | GeometryCollection => {
+            for n in 0..geom.get_num_geometries()? {
+                planar_polygons_3d(&geom.get_geometry_n(n)?, polygons)?;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+pub fn distance_3d(a: &BinaryChunked, b: &BinaryChunked) -> GResult<Float64Chunked> {
+    type Segment = ([f64; 3], [f64; 3]);
+    // Points are segments reduced to a single point
+    fn segments(coords: &[[f64; 3]]) -> Vec<Segment> {
+        match coords {
+            [point] => vec![(*point, *point)],
+            _ => coords.windows(2).map(|s| (s[0], s[1])).collect(),
+        }
+    }
+    fn bounds(&(p, q): &Segment) -> ([f64; 3], [f64; 3]) {
+        (
+            [0, 1, 2].map(|i| p[i].min(q[i])),
+            [0, 1, 2].map(|i| p[i].max(q[i])),
+        )
+    }
+    fn parse(wkb: &[u8]) -> GResult<(Vec<Segment>, Vec<PlanarPolygon>)> {
+        let geom = Geometry::new_from_wkb(wkb)?;
+        let (mut components, mut polygons) = (vec![], vec![]);
+        components_3d(&geom, &mut components)?;
+        planar_polygons_3d(&geom, &mut polygons)?;
+        let segments = components.iter().flat_map(|coords| segments(coords));
+        Ok((segments.collect(), polygons))
+    }
+    broadcast_try_binary_elementwise_values(a, b, |a, b| {
+        let (left, left_polygons) = parse(a)?;
+        let (right, right_polygons) = parse(b)?;
+        if left.is_empty() || right.is_empty() {
+            // Empty geometries have no segments: match `distance` behavior
+            return Ok(f64::NAN);
+        }
+        // The closest point of a polygon can be inside it rather than on its boundary
+        let mut best = f64::INFINITY;
+        for (segments, polygons) in [(&left, &right_polygons), (&right, &left_polygons)] {
+            for polygon in polygons {
+                for &(p, q) in segments {
+                    if polygon.intersects_segment(p, q) {
+                        return Ok(0.0);
+                    }
+                    for distance in [p, q].map(|point| polygon.interior_distance(point)) {
+                        best = best.min(distance.unwrap_or(f64::INFINITY));
+                    }
+                }
+            }
+        }
+        // Pairs whose bounding boxes are farther apart than the best distance so far can't
+        // be any closer. The 3D distance is at least the XY distance, so the right segments
+        // are looked up in an XY tree with the left bounds grown by the best distance.
+        let right_bounds = right.iter().map(bounds).collect::<Vec<_>>();
+        let mut tree = RTreeBuilder::new(right.len() as u32);
+        for (min, max) in &right_bounds {
+            tree.add(min[0], min[1], max[0], max[1]);
+        }
+        let tree = tree.finish::<STRSort>();
+        for segment in &left {
+            let (left_bounds, (p1, q1)) = (bounds(segment), *segment);
+            let ([xmin, ymin, _], [xmax, ymax, _]) = left_bounds;
+            for hit in tree.search(xmin - best, ymin - best, xmax + best, ymax + best) {
+                let hit = hit as usize;
+                if box_distance_3d(left_bounds, right_bounds[hit]) < best {
+                    let (p2, q2) = right[hit];
+                    best = best.min(segment_distance_3d(p1, q1, p2, q2));
+                }
+            }
+        }
+        Ok::<_, GError>(best)
+    })
+}
+
 pub fn distance(a: &BinaryChunked, b: &BinaryChunked) -> GResult<Float64Chunked> {
     broadcast_try_binary_elementwise_values(a, b, |a, b| {
         let a = Geometry::new_from_wkb(a)?;
@@ -919,52 +1924,47 @@ pub fn distance(a: &BinaryChunked, b: &BinaryChunked) -> GResult<Float64Chunked>
     })
 }
 
-pub fn hausdorff_distance(a: &BinaryChunked, b: &BinaryChunked) -> GResult<Float64Chunked> {
-    broadcast_try_binary_elementwise_values(a, b, |a, b| {
-        let a = Geometry::new_from_wkb(a)?;
-        let b = Geometry::new_from_wkb(b)?;
-        a.hausdorff_distance(&b)
-    })
+fn validate_densify(densify: Option<f64>) -> GResult<()> {
+    match densify {
+        Some(densify) if !(densify > 0.0 && densify <= 1.0) => Err(GError::GenericError(
+            "densify must be a fraction in the range (0, 1]".into(),
+        )),
+        _ => Ok(()),
+    }
 }
 
-pub fn hausdorff_distance_densify(
+pub fn hausdorff_distance(
     a: &BinaryChunked,
     b: &BinaryChunked,
-    densify: f64,
+    densify: Option<f64>,
 ) -> GResult<Float64Chunked> {
+    validate_densify(densify)?;
     broadcast_try_binary_elementwise_values(a, b, |a, b| {
         let a = Geometry::new_from_wkb(a)?;
         let b = Geometry::new_from_wkb(b)?;
-        a.hausdorff_distance_densify(&b, densify)
-    })
-}
-
-pub fn frechet_distance(a: &BinaryChunked, b: &BinaryChunked) -> GResult<Float64Chunked> {
-    broadcast_try_binary_elementwise_values(a, b, |a, b| {
-        let a = Geometry::new_from_wkb(a)?;
-        let b = Geometry::new_from_wkb(b)?;
-        // TODO: bug report to GEOS
-        if a.is_empty()? || b.is_empty()? {
-            Ok(f64::NAN)
-        } else {
-            a.frechet_distance(&b)
+        match densify {
+            Some(densify) => a.hausdorff_distance_densify(&b, densify),
+            None => a.hausdorff_distance(&b),
         }
     })
 }
 
-pub fn frechet_distance_densify(
+pub fn frechet_distance(
     a: &BinaryChunked,
     b: &BinaryChunked,
-    densify: f64,
+    densify: Option<f64>,
 ) -> GResult<Float64Chunked> {
+    validate_densify(densify)?;
     broadcast_try_binary_elementwise_values(a, b, |a, b| {
         let a = Geometry::new_from_wkb(a)?;
         let b = Geometry::new_from_wkb(b)?;
         // TODO: bug report to GEOS
         if a.is_empty()? || b.is_empty()? {
-            Ok(f64::NAN)
-        } else {
-            a.frechet_distance_densify(&b, densify)
+            return Ok(f64::NAN);
+        }
+        match densify {
+            Some(densify) => a.frechet_distance_densify(&b, densify),
+            None => a.frechet_distance(&b),
         }
     })
 }
@@ -973,6 +1973,25 @@ pub fn minimum_clearance(wkb: &BinaryChunked) -> GResult<Float64Chunked> {
     wkb.try_apply_nonnull_values_generic(|wkb| Geometry::new_from_wkb(wkb)?.minimum_clearance())
 }
 
+pub fn minimum_bounding_radius(wkb: &BinaryChunked) -> GResult<Float64Chunked> {
+    wkb.try_apply_nonnull_values_generic(|wkb| {
+        let hull = Geometry::new_from_wkb(wkb)?.convex_hull()?;
+        let coords = match hull.geometry_type()? {
+            _ if hull.is_empty()? => vec![],
+            Polygon => hull
+                .get_exterior_ring()?
+                .get_coord_seq()?
+                .as_buffer(Some(2))?,
+            _ => hull.get_coord_seq()?.as_buffer(Some(2))?,
+        };
+        let points = coords
+            .chunks_exact(2)
+            .map(|coord| [coord[0], coord[1]])
+            .collect::<Vec<_>>();
+        Ok(minimum_enclosing_circle(&points).map_or(f64::NAN, |(_, radius)| radius))
+    })
+}
+
 pub fn has_z(wkb: &BinaryChunked) -> GResult<BooleanChunked> {
     wkb.try_apply_nonnull_values_generic(|wkb| Geometry::new_from_wkb(wkb)?.has_z())
 }
@@ -986,6 +2005,7 @@ pub fn is_ccw(wkb: &BinaryChunked) -> GResult<BooleanChunked> {
         let geom = Geometry::new_from_wkb(wkb)?;
         match geom.geometry_type()? {
             Point | LinearRing | LineString | CircularString => geom.get_coord_seq()?.is_ccw(),
+            Polygon if !geom.is_empty()? => geom.get_exterior_ring()?.get_coord_seq()?.is_ccw(),
             _ => Ok(false),
         }
     })
@@ -1016,13 +2036,103 @@ pub fn is_simple(wkb: &BinaryChunked) -> GResult<BooleanChunked> {
 }
 
 pub fn is_valid(wkb: &BinaryChunked) -> GResult<BooleanChunked> {
-    wkb.try_apply_nonnull_values_generic(|wkb| Geometry::new_from_wkb(wkb)?.is_valid())
+    wkb.try_apply_nonnull_values_generic(|wkb| match is_trivially_valid(wkb) {
+        true => Ok(true),
+        false => Geometry::new_from_wkb(wkb)?.is_valid(),
+    })
 }
 
 pub fn is_valid_reason(wkb: &BinaryChunked) -> GResult<StringChunked> {
     wkb.try_apply_nonnull_values_generic(|wkb| Geometry::new_from_wkb(wkb)?.is_valid_reason())
 }
 
+type ValidityDetail = (BooleanChunked, StringChunked, BinaryChunked);
+
+pub fn is_valid_detail(wkb: &BinaryChunked) -> GResult<ValidityDetail> {
+    let mut valid = Vec::with_capacity(wkb.len());
+    let mut reasons = Vec::with_capacity(wkb.len());
+    let mut locations = BinaryChunkedBuilder::new("location".into(), wkb.len());
+    for wkb in wkb {
+        let Some(wkb) = wkb else {
+            valid.push(None);
+            reasons.push(None);
+            locations.append_null();
+            continue;
+        };
+        let geom = Geometry::new_from_wkb(wkb)?;
+        // GEOSisValidDetail gives the location as a geometry, rather than formatted in
+        // the reason like GEOSisValidReason does
+        let Some((reason, mut location)) = geom.is_valid_detail(false)? else {
+            valid.push(Some(true));
+            reasons.push(None);
+            locations.append_null();
+            continue;
+        };
+        valid.push(Some(false));
+        reasons.push(Some(reason));
+        location.set_srid(geom.get_srid()?);
+        locations.append_value(location.to_ewkb()?);
+    }
+    Ok((
+        BooleanChunked::from_iter_options("is_valid".into(), valid.into_iter()),
+        StringChunked::from_iter_options("reason".into(), reasons.into_iter()),
+        locations.finish(),
+    ))
+}
+
+type PreparedPredicate = fn(&PreparedGeometry<'_>, &Geometry) -> GResult<bool>;
+
+fn envelopes_disjoint(geom: &Geometry, extent: &[f64]) -> GResult<bool> {
+    Ok(geom.get_x_max()? < extent[0]
+        || geom.get_x_min()? > extent[2]
+        || geom.get_y_max()? < extent[1]
+        || geom.get_y_min()? > extent[3])
+}
+
+/// Evaluate an intersecting predicate such as `intersects`, `contains` or `within`.
+/// When one side is a scalar, it is prepared once and tested against each row with
+/// `prepared`, or `reversed` when the scalar is on the right side. Rows whose envelope
+/// is disjoint from the scalar envelope are rejected without calling GEOS.
+fn intersecting_predicate(
+    a: &BinaryChunked,
+    b: &BinaryChunked,
+    predicate: fn(&Geometry, &Geometry) -> GResult<bool>,
+    prepared: PreparedPredicate,
+    reversed: PreparedPredicate,
+) -> GResult<BooleanChunked> {
+    let (scalar, column, prepared) = match (a.len(), b.len()) {
+        (1, len) if len != 1 => (a, b, prepared),
+        (len, 1) if len != 1 => (b, a, reversed),
+        _ => {
+            return broadcast_try_binary_elementwise_values(a, b, |a, b| {
+                let a = Geometry::new_from_wkb(a)?;
+                let b = Geometry::new_from_wkb(b)?;
+                predicate(&a, &b)
+            })
+        }
+    };
+    let Some(scalar) = scalar.get(0) else {
+        return Ok(BooleanChunked::full_null(a.name().clone(), column.len()));
+    };
+    let scalar = Geometry::new_from_wkb(scalar)?;
+    let scalar_prepared = scalar.to_prepared_geom()?;
+    let extent = match scalar.is_empty()? {
+        true => None,
+        false => Some(scalar.get_extent()?),
+    };
+    column
+        .try_apply_nonnull_values_generic(|wkb| {
+            let geom = Geometry::new_from_wkb(wkb)?;
+            if let Some(extent) = &extent {
+                if !geom.is_empty()? && envelopes_disjoint(&geom, extent)? {
+                    return Ok(false);
+                }
+            }
+            prepared(&scalar_prepared, &geom)
+        })
+        .map(|ca| ca.with_name(a.name().clone()))
+}
+
 pub fn crosses(a: &BinaryChunked, b: &BinaryChunked) -> GResult<BooleanChunked> {
     broadcast_try_binary_elementwise_values(a, b, |a, b| {
         let a = Geometry::new_from_wkb(a)?;
@@ -1032,11 +2142,60 @@ pub fn crosses(a: &BinaryChunked, b: &BinaryChunked) -> GResult<BooleanChunked>
 }
 
 pub fn contains(a: &BinaryChunked, b: &BinaryChunked) -> GResult<BooleanChunked> {
-    broadcast_try_binary_elementwise_values(a, b, |a, b| {
-        let a = Geometry::new_from_wkb(a)?;
-        let b = Geometry::new_from_wkb(b)?;
-        Geometry::contains(&a, &b)
-    })
+    intersecting_predicate(
+        a,
+        b,
+        |a, b| a.contains(b),
+        |a, b| a.contains(b),
+        |b, a| b.within(a),
+    )
+}
+
+/// Evaluate a prepared predicate between each geometry and the point on the same row.
+/// Rows are grouped by geometry, so that each distinct geometry is prepared once and
+/// tested against all of its points, whether it is a scalar or repeated in a column.
+fn prepared_points_predicate(
+    wkb: &BinaryChunked,
+    points: &BinaryChunked,
+    predicate: PreparedPredicate,
+) -> GResult<BooleanChunked> {
+    fn get(ca: &BinaryChunked, index: usize) -> Option<&[u8]> {
+        ca.get(if ca.len() == 1 { 0 } else { index })
+    }
+    let len = match (wkb.len(), points.len()) {
+        (1, len) | (len, 1) => len,
+        (a, b) if a == b => a,
+        _ => {
+            return Err(GError::GenericError(
+                "Points must have the same length as the geometries".into(),
+            ))
+        }
+    };
+    let mut rows = HashMap::<&[u8], Vec<usize>>::new();
+    for index in 0..len {
+        if let (Some(wkb), Some(_)) = (get(wkb, index), get(points, index)) {
+            rows.entry(wkb).or_default().push(index);
+        }
+    }
+    let mut result = vec![None; len];
+    for (wkb, indices) in rows {
+        let geom = Geometry::new_from_wkb(wkb)?;
+        let prepared = geom.to_prepared_geom()?;
+        for index in indices {
+            if let Some(point) = get(points, index) {
+                let point = Geometry::new_from_wkb(point)?;
+                result[index] = Some(predicate(&prepared, &point)?);
+            }
+        }
+    }
+    Ok(BooleanChunked::from_iter_options(
+        wkb.name().clone(),
+        result.into_iter(),
+    ))
+}
+
+pub fn contains_points(wkb: &BinaryChunked, points: &BinaryChunked) -> GResult<BooleanChunked> {
+    prepared_points_predicate(wkb, points, |a, b| a.contains(b))
 }
 
 pub fn contains_properly(a: &BinaryChunked, b: &BinaryChunked) -> GResult<BooleanChunked> {
@@ -1085,11 +2244,17 @@ pub fn dwithin(
 }
 
 pub fn intersects(a: &BinaryChunked, b: &BinaryChunked) -> GResult<BooleanChunked> {
-    broadcast_try_binary_elementwise_values(a, b, |a, b| {
-        let a = Geometry::new_from_wkb(a)?;
-        let b = Geometry::new_from_wkb(b)?;
-        Geometry::intersects(&a, &b)
-    })
+    intersecting_predicate(
+        a,
+        b,
+        |a, b| a.intersects(b),
+        |a, b| a.intersects(b),
+        |b, a| b.intersects(a),
+    )
+}
+
+pub fn intersects_points(wkb: &BinaryChunked, points: &BinaryChunked) -> GResult<BooleanChunked> {
+    prepared_points_predicate(wkb, points, |a, b| a.intersects(b))
 }
 
 pub fn overlaps(a: &BinaryChunked, b: &BinaryChunked) -> GResult<BooleanChunked> {
@@ -1109,17 +2274,27 @@ pub fn touches(a: &BinaryChunked, b: &BinaryChunked) -> GResult<BooleanChunked>
 }
 
 pub fn within(a: &BinaryChunked, b: &BinaryChunked) -> GResult<BooleanChunked> {
+    intersecting_predicate(
+        a,
+        b,
+        |a, b| a.within(b),
+        |a, b| a.within(b),
+        |b, a| b.contains(a),
+    )
+}
+
+pub fn equals(a: &BinaryChunked, b: &BinaryChunked) -> GResult<BooleanChunked> {
     broadcast_try_binary_elementwise_values(a, b, |a, b| {
         let a = Geometry::new_from_wkb(a)?;
         let b = Geometry::new_from_wkb(b)?;
-        Geometry::within(&a, &b)
+        Geometry::equals(&a, &b)
     })
 }
 
-pub fn equals(a: &BinaryChunked, b: &BinaryChunked) -> GResult<BooleanChunked> {
+pub fn equal_boundary(a: &BinaryChunked, b: &BinaryChunked) -> GResult<BooleanChunked> {
     broadcast_try_binary_elementwise_values(a, b, |a, b| {
-        let a = Geometry::new_from_wkb(a)?;
-        let b = Geometry::new_from_wkb(b)?;
+        let a = topological_boundary(&Geometry::new_from_wkb(a)?)?;
+        let b = topological_boundary(&Geometry::new_from_wkb(b)?)?;
         Geometry::equals(&a, &b)
     })
 }
@@ -1135,32 +2310,73 @@ pub fn equals_identical(a: &BinaryChunked, b: &BinaryChunked) -> GResult<Boolean
 pub fn equals_exact(
     a: &BinaryChunked,
     b: &BinaryChunked,
-    tolerance: f64,
+    tolerance: &Float64Chunked,
 ) -> GResult<BooleanChunked> {
-    broadcast_try_binary_elementwise_values(a, b, |a, b| {
+    broadcast_try_ternary_elementwise_values(a, b, tolerance, |a, b, tolerance| {
         let a = Geometry::new_from_wkb(a)?;
         let b = Geometry::new_from_wkb(b)?;
         Geometry::equals_exact(&a, &b, tolerance)
     })
 }
 
-pub fn relate(a: &BinaryChunked, b: &BinaryChunked) -> GResult<StringChunked> {
+pub fn relate(
+    a: &BinaryChunked,
+    b: &BinaryChunked,
+    boundary_node_rule: BoundaryNodeRule,
+) -> GResult<StringChunked> {
     broadcast_try_binary_elementwise_values(a, b, |a, b| {
         let a = Geometry::new_from_wkb(a)?;
         let b = Geometry::new_from_wkb(b)?;
-        Geometry::relate(&a, &b)
+        match boundary_node_rule {
+            BoundaryNodeRule::Mod2 => Geometry::relate(&a, &b),
+            rule => a.relate_boundary_node_rule(&b, rule.into()),
+        }
+    })
+}
+
+/// Return the pattern in uppercase, as GEOS only accepts uppercase `T` and `F`.
+fn validate_relate_pattern(pattern: &str, index: usize) -> GResult<String> {
+    let upper = pattern.to_ascii_uppercase();
+    if upper.len() != 9 || !upper.chars().all(|c| "TF*012".contains(c)) {
+        let msg = format!("Invalid DE-9IM pattern at row {index}: `{pattern}`");
+        return Err(GError::GenericError(msg));
+    }
+    Ok(upper)
+}
+
+/// Match a DE-9IM intersection matrix against a validated pattern.
+fn matches_relate_pattern(matrix: &str, pattern: &str) -> bool {
+    matrix.chars().zip(pattern.chars()).all(|(m, p)| match p {
+        '*' => true,
+        'T' => m != 'F',
+        p => m == p,
     })
 }
 
 pub fn relate_pattern(
     a: &BinaryChunked,
     b: &BinaryChunked,
-    pattern: &str,
+    pattern: &StringChunked,
+    boundary_node_rule: BoundaryNodeRule,
 ) -> GResult<BooleanChunked> {
-    broadcast_try_binary_elementwise_values(a, b, |a, b| {
+    let mut index = 0;
+    broadcast_try_ternary_elementwise(a, b, pattern, |a, b, pattern| {
+        let row = index;
+        index += 1;
+        let (Some(a), Some(b), Some(pattern)) = (a, b, pattern) else {
+            return Ok(None);
+        };
+        let pattern = validate_relate_pattern(pattern, row)?;
         let a = Geometry::new_from_wkb(a)?;
         let b = Geometry::new_from_wkb(b)?;
-        Geometry::relate_pattern(&a, &b, pattern)
+        let matches = match boundary_node_rule {
+            BoundaryNodeRule::Mod2 => Geometry::relate_pattern(&a, &b, &pattern)?,
+            rule => {
+                let matrix = a.relate_boundary_node_rule(&b, rule.into())?;
+                matches_relate_pattern(&matrix, &pattern)
+            }
+        };
+        Ok(Some(matches))
     })
 }
 
@@ -1262,15 +2478,34 @@ pub fn union_prec(a: &BinaryChunked, b: &BinaryChunked, grid_size: f64) -> GResu
     })
 }
 
+/// Parse a geometry used as a coverage, which must be a collection.
+fn coverage_from_wkb(wkb: &[u8]) -> GResult<Geometry> {
+    let geom = Geometry::new_from_wkb(wkb)?;
+    if geom.geometry_type()?.is_collection() {
+        Ok(geom)
+    } else {
+        let msg = "Geometry must be a collection";
+        Err(GError::GenericError(msg.into()))
+    }
+}
+
 pub fn coverage_union(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
+    wkb.try_apply_nonnull_values_generic(|wkb| coverage_from_wkb(wkb)?.coverage_union()?.to_ewkb())
+}
+
+pub fn coverage_is_valid(wkb: &BinaryChunked, gap_width: f64) -> GResult<BooleanChunked> {
+    wkb.try_apply_nonnull_values_generic(|wkb| coverage_from_wkb(wkb)?.coverage_is_valid(gap_width))
+}
+
+pub fn coverage_simplify(
+    wkb: &BinaryChunked,
+    tolerance: f64,
+    simplify_boundary: bool,
+) -> GResult<BinaryChunked> {
     wkb.try_apply_nonnull_values_generic(|wkb| {
-        let geom = Geometry::new_from_wkb(wkb)?;
-        if geom.geometry_type()?.is_collection() {
-            geom.coverage_union()?.to_ewkb()
-        } else {
-            let msg = "Geometry must be a collection";
-            Err(GError::GenericError(msg.into()))
-        }
+        coverage_from_wkb(wkb)?
+            .coverage_simplify_vw(tolerance, !simplify_boundary)?
+            .to_ewkb()
     })
 }
 
@@ -1278,6 +2513,45 @@ fn collect_geometry_vec(wkb: &BinaryChunked) -> GResult<Vec<Geometry>> {
     wkb.iter().flatten().map(Geometry::new_from_wkb).collect()
 }
 
+pub fn intersection_all(wkb: &BinaryChunked, grid_size: Option<f64>) -> GResult<BinaryChunked> {
+    let mut geometries = collect_geometry_vec(wkb)?.into_iter();
+    let mut intersection = match geometries.next() {
+        Some(geom) => geom,
+        None => Geometry::create_empty_collection(GeometryCollection)?,
+    };
+    for geom in geometries {
+        if intersection.is_empty()? {
+            break;
+        }
+        intersection = match grid_size {
+            Some(grid_size) => intersection.intersection_prec(&geom, grid_size)?,
+            None => intersection.intersection(&geom)?,
+        };
+    }
+    Ok(BinaryChunked::from_slice(
+        wkb.name().clone(),
+        &[intersection.to_ewkb()?],
+    ))
+}
+
+pub fn union_all(wkb: &BinaryChunked, grid_size: Option<f64>) -> GResult<BinaryChunked> {
+    let geometries = collect_geometry_vec(wkb)?;
+    let srid = match geometries.first() {
+        Some(geom) => geom.get_srid()?,
+        None => 0,
+    };
+    let collection = Geometry::create_geometry_collection(geometries)?;
+    let mut union = match grid_size {
+        Some(grid_size) => collection.unary_union_prec(grid_size)?,
+        None => collection.unary_union()?,
+    };
+    union.set_srid(srid);
+    Ok(BinaryChunked::from_slice(
+        wkb.name().clone(),
+        &[union.to_ewkb()?],
+    ))
+}
+
 pub fn coverage_union_all(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
     collect_geometry_vec(wkb)
         .and_then(Geometry::create_geometry_collection)
@@ -1293,14 +2567,24 @@ pub fn polygonize(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
         .map(|res| BinaryChunked::from_slice(wkb.name().clone(), &[res]))
 }
 
-fn aggregate_with<F>(wkb: &BinaryChunked, func: F) -> GResult<BinaryChunked>
-where
-    F: FnOnce(Vec<Geometry>) -> GResult<Geometry>,
-{
-    collect_geometry_vec(wkb)
-        .and_then(func)
-        .and_then(|geom| geom.to_ewkb())
-        .map(|res| BinaryChunked::from_slice(wkb.name().clone(), &[res]))
+type PolygonizeFull = (BinaryChunked, BinaryChunked, BinaryChunked, BinaryChunked);
+
+pub fn polygonize_full(wkb: &BinaryChunked) -> GResult<PolygonizeFull> {
+    let collection = collect_geometry_vec(wkb).and_then(Geometry::create_geometry_collection)?;
+    let (polygons, cut_edges, dangles, invalid_rings) = collection.polygonize_full()?;
+    let output = |name: &str, geom: Option<Geometry>| {
+        let geom = match geom {
+            Some(geom) => geom,
+            None => Geometry::create_empty_collection(GeometryCollection)?,
+        };
+        Ok::<_, GError>(BinaryChunked::from_slice(name.into(), &[geom.to_ewkb()?]))
+    };
+    Ok((
+        output("polygons", Some(polygons))?,
+        output("dangles", dangles)?,
+        output("cut_edges", cut_edges)?,
+        output("invalid_rings", invalid_rings)?,
+    ))
 }
 
 fn collection_supertype(wkb: &BinaryChunked) -> GResult<GeometryTypes> {
@@ -1327,31 +2611,193 @@ fn collection_supertype(wkb: &BinaryChunked) -> GResult<GeometryTypes> {
     })
 }
 
-pub fn collect(wkb: &BinaryChunked, into: Option<WKBGeometryType>) -> GResult<BinaryChunked> {
-    let into = match into {
+pub fn collect(
+    wkb: &BinaryChunked,
+    into: Option<WKBGeometryType>,
+    on_mismatch: CollectOnMismatch,
+) -> GResult<BinaryChunked> {
+    let mut into = match into {
         Some(into) => into.try_into(),
         None => collection_supertype(wkb),
     }?;
-    match into {
-        MultiPoint => aggregate_with(wkb, Geometry::create_multipoint),
-        MultiLineString => aggregate_with(wkb, Geometry::create_multiline_string),
-        MultiCurve => aggregate_with(wkb, Geometry::create_multicurve),
-        MultiPolygon => aggregate_with(wkb, Geometry::create_multipolygon),
-        MultiSurface => aggregate_with(wkb, Geometry::create_multisurface),
-        GeometryCollection => aggregate_with(wkb, Geometry::create_geometry_collection),
-        _ => Err(GError::GenericError("type must be a collection".into())),
+    let members: Option<&[GeometryTypes]> = match into {
+        MultiPoint => Some(&[Point]),
+        MultiLineString => Some(&[LineString, LinearRing]),
+        MultiCurve => Some(&[LineString, LinearRing, CircularString, CompoundCurve]),
+        MultiPolygon => Some(&[Polygon]),
+        MultiSurface => Some(&[Polygon, CurvePolygon]),
+        GeometryCollection => None,
+        _ => return Err(GError::GenericError("type must be a collection".into())),
+    };
+    let mut geometries = collect_geometry_vec(wkb)?;
+    if let Some(members) = members {
+        let types = geometries
+            .iter()
+            .map(Geom::geometry_type)
+            .collect::<GResult<Vec<_>>>()?;
+        let mut mismatched: Vec<GeometryTypes> = vec![];
+        for geometry_type in &types {
+            if !members.contains(geometry_type) && !mismatched.contains(geometry_type) {
+                mismatched.push(*geometry_type);
+            }
+        }
+        if !mismatched.is_empty() {
+            match on_mismatch {
+                CollectOnMismatch::Raise => {
+                    let names = mismatched.iter().map(|t| format!("{t:?}"));
+                    let names = names.collect::<Vec<_>>().join(", ");
+                    let msg = format!("Cannot collect geometries of type {names} into {into:?}");
+                    return Err(GError::GenericError(msg));
+                }
+                CollectOnMismatch::Skip => {
+                    geometries = geometries
+                        .into_iter()
+                        .zip(types)
+                        .filter_map(|(geom, t)| members.contains(&t).then_some(geom))
+                        .collect();
+                }
+                CollectOnMismatch::Wrap => into = GeometryCollection,
+            }
+        }
     }
+    let collection = match into {
+        MultiPoint => Geometry::create_multipoint(geometries),
+        MultiLineString => Geometry::create_multiline_string(geometries),
+        MultiCurve => Geometry::create_multicurve(geometries),
+        MultiPolygon => Geometry::create_multipolygon(geometries),
+        MultiSurface => Geometry::create_multisurface(geometries),
+        _ => Geometry::create_geometry_collection(geometries),
+    }?;
+    Ok(BinaryChunked::from_slice(
+        wkb.name().clone(),
+        &[collection.to_ewkb()?],
+    ))
 }
 
-pub fn boundary(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
-    wkb.try_apply_nonnull_values_generic(|wkb| {
-        let geom = Geometry::new_from_wkb(wkb)?;
-        match geom.geometry_type()? {
-            GeometryCollection => Geometry::create_empty_collection(GeometryCollection),
-            _ => geom.boundary(),
-        }?
-        .to_ewkb()
-    })
+/// Return the coordinates of a group of points in row order, with the dimensions and
+/// SRID of the first point. Empty points are skipped.
+fn collect_point_coordinates(
+    wkb: &BinaryChunked,
+    into: GeometryTypes,
+) -> GResult<(Vec<f64>, bool, bool, i32)> {
+    let mut coords = vec![];
+    let mut first = None;
+    for geom in collect_geometry_vec(wkb)? {
+        let geometry_type = geom.geometry_type()?;
+        if geometry_type != Point {
+            let msg =
+                format!("Only points can be collected into a {into:?}, got {geometry_type:?}");
+            return Err(GError::GenericError(msg));
+        }
+        if geom.is_empty()? {
+            continue;
+        }
+        let (has_z, has_m, _) = match first {
+            Some(first) => first,
+            None => *first.insert((geom.has_z()?, geom.has_m()?, geom.get_srid()?)),
+        };
+        let dims = 2 + usize::from(has_z) + usize::from(has_m);
+        coords.extend(geom.get_coord_seq()?.as_buffer(Some(dims))?);
+    }
+    let (has_z, has_m, srid) = first.unwrap_or((false, false, 0));
+    Ok((coords, has_z, has_m, srid))
+}
+
+pub fn collect_line(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
+    let (coords, has_z, has_m, srid) = collect_point_coordinates(wkb, LineString)?;
+    let dims = 2 + usize::from(has_z) + usize::from(has_m);
+    let size = coords.len() / dims;
+    if size < 2 {
+        let msg = "At least two points are required to build a LineString";
+        return Err(GError::GenericError(msg.into()));
+    }
+    let mut line =
+        Geometry::create_line_string(CoordSeq::new_from_buffer(&coords, size, has_z, has_m)?)?;
+    line.set_srid(srid);
+    Ok(BinaryChunked::from_slice(
+        wkb.name().clone(),
+        &[line.to_ewkb()?],
+    ))
+}
+
+pub fn collect_polygon(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
+    let (mut coords, has_z, has_m, srid) = collect_point_coordinates(wkb, Polygon)?;
+    let dims = 2 + usize::from(has_z) + usize::from(has_m);
+    if coords.len() >= dims && coords[..dims] != coords[coords.len() - dims..] {
+        coords.extend_from_within(..dims);
+    }
+    let size = coords.len() / dims;
+    if size < 4 {
+        let msg = "At least three distinct points are required to build a Polygon";
+        return Err(GError::GenericError(msg.into()));
+    }
+    let shell = CoordSeq::new_from_buffer(&coords, size, has_z, has_m)?;
+    let mut polygon = Geometry::create_polygon(Geometry::create_linear_ring(shell)?, vec![])?;
+    polygon.set_srid(srid);
+    Ok(BinaryChunked::from_slice(
+        wkb.name().clone(),
+        &[polygon.to_ewkb()?],
+    ))
+}
+
+fn snap_to_precision(geom: Geometry, grid_size: Option<f64>) -> GResult<Geometry> {
+    match grid_size {
+        Some(grid_size) => geom.set_precision(grid_size, geos::Precision::ValidOutput),
+        None => Ok(geom),
+    }
+}
+
+fn normalized_ewkb(wkb: &[u8], grid_size: Option<f64>) -> GResult<Vec<u8>> {
+    let mut geom = snap_to_precision(Geometry::new_from_wkb(wkb)?, grid_size)?;
+    geom.normalize()?;
+    geom.to_ewkb()
+}
+
+pub fn unique_geometries(
+    wkb: &BinaryChunked,
+    grid_size: Option<f64>,
+) -> GResult<(BinaryChunked, UInt32Chunked)> {
+    let mut seen = HashSet::new();
+    let mut geometries = vec![];
+    let mut indices = vec![];
+    for (index, wkb) in wkb.iter().enumerate() {
+        let Some(wkb) = wkb else {
+            continue;
+        };
+        if seen.insert(normalized_ewkb(wkb, grid_size)?) {
+            geometries.push(wkb);
+            indices.push(index as u32);
+        }
+    }
+    Ok((
+        BinaryChunked::from_slice(wkb.name().clone(), &geometries),
+        UInt32Chunked::from_vec("index".into(), indices),
+    ))
+}
+
+/// Hash the normalized EWKB of each geometry, so that geometries that are equal but
+/// encoded differently share the same hash.
+pub fn geometry_hash(wkb: &BinaryChunked, grid_size: Option<f64>) -> GResult<UInt64Chunked> {
+    wkb.try_apply_nonnull_values_generic(|wkb| {
+        let mut hasher = DefaultHasher::new();
+        normalized_ewkb(wkb, grid_size)?.hash(&mut hasher);
+        Ok(hasher.finish())
+    })
+}
+
+/// GEOS has no boundary for geometry collections, which are given an empty one.
+fn topological_boundary(geom: &Geometry) -> GResult<Geometry> {
+    match geom.geometry_type()? {
+        GeometryCollection => Geometry::create_empty_collection(GeometryCollection),
+        _ => geom.boundary(),
+    }
+}
+
+pub fn boundary(wkb: &BinaryChunked, grid_size: Option<f64>) -> GResult<BinaryChunked> {
+    wkb.try_apply_nonnull_values_generic(|wkb| {
+        let boundary = topological_boundary(&Geometry::new_from_wkb(wkb)?)?;
+        snap_to_precision(boundary, grid_size)?.to_ewkb()
+    })
 }
 
 pub fn buffer(
@@ -1360,10 +2806,90 @@ pub fn buffer(
     params: &BufferKwargs,
 ) -> GResult<BinaryChunked> {
     let buffer_params: BufferParams = params.try_into()?;
-    broadcast_try_binary_elementwise_values(wkb, distance, |wkb, distance| {
-        Geometry::new_from_wkb(wkb)?
-            .buffer_with_params(distance, &buffer_params)?
+    broadcast_try_binary_elementwise(wkb, distance, |wkb, distance| {
+        let (Some(wkb), Some(distance)) = (wkb, distance) else {
+            return Ok(None);
+        };
+        let geom = Geometry::new_from_wkb(wkb)?;
+        if distance < 0.0 && !geom.is_empty()? && geom.get_num_dimensions()? < 2 {
+            let msg = "Negative buffer distance requires a polygonal geometry";
+            return Err(GError::GenericError(msg.into()));
+        }
+        let buffered = geom.buffer_with_params(distance, &buffer_params)?;
+        let buffered = snap_to_precision(buffered, params.output_precision)?;
+        if distance < 0.0 && buffered.is_empty()? {
+            match params.on_empty {
+                BufferOnEmpty::Null => return Ok(None),
+                BufferOnEmpty::Original => return Ok(Some(wkb.to_vec())),
+                BufferOnEmpty::Empty => {}
+            }
+        }
+        buffered.to_ewkb().map(Some)
+    })
+}
+
+/// Get the value of a parameter column at `index`, broadcasting single values.
+fn broadcast_get<T: PolarsDataType>(ca: &ChunkedArray<T>, index: usize) -> Option<T::Physical<'_>> {
+    match ca.len() {
+        1 => ca.get(0),
+        _ => ca.get(index),
+    }
+}
+
+/// Parse a style name with the same names as its keyword argument counterpart.
+fn parse_style<T: DeserializeOwned>(name: &str, value: &str) -> GResult<T> {
+    T::deserialize(StrDeserializer::<serde::de::value::Error>::new(value))
+        .map_err(|_| GError::GenericError(format!("Invalid {name}: `{value}`")))
+}
+
+pub fn buffer_with_columns(
+    wkb: &BinaryChunked,
+    distance: &Float64Chunked,
+    quad_segs: &Int32Chunked,
+    cap_style: &StringChunked,
+    join_style: &StringChunked,
+    mitre_limit: &Float64Chunked,
+) -> GResult<BinaryChunked> {
+    let lengths = [
+        distance.len(),
+        quad_segs.len(),
+        cap_style.len(),
+        join_style.len(),
+        mitre_limit.len(),
+    ];
+    if lengths.iter().any(|&len| len != 1 && len != wkb.len()) {
+        return Err(GError::GenericError(
+            "Buffer parameters must have the same length as the geometries".into(),
+        ));
+    }
+    let mut index = 0;
+    try_unary_elementwise(wkb, |wkb| {
+        let row = index;
+        index += 1;
+        let (Some(wkb), Some(distance), Some(quad_segs), Some(cap), Some(join), Some(mitre)) = (
+            wkb,
+            broadcast_get(distance, row),
+            broadcast_get(quad_segs, row),
+            broadcast_get(cap_style, row),
+            broadcast_get(join_style, row),
+            broadcast_get(mitre_limit, row),
+        ) else {
+            return Ok(None);
+        };
+        let buffer_params = BufferParams::builder()
+            .quadrant_segments(quad_segs)
+            .end_cap_style(parse_style::<CapStyle>("cap style", cap)?.into())
+            .join_style(parse_style::<JoinStyle>("join style", join)?.into())
+            .mitre_limit(mitre)
+            .build()?;
+        let geom = Geometry::new_from_wkb(wkb)?;
+        if distance < 0.0 && !geom.is_empty()? && geom.get_num_dimensions()? < 2 {
+            let msg = "Negative buffer distance requires a polygonal geometry";
+            return Err(GError::GenericError(msg.into()));
+        }
+        geom.buffer_with_params(distance, &buffer_params)?
             .to_ewkb()
+            .map(Some)
     })
 }
 
@@ -1372,15 +2898,31 @@ pub fn offset_curve(
     distance: &Float64Chunked,
     params: &OffsetCurveKwargs,
 ) -> GResult<BinaryChunked> {
-    broadcast_try_binary_elementwise_values(wkb, distance, |wkb, distance| {
-        Geometry::new_from_wkb(wkb)?
-            .offset_curve(
+    let buffer_params: BufferParams = params.try_into()?;
+    broadcast_try_binary_elementwise(wkb, distance, |wkb, distance| {
+        let (Some(wkb), Some(distance)) = (wkb, distance) else {
+            return Ok(None);
+        };
+        let geom = Geometry::new_from_wkb(wkb)?;
+        let line = match geom.geometry_type()? {
+            LineString | LinearRing | MultiLineString | CircularString | CompoundCurve
+            | MultiCurve => geom,
+            Polygon | MultiPolygon | CurvePolygon | MultiSurface if params.boundary => {
+                geom.boundary()?
+            }
+            GeometryCollection if !geom.is_empty()? && geom.get_num_dimensions()? == 1 => geom,
+            _ => return Ok(None),
+        };
+        let offset = match params.single_sided {
+            true => line.buffer_with_params(distance, &buffer_params)?,
+            false => line.offset_curve(
                 distance,
                 params.quad_segs,
                 params.join_style.into(),
                 params.mitre_limit,
-            )?
-            .to_ewkb()
+            )?,
+        };
+        offset.to_ewkb().map(Some)
     })
 }
 
@@ -1390,6 +2932,53 @@ pub fn get_centroid(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
     })
 }
 
+/// Return the largest non-empty part of a geometry, preferring parts of higher dimension,
+/// then larger area for polygons or length for lines, along with its dimension and size.
+fn largest_part<G: Geom>(geom: &G) -> GResult<Option<(usize, f64, Geometry)>> {
+    if geom.is_empty()? {
+        return Ok(None);
+    }
+    match geom.geometry_type()? {
+        MultiPoint | MultiLineString | MultiCurve | MultiPolygon | MultiSurface
+        | GeometryCollection => {
+            let mut largest: Option<(usize, f64, Geometry)> = None;
+            for n in 0..geom.get_num_geometries()? {
+                let Some(part) = largest_part(&geom.get_geometry_n(n)?)? else {
+                    continue;
+                };
+                let is_larger = largest
+                    .as_ref()
+                    .is_none_or(|(dimension, size, _)| (part.0, part.1) > (*dimension, *size));
+                if is_larger {
+                    largest = Some(part);
+                }
+            }
+            Ok(largest)
+        }
+        _ => {
+            let dimension = geom.get_num_dimensions()?;
+            let size = match dimension {
+                2 => geom.area()?,
+                1 => geom.length()?,
+                _ => 0.0,
+            };
+            Ok(Some((dimension, size, Geom::clone(geom)?)))
+        }
+    }
+}
+
+pub fn largest_part_centroid(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
+    wkb.try_apply_nonnull_values_generic(|wkb| {
+        let geom = Geometry::new_from_wkb(wkb)?;
+        let mut centroid = match largest_part(&geom)? {
+            Some((_, _, part)) => part.get_centroid()?,
+            None => Geometry::create_empty_point()?,
+        };
+        centroid.set_srid(geom.get_srid()?);
+        centroid.to_ewkb()
+    })
+}
+
 pub fn get_center(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
     wkb.try_apply_nonnull_values_generic(|wkb| {
         let geom = Geometry::new_from_wkb(wkb)?;
@@ -1402,16 +2991,91 @@ pub fn get_center(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
     })
 }
 
-pub fn clip_by_rect(wkb: &BinaryChunked, rect: &ArrayChunked) -> GResult<BinaryChunked> {
+fn collect_parts_with_dimension<T: Geom>(
+    geom: &T,
+    dimension: i32,
+    parts: &mut Vec<Geometry>,
+) -> GResult<()> {
+    if geom.is_empty()? || geom.get_num_dimensions()? != dimension {
+        return Ok(());
+    }
+    if geom.geometry_type()?.is_collection() {
+        for n in 0..geom.get_num_geometries()? {
+            collect_parts_with_dimension(&geom.get_geometry_n(n)?, dimension, parts)?;
+        }
+    } else {
+        parts.push(Geom::clone(geom)?);
+    }
+    Ok(())
+}
+
+fn clip_to_mask(
+    geom: &Geometry,
+    mask: &Geometry,
+    prepared_mask: &PreparedGeometry,
+) -> GResult<Geometry> {
+    if geom.is_empty()? || prepared_mask.covers(geom)? {
+        return Geom::clone(geom);
+    }
+    let mut clipped = if geom.geometry_type()? == GeometryCollection {
+        let mut parts = vec![];
+        for n in 0..geom.get_num_geometries()? {
+            let part = clip_to_mask(&geom.get_geometry_n(n)?.clone()?, mask, prepared_mask)?;
+            if !part.is_empty()? {
+                parts.push(part);
+            }
+        }
+        Geometry::create_geometry_collection(parts)?
+    } else {
+        // Drop the lower-dimension parts created where the geometry only touches the mask.
+        let dimension = geom.get_num_dimensions()?;
+        let mut parts = vec![];
+        collect_parts_with_dimension(&geom.intersection(mask)?, dimension, &mut parts)?;
+        match (dimension, parts.len()) {
+            (_, 1) => parts.pop().unwrap(),
+            (0, 0) => Geometry::create_empty_point()?,
+            (0, _) => Geometry::create_multipoint(parts)?,
+            (1, 0) => Geometry::create_empty_line_string()?,
+            (1, _) => Geometry::create_multiline_string(parts)?,
+            (_, 0) => Geometry::create_empty_polygon()?,
+            (_, _) => Geometry::create_multipolygon(parts)?,
+        }
+    };
+    clipped.set_srid(geom.get_srid()?);
+    Ok(clipped)
+}
+
+pub fn clip(wkb: &BinaryChunked, mask: &BinaryChunked) -> GResult<BinaryChunked> {
+    if mask.len() == 1 {
+        let Some(mask) = mask.get(0) else {
+            return Ok(BinaryChunked::full_null(wkb.name().clone(), wkb.len()));
+        };
+        let mask = Geometry::new_from_wkb(mask)?;
+        let prepared_mask = mask.to_prepared_geom()?;
+        return wkb.try_apply_nonnull_values_generic(|wkb| {
+            clip_to_mask(&Geometry::new_from_wkb(wkb)?, &mask, &prepared_mask)?.to_ewkb()
+        });
+    }
+    broadcast_try_binary_elementwise_values(wkb, mask, |wkb, mask| {
+        let mask = Geometry::new_from_wkb(mask)?;
+        let prepared_mask = mask.to_prepared_geom()?;
+        clip_to_mask(&Geometry::new_from_wkb(wkb)?, &mask, &prepared_mask)?.to_ewkb()
+    })
+}
+
+pub fn clip_by_rect(
+    wkb: &BinaryChunked,
+    rect: &ArrayChunked,
+    grid_size: Option<f64>,
+) -> GResult<BinaryChunked> {
     broadcast_try_binary_elementwise_values(wkb, rect, |wkb, rect| {
         let rect = rect.as_any().downcast_ref::<Float64Array>().unwrap();
         let xmin = unsafe { rect.get_unchecked(0) }.unwrap_or(f64::NAN);
         let ymin = unsafe { rect.get_unchecked(1) }.unwrap_or(f64::NAN);
         let xmax = unsafe { rect.get_unchecked(2) }.unwrap_or(f64::NAN);
         let ymax = unsafe { rect.get_unchecked(3) }.unwrap_or(f64::NAN);
-        Geometry::new_from_wkb(wkb)?
-            .clip_by_rect(xmin, ymin, xmax, ymax)?
-            .to_ewkb()
+        let clipped = Geometry::new_from_wkb(wkb)?.clip_by_rect(xmin, ymin, xmax, ymax)?;
+        snap_to_precision(clipped, grid_size)?.to_ewkb()
     })
 }
 
@@ -1421,11 +3085,37 @@ pub fn convex_hull(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
     })
 }
 
+/// Return the convex hull of geometries with fewer than three non-collinear points, for
+/// which it is already degenerate, and the result of `hull` otherwise.
+fn concave_hull_or_degenerate(
+    wkb: &[u8],
+    hull: impl FnOnce(&Geometry) -> GResult<Geometry>,
+) -> GResult<Vec<u8>> {
+    let geom = Geometry::new_from_wkb(wkb)?;
+    let convex_hull = geom.convex_hull()?;
+    if convex_hull.geometry_type()? != Polygon {
+        return convex_hull.to_ewkb();
+    }
+    hull(&geom)?.to_ewkb()
+}
+
 pub fn concave_hull(wkb: &BinaryChunked, params: &ConcaveHullKwargs) -> GResult<BinaryChunked> {
     wkb.try_apply_nonnull_values_generic(|wkb| {
-        Geometry::new_from_wkb(wkb)?
-            .concave_hull(params.ratio, params.allow_holes)?
-            .to_ewkb()
+        concave_hull_or_degenerate(wkb, |geom| {
+            geom.concave_hull(params.ratio, params.allow_holes)
+        })
+    })
+}
+
+pub fn concave_hull_by_length(
+    wkb: &BinaryChunked,
+    max_length: &Float64Chunked,
+    allow_holes: bool,
+) -> GResult<BinaryChunked> {
+    broadcast_try_binary_elementwise_values(wkb, max_length, |wkb, max_length| {
+        concave_hull_or_degenerate(wkb, |geom| {
+            geom.concave_hull_by_length(max_length, allow_holes)
+        })
     })
 }
 
@@ -1440,9 +3130,313 @@ pub fn delaunay_triangulation(
         .map(|res| BinaryChunked::from_slice(wkb.name().clone(), &[res]))
 }
 
-pub fn densify(wkb: &BinaryChunked, tolerance: &Float64Chunked) -> GResult<BinaryChunked> {
-    broadcast_try_binary_elementwise_values(wkb, tolerance, |wkb, tolerance| {
-        Geometry::new_from_wkb(wkb)?.densify(tolerance)?.to_ewkb()
+fn constrained_triangles(geom: &Geometry, only_edges: bool, srid: i32) -> GResult<Geometry> {
+    let mut triangles = geom.constrained_delaunay_triangulation()?;
+    if only_edges {
+        let rings = (0..triangles.get_num_geometries()?)
+            .map(|n| {
+                triangles
+                    .get_geometry_n(n)?
+                    .get_exterior_ring()?
+                    .get_coord_seq()
+            })
+            .map(|coords| Geometry::create_line_string(coords?))
+            .collect::<GResult<Vec<_>>>()?;
+        triangles = Geometry::create_multiline_string(rings)?.unary_union()?;
+    }
+    triangles.set_srid(srid);
+    Ok(triangles)
+}
+
+pub fn constrained_delaunay(
+    wkb: &BinaryChunked,
+    params: &ConstrainedDelaunayKwargs,
+) -> GResult<BinaryChunked> {
+    wkb.try_apply_nonnull_values_generic(|wkb| {
+        let geom = Geometry::new_from_wkb(wkb)?;
+        constrained_triangles(&geom, params.only_edges, geom.get_srid()?)?.to_ewkb()
+    })
+}
+
+pub fn constrained_delaunay_all(
+    wkb: &BinaryChunked,
+    params: &ConstrainedDelaunayKwargs,
+) -> GResult<BinaryChunked> {
+    let geometries = collect_geometry_vec(wkb)?;
+    let srid = match geometries.first() {
+        Some(geom) => geom.get_srid()?,
+        None => 0,
+    };
+    let collection = Geometry::create_geometry_collection(geometries)?;
+    let triangles = constrained_triangles(&collection, params.only_edges, srid)?;
+    Ok(BinaryChunked::from_slice(
+        wkb.name().clone(),
+        &[triangles.to_ewkb()?],
+    ))
+}
+
+type DensifyCoords<'a> = &'a dyn Fn(&[f64], usize) -> Vec<f64>;
+
+fn segmentized_coord_seq<G: Geom>(geom: &G, densify: DensifyCoords) -> GResult<CoordSeq> {
+    let has_z = geom.has_z()?;
+    let has_m = geom.has_m()?;
+    let dims = 2 + usize::from(has_z) + usize::from(has_m);
+    let coords = geom.get_coord_seq()?.as_buffer(Some(dims))?;
+    let coords = densify(&coords, dims);
+    CoordSeq::new_from_buffer(&coords, coords.len() / dims, has_z, has_m)
+}
+
+/// Rebuild the geometry with the coordinates of each point, line and ring passed through
+/// `densify`.
+fn segmentized<G: Geom>(geom: &G, densify: DensifyCoords) -> GResult<Geometry> {
+    let parts = |geom: &G| {
+        (0..geom.get_num_geometries()?)
+            .map(|n| segmentized(&geom.get_geometry_n(n)?, densify))
+            .collect::<GResult<Vec<_>>>()
+    };
+    let mut result = match geom.geometry_type()? {
+        _ if geom.is_empty()? => return Geom::clone(geom),
+        Point => Geometry::create_point(segmentized_coord_seq(geom, densify)?)?,
+        LineString => Geometry::create_line_string(segmentized_coord_seq(geom, densify)?)?,
+        LinearRing => Geometry::create_linear_ring(segmentized_coord_seq(geom, densify)?)?,
+        Polygon => {
+            let exterior = segmentized(&geom.get_exterior_ring()?, densify)?;
+            let interiors = (0..geom.get_num_interior_rings()?)
+                .map(|n| segmentized(&geom.get_interior_ring_n(n)?, densify))
+                .collect::<GResult<Vec<_>>>()?;
+            Geometry::create_polygon(exterior, interiors)?
+        }
+        MultiPoint => Geometry::create_multipoint(parts(geom)?)?,
+        MultiLineString => Geometry::create_multiline_string(parts(geom)?)?,
+        MultiPolygon => Geometry::create_multipolygon(parts(geom)?)?,
+        GeometryCollection => Geometry::create_geometry_collection(parts(geom)?)?,
+        t => {
+            let msg = format!("Unsupported geometry type for segmentize: {t:?}");
+            return Err(GError::GenericError(msg));
+        }
+    };
+    result.set_srid(geom.get_srid()?);
+    Ok(result)
+}
+
+/// Return the coordinates of a line or curve, with its arcs replaced by segments no longer
+/// than `max_length`.
+fn linearized_coords<G: Geom>(geom: &G, dims: usize, max_length: f64) -> GResult<Vec<f64>> {
+    match geom.geometry_type()? {
+        _ if geom.is_empty()? => Ok(vec![]),
+        CircularString => {
+            let coords = geom.get_coord_seq()?.as_buffer(Some(dims))?;
+            Ok(linearize_arcs(&coords, dims, max_length))
+        }
+        CompoundCurve => {
+            let mut coords = vec![];
+            for n in 0..geom.get_num_geometries()? {
+                let part = linearized_coords(&geom.get_geometry_n(n)?, dims, max_length)?;
+                // Each part starts where the previous one ends
+                let joint = if coords.is_empty() { 0 } else { dims };
+                coords.extend_from_slice(&part[joint.min(part.len())..]);
+            }
+            Ok(coords)
+        }
+        _ => geom.get_coord_seq()?.as_buffer(Some(dims)),
+    }
+}
+
+/// Rebuild curve geometries with linear types, with arcs replaced by segments no longer
+/// than `max_length`. Linear geometries are returned as they are.
+fn linearized<G: Geom>(geom: &G, max_length: f64) -> GResult<Geometry> {
+    let has_z = geom.has_z()?;
+    let has_m = geom.has_m()?;
+    let dims = 2 + usize::from(has_z) + usize::from(has_m);
+    let coord_seq =
+        |coords: Vec<f64>| CoordSeq::new_from_buffer(&coords, coords.len() / dims, has_z, has_m);
+    let parts = |geom: &G| {
+        (0..geom.get_num_geometries()?)
+            .map(|n| linearized(&geom.get_geometry_n(n)?, max_length))
+            .collect::<GResult<Vec<_>>>()
+    };
+    let mut result = match geom.geometry_type()? {
+        _ if geom.is_empty()? => return Geom::clone(geom),
+        CircularString | CompoundCurve => {
+            Geometry::create_line_string(coord_seq(linearized_coords(geom, dims, max_length)?)?)?
+        }
+        CurvePolygon => {
+            let exterior = linearized_coords(&geom.get_exterior_ring()?, dims, max_length)?;
+            let interiors = (0..geom.get_num_interior_rings()?)
+                .map(|n| {
+                    let ring = linearized_coords(&geom.get_interior_ring_n(n)?, dims, max_length)?;
+                    Geometry::create_linear_ring(coord_seq(ring)?)
+                })
+                .collect::<GResult<Vec<_>>>()?;
+            Geometry::create_polygon(
+                Geometry::create_linear_ring(coord_seq(exterior)?)?,
+                interiors,
+            )?
+        }
+        MultiCurve => Geometry::create_multiline_string(parts(geom)?)?,
+        MultiSurface => Geometry::create_multipolygon(parts(geom)?)?,
+        GeometryCollection => Geometry::create_geometry_collection(parts(geom)?)?,
+        _ => return Geom::clone(geom),
+    };
+    result.set_srid(geom.get_srid()?);
+    Ok(result)
+}
+
+fn validate_max_segment_length(max_length: f64) -> GResult<()> {
+    if max_length.is_nan() || max_length <= 0.0 {
+        let msg = "max_segment_length must be greater than 0";
+        return Err(GError::GenericError(msg.into()));
+    }
+    Ok(())
+}
+
+pub fn segmentize(
+    wkb: &BinaryChunked,
+    max_segment_length: &Float64Chunked,
+) -> GResult<BinaryChunked> {
+    broadcast_try_binary_elementwise_values(wkb, max_segment_length, |wkb, max_length| {
+        validate_max_segment_length(max_length)?;
+        let densify = |coords: &[f64], dims| segmentize_coords(coords, dims, max_length);
+        let geom = linearized(&Geometry::new_from_wkb(wkb)?, max_length)?;
+        segmentized(&geom, &densify)?.to_ewkb()
+    })
+}
+
+/// Return the geodesic of the ellipsoid of a geographic CRS. proj4rs does not expose the
+/// ellipsoid it resolved, so the semi-axes are read from the geocentric coordinates of the
+/// equator and of the pole, in the geocentric CRS sharing the datum of the definition.
+fn geodesic_from_srid(srid: i32) -> GResult<Geodesic> {
+    let not_geographic = || {
+        let msg = format!("densify_geodesic requires a geographic SRID, got {srid}");
+        GError::GenericError(msg)
+    };
+    let definition = u16::try_from(srid)
+        .ok()
+        .and_then(crs_definitions::from_code)
+        .ok_or_else(not_geographic)?;
+    let to_err = |e: proj4rs::errors::Error| GError::GenericError(e.to_string());
+    let proj = Proj::from_proj_string(definition.proj4).map_err(to_err)?;
+    if !proj.is_latlong() {
+        return Err(not_geographic());
+    }
+    let geocentric = definition
+        .proj4
+        .split_whitespace()
+        .map(|param| match param.starts_with("+proj=") {
+            true => "+proj=geocent",
+            false => param,
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+    let geocentric = Proj::from_proj_string(&geocentric).map_err(to_err)?;
+    let transform = |lat| proj4rs::adaptors::transform_xyz(&proj, &geocentric, 0.0, lat, 0.0);
+    let (a, _, _) = transform(0.0).map_err(to_err)?;
+    let (_, _, b) = transform(std::f64::consts::FRAC_PI_2).map_err(to_err)?;
+    Ok(Geodesic::new(a, (a - b) / a))
+}
+
+/// Insert vertices along the geodesic of each segment so that none is longer than
+/// `max_length` meters. X is the longitude and Y the latitude, in degrees.
+fn geodesic_segmentize_coords(
+    geodesic: &Geodesic,
+    coords: &[f64],
+    dimensions: usize,
+    max_length: f64,
+) -> Vec<f64> {
+    split_segments(
+        coords,
+        dimensions,
+        |start, end| {
+            let (length, azimuth, _, _): (f64, f64, f64, f64) =
+                geodesic.inverse(start[1], start[0], end[1], end[0]);
+            ((length / max_length).ceil(), (length, azimuth))
+        },
+        |start, end, &(length, azimuth), fraction, result| {
+            let (lat, lon): (f64, f64) =
+                geodesic.direct(start[1], start[0], azimuth, length * fraction);
+            result.extend([lon, lat]);
+            let ordinates = start.iter().zip(end.iter()).skip(2);
+            result.extend(ordinates.map(|(from, to)| from + (to - from) * fraction));
+        },
+    )
+}
+
+pub fn densify_geodesic(
+    wkb: &BinaryChunked,
+    max_segment_length: &Float64Chunked,
+) -> GResult<BinaryChunked> {
+    let mut geodesics = HashMap::<i32, Geodesic>::new();
+    broadcast_try_binary_elementwise_values(wkb, max_segment_length, |wkb, max_length| {
+        validate_max_segment_length(max_length)?;
+        let geom = Geometry::new_from_wkb(wkb)?;
+        let srid = geom.get_srid()?;
+        let geodesic = match geodesics.entry(srid) {
+            Entry::Occupied(e) => e.into_mut(),
+            Entry::Vacant(e) => e.insert(geodesic_from_srid(srid)?),
+        };
+        let densify =
+            |coords: &[f64], dims| geodesic_segmentize_coords(geodesic, coords, dims, max_length);
+        segmentized(&geom, &densify)?.to_ewkb()
+    })
+}
+
+fn subdivide_into(
+    geom: Geometry,
+    max_vertices: usize,
+    depth: usize,
+    pieces: &mut Vec<Geometry>,
+) -> GResult<()> {
+    if geom.is_empty()? {
+        return Ok(());
+    }
+    if geom.geometry_type()?.is_collection() {
+        for n in 0..geom.get_num_geometries()? {
+            let part = geom.get_geometry_n(n)?.clone()?;
+            subdivide_into(part, max_vertices, depth, pieces)?;
+        }
+        return Ok(());
+    }
+    let extent = geom.get_extent()?;
+    let (xmin, ymin, xmax, ymax) = (extent[0], extent[1], extent[2], extent[3]);
+    // Same recursion limit as PostGIS, for pieces that clipping cannot simplify further
+    if geom.get_num_coordinates()? <= max_vertices
+        || depth >= 50
+        || (xmax - xmin <= 0.0 && ymax - ymin <= 0.0)
+    {
+        pieces.push(geom);
+        return Ok(());
+    }
+    let halves = if xmax - xmin >= ymax - ymin {
+        let x = f64::midpoint(xmin, xmax);
+        [[xmin, ymin, x, ymax], [x, ymin, xmax, ymax]]
+    } else {
+        let y = f64::midpoint(ymin, ymax);
+        [[xmin, ymin, xmax, y], [xmin, y, xmax, ymax]]
+    };
+    for [xmin, ymin, xmax, ymax] in halves {
+        let half = geom.clip_by_rect(xmin, ymin, xmax, ymax)?;
+        subdivide_into(half, max_vertices, depth + 1, pieces)?;
+    }
+    Ok(())
+}
+
+pub fn subdivide(wkb: &BinaryChunked, max_vertices: usize) -> GResult<ListChunked> {
+    if max_vertices < 5 {
+        return Err(GError::GenericError(
+            "max_vertices must be at least 5".into(),
+        ));
+    }
+    let dt = DataType::List(Box::new(DataType::Binary));
+    try_unary_elementwise_values_with_dtype(wkb, dt, |wkb| {
+        let geom = Geometry::new_from_wkb(wkb)?;
+        let srid = geom.get_srid()?;
+        let mut pieces = vec![];
+        subdivide_into(geom, max_vertices, 0, &mut pieces)?;
+        let pieces = BinaryViewArray::try_arr_from_iter(pieces.into_iter().map(|mut piece| {
+            piece.set_srid(srid);
+            piece.to_ewkb()
+        }))?;
+        Ok(Box::new(pieces) as Box<dyn Array>)
     })
 }
 
@@ -1450,11 +3444,21 @@ pub fn envelope(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
     wkb.try_apply_nonnull_values_generic(|wkb| Geometry::new_from_wkb(wkb)?.envelope()?.to_ewkb())
 }
 
-pub fn extract_unique_points(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
-    wkb.try_apply_nonnull_values_generic(|wkb| {
-        Geometry::new_from_wkb(wkb)?
-            .extract_unique_points()?
-            .to_ewkb()
+/// A null tolerance keeps the exact coordinates, rather than giving a null result.
+pub fn extract_unique_points(
+    wkb: &BinaryChunked,
+    tolerance: &Float64Chunked,
+) -> GResult<BinaryChunked> {
+    broadcast_try_binary_elementwise(wkb, tolerance, |wkb, tolerance| {
+        let Some(wkb) = wkb else {
+            return Ok(None);
+        };
+        let mut geom = Geometry::new_from_wkb(wkb)?;
+        if let Some(tolerance) = tolerance {
+            // Round each coordinate independently, without collapsing polygons
+            geom = geom.set_precision(tolerance, geos::Precision::NoTopo)?;
+        }
+        geom.extract_unique_points()?.to_ewkb().map(Some)
     })
 }
 
@@ -1462,8 +3466,40 @@ pub fn build_area(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
     wkb.try_apply_nonnull_values_generic(|wkb| Geometry::new_from_wkb(wkb)?.build_area()?.to_ewkb())
 }
 
-pub fn make_valid(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
-    wkb.try_apply_nonnull_values_generic(|wkb| Geometry::new_from_wkb(wkb)?.make_valid()?.to_ewkb())
+type BuildAreaFull = (BinaryChunked, BinaryChunked);
+
+pub fn build_area_full(wkb: &BinaryChunked) -> GResult<BuildAreaFull> {
+    let mut areas = BinaryChunkedBuilder::new("area".into(), wkb.len());
+    let mut remainders = BinaryChunkedBuilder::new("remainder".into(), wkb.len());
+    for wkb in wkb {
+        let Some(wkb) = wkb else {
+            areas.append_null();
+            remainders.append_null();
+            continue;
+        };
+        let geom = Geometry::new_from_wkb(wkb)?;
+        let area = geom.build_area()?;
+        // Noding splits the linework at the vertices of the area, so that the edges
+        // used by its boundary are removed exactly
+        let linework = geom.node()?;
+        let mut remainder = match area.is_empty()? {
+            true => linework,
+            false => linework.difference(&area.boundary()?)?,
+        };
+        remainder.set_srid(geom.get_srid()?);
+        areas.append_value(area.to_ewkb()?);
+        remainders.append_value(remainder.to_ewkb()?);
+    }
+    Ok((areas.finish(), remainders.finish()))
+}
+
+pub fn make_valid(wkb: &BinaryChunked, params: &MakeValidKwargs) -> GResult<BinaryChunked> {
+    let make_valid_params: MakeValidParams = params.try_into()?;
+    wkb.try_apply_nonnull_values_generic(|wkb| {
+        Geometry::new_from_wkb(wkb)?
+            .make_valid_with_params(&make_valid_params)?
+            .to_ewkb()
+    })
 }
 
 pub fn normalize(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
@@ -1478,12 +3514,118 @@ pub fn node(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
     wkb.try_apply_nonnull_values_generic(|wkb| Geometry::new_from_wkb(wkb)?.node()?.to_ewkb())
 }
 
+pub fn node_prec(wkb: &BinaryChunked, grid_size: &Float64Chunked) -> GResult<BinaryChunked> {
+    broadcast_try_binary_elementwise_values(wkb, grid_size, |wkb, grid_size| {
+        Geometry::new_from_wkb(wkb)?
+            .set_precision(grid_size, geos::Precision::ValidOutput)?
+            .node()?
+            .to_ewkb()
+    })
+}
+
 pub fn point_on_surface(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
     wkb.try_apply_nonnull_values_generic(|wkb| {
         Geometry::new_from_wkb(wkb)?.point_on_surface()?.to_ewkb()
     })
 }
 
+/// Append a point on the surface of each non-empty part of a geometry to `points`,
+/// recursing into nested collections.
+fn collect_points_on_surface<G: Geom>(
+    geom: &G,
+    srid: i32,
+    points: &mut Vec<Geometry>,
+) -> GResult<()> {
+    match geom.geometry_type()? {
+        _ if geom.is_empty()? => {}
+        MultiPoint | MultiLineString | MultiCurve | MultiPolygon | MultiSurface
+        | GeometryCollection => {
+            for n in 0..geom.get_num_geometries()? {
+                collect_points_on_surface(&geom.get_geometry_n(n)?, srid, points)?;
+            }
+        }
+        _ => {
+            let mut point = geom.point_on_surface()?;
+            point.set_srid(srid);
+            points.push(point);
+        }
+    }
+    Ok(())
+}
+
+pub fn points_on_surface(wkb: &BinaryChunked) -> GResult<ListChunked> {
+    let dt = DataType::List(Box::new(DataType::Binary));
+    try_unary_elementwise_values_with_dtype(wkb, dt, |wkb| {
+        let geom = Geometry::new_from_wkb(wkb)?;
+        let mut points = vec![];
+        collect_points_on_surface(&geom, geom.get_srid()?, &mut points)?;
+        let points = BinaryViewArray::try_arr_from_iter(points.iter().map(GeometryUtils::to_ewkb))?;
+        Ok(Box::new(points) as Box<dyn Array>)
+    })
+}
+
+fn without_holes<G: Geom>(geom: &G) -> GResult<Geometry> {
+    let parts = |geom: &G| {
+        (0..geom.get_num_geometries()?)
+            .map(|n| without_holes(&geom.get_geometry_n(n)?))
+            .collect::<GResult<Vec<_>>>()
+    };
+    let mut result = match geom.geometry_type()? {
+        Polygon if !geom.is_empty()? => {
+            let shell = geom.get_exterior_ring()?.get_coord_seq()?;
+            Geometry::create_polygon(Geometry::create_linear_ring(shell)?, vec![])?
+        }
+        MultiPolygon => Geometry::create_multipolygon(parts(geom)?)?,
+        GeometryCollection => Geometry::create_geometry_collection(parts(geom)?)?,
+        _ => return Geom::clone(geom),
+    };
+    result.set_srid(geom.get_srid()?);
+    Ok(result)
+}
+
+pub fn remove_holes(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
+    wkb.try_apply_nonnull_values_generic(|wkb| {
+        without_holes(&Geometry::new_from_wkb(wkb)?)?.to_ewkb()
+    })
+}
+
+/// Return a copy of `ring` wound clockwise if `cw`, counter-clockwise otherwise.
+fn wound<G: Geom>(ring: &G, cw: bool) -> GResult<Geometry> {
+    let ring = Geom::clone(ring)?;
+    if ring.is_empty()? || ring.get_coord_seq()?.is_ccw()? != cw {
+        return Ok(ring);
+    }
+    ring.reverse()
+}
+
+fn oriented<G: Geom>(geom: &G, exterior_cw: bool) -> GResult<Geometry> {
+    let parts = |geom: &G| {
+        (0..geom.get_num_geometries()?)
+            .map(|n| oriented(&geom.get_geometry_n(n)?, exterior_cw))
+            .collect::<GResult<Vec<_>>>()
+    };
+    let mut result = match geom.geometry_type()? {
+        Polygon if !geom.is_empty()? => {
+            let exterior = wound(&geom.get_exterior_ring()?, exterior_cw)?;
+            let interiors = (0..geom.get_num_interior_rings()?)
+                .map(|n| wound(&geom.get_interior_ring_n(n)?, !exterior_cw))
+                .collect::<GResult<Vec<_>>>()?;
+            Geometry::create_polygon(exterior, interiors)?
+        }
+        MultiPolygon => Geometry::create_multipolygon(parts(geom)?)?,
+        GeometryCollection => Geometry::create_geometry_collection(parts(geom)?)?,
+        _ => return Geom::clone(geom),
+    };
+    result.set_srid(geom.get_srid()?);
+    Ok(result)
+}
+
+pub fn orient(wkb: &BinaryChunked, params: &OrientKwargs) -> GResult<BinaryChunked> {
+    wkb.try_apply_nonnull_values_generic(|wkb| {
+        oriented(&Geometry::new_from_wkb(wkb)?, params.exterior_cw)?.to_ewkb()
+    })
+}
+
 pub fn remove_repeated_points(
     wkb: &BinaryChunked,
     tolerance: &Float64Chunked,
@@ -1495,58 +3637,324 @@ pub fn remove_repeated_points(
     })
 }
 
-pub fn reverse(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
-    wkb.try_apply_nonnull_values_generic(|wkb| Geometry::new_from_wkb(wkb)?.reverse()?.to_ewkb())
+/// Reverse the linear components of a geometry, recursing into collections, and leave
+/// points and polygons unchanged.
+fn reverse_lines<G: Geom>(geom: &G) -> GResult<Geometry> {
+    match geom.geometry_type()? {
+        LineString | LinearRing | CircularString | CompoundCurve | MultiLineString | MultiCurve => {
+            geom.reverse()
+        }
+        GeometryCollection => {
+            let parts = (0..geom.get_num_geometries()?)
+                .map(|n| reverse_lines(&geom.get_geometry_n(n)?))
+                .collect::<GResult<Vec<_>>>()?;
+            let mut result = Geometry::create_geometry_collection(parts)?;
+            result.set_srid(geom.get_srid()?);
+            Ok(result)
+        }
+        _ => Geom::clone(geom),
+    }
+}
+
+pub fn reverse(wkb: &BinaryChunked, params: &ReverseKwargs) -> GResult<BinaryChunked> {
+    wkb.try_apply_nonnull_values_generic(|wkb| {
+        let geom = Geometry::new_from_wkb(wkb)?;
+        match params.lines_only {
+            true => reverse_lines(&geom)?.to_ewkb(),
+            false => geom.reverse()?.to_ewkb(),
+        }
+    })
 }
 
-pub fn simplify(wkb: &BinaryChunked, tolerance: &Float64Chunked) -> GResult<BinaryChunked> {
+/// Restore the first and last coordinates of a line or ring that the simplification
+/// moved. A ring whose start vertex was dropped is closed again on that vertex.
+fn pinned_coord_seq<A: Geom, B: Geom>(original: &A, simplified: &B) -> GResult<CoordSeq> {
+    let has_z = original.has_z()?;
+    let has_m = original.has_m()?;
+    let dims = 2 + usize::from(has_z) + usize::from(has_m);
+    let original_coords = original.get_coord_seq()?.as_buffer(Some(dims))?;
+    let first = &original_coords[..dims];
+    let last = &original_coords[original_coords.len() - dims..];
+    let mut coords = simplified.get_coord_seq()?.as_buffer(Some(dims))?;
+    if coords[..dims] != *first {
+        if first == last {
+            coords.truncate(coords.len() - dims);
+            coords.extend_from_slice(first);
+        }
+        coords.splice(0..0, first.iter().copied());
+    }
+    if coords[coords.len() - dims..] != *last {
+        coords.extend_from_slice(last);
+    }
+    CoordSeq::new_from_buffer(&coords, coords.len() / dims, has_z, has_m)
+}
+
+/// Keep the endpoints of lines and the start vertex of polygon rings in place. Parts and
+/// holes removed by the simplification can't be matched to the original ones, so the
+/// simplified geometry is returned as is when their count changed.
+fn pin_line_endpoints<A: Geom, B: Geom>(original: &A, simplified: &B) -> GResult<Geometry> {
+    if original.is_empty()?
+        || simplified.is_empty()?
+        || original.geometry_type()? != simplified.geometry_type()?
+        || original.get_num_geometries()? != simplified.get_num_geometries()?
+    {
+        return Geom::clone(simplified);
+    }
+    let parts = || {
+        (0..original.get_num_geometries()?)
+            .map(|n| {
+                let original = original.get_geometry_n(n)?;
+                let simplified = simplified.get_geometry_n(n)?;
+                pin_line_endpoints(&original, &simplified)
+            })
+            .collect::<GResult<Vec<_>>>()
+    };
+    let mut result = match original.geometry_type()? {
+        LineString => Geometry::create_line_string(pinned_coord_seq(original, simplified)?)?,
+        LinearRing => Geometry::create_linear_ring(pinned_coord_seq(original, simplified)?)?,
+        Polygon if original.get_num_interior_rings()? == simplified.get_num_interior_rings()? => {
+            let exterior = original.get_exterior_ring()?;
+            let exterior = pin_line_endpoints(&exterior, &simplified.get_exterior_ring()?)?;
+            let interiors = (0..original.get_num_interior_rings()?)
+                .map(|n| {
+                    let original = original.get_interior_ring_n(n)?;
+                    pin_line_endpoints(&original, &simplified.get_interior_ring_n(n)?)
+                })
+                .collect::<GResult<Vec<_>>>()?;
+            Geometry::create_polygon(exterior, interiors)?
+        }
+        MultiLineString => Geometry::create_multiline_string(parts()?)?,
+        MultiPolygon => Geometry::create_multipolygon(parts()?)?,
+        _ => return Geom::clone(simplified),
+    };
+    result.set_srid(original.get_srid()?);
+    Ok(result)
+}
+
+pub fn simplify(
+    wkb: &BinaryChunked,
+    tolerance: &Float64Chunked,
+    preserve_endpoints: bool,
+) -> GResult<BinaryChunked> {
     broadcast_try_binary_elementwise_values(wkb, tolerance, |wkb, tolerance| {
-        Geometry::new_from_wkb(wkb)?.simplify(tolerance)?.to_ewkb()
+        let geom = Geometry::new_from_wkb(wkb)?;
+        let simplified = geom.simplify(tolerance)?;
+        match preserve_endpoints {
+            true => pin_line_endpoints(&geom, &simplified)?.to_ewkb(),
+            false => simplified.to_ewkb(),
+        }
     })
 }
 
 pub fn topology_preserve_simplify(
     wkb: &BinaryChunked,
     tolerance: &Float64Chunked,
+    preserve_endpoints: bool,
 ) -> GResult<BinaryChunked> {
     broadcast_try_binary_elementwise_values(wkb, tolerance, |wkb, tolerance| {
-        Geometry::new_from_wkb(wkb)?
-            .topology_preserve_simplify(tolerance)?
-            .to_ewkb()
+        let geom = Geometry::new_from_wkb(wkb)?;
+        let simplified = geom.topology_preserve_simplify(tolerance)?;
+        match preserve_endpoints {
+            true => pin_line_endpoints(&geom, &simplified)?.to_ewkb(),
+            false => simplified.to_ewkb(),
+        }
+    })
+}
+
+fn polygon_rings<G: Geom>(polygon: &G, dims: usize) -> GResult<Vec<Vec<f64>>> {
+    let mut rings = vec![polygon.get_exterior_ring()?.get_coord_seq()?];
+    for n in 0..polygon.get_num_interior_rings()? {
+        rings.push(polygon.get_interior_ring_n(n)?.get_coord_seq()?);
+    }
+    rings
+        .iter()
+        .map(|ring| ring.as_buffer(Some(dims)))
+        .collect()
+}
+
+/// Simplify lines or rings which must not cross each other. Each part avoids the parts
+/// already simplified and those still to come, when their bounding boxes overlap.
+fn simplify_vw_parts(
+    parts: &[Vec<f64>],
+    dims: usize,
+    tolerance: f64,
+    min_points: usize,
+    preserve_topology: bool,
+) -> Vec<Vec<f64>> {
+    let bounds = parts
+        .iter()
+        .map(|coords| {
+            let init = [
+                f64::INFINITY,
+                f64::INFINITY,
+                f64::NEG_INFINITY,
+                f64::NEG_INFINITY,
+            ];
+            coords
+                .chunks_exact(dims)
+                .fold(init, |[xmin, ymin, xmax, ymax], c| {
+                    [
+                        xmin.min(c[0]),
+                        ymin.min(c[1]),
+                        xmax.max(c[0]),
+                        ymax.max(c[1]),
+                    ]
+                })
+        })
+        .collect::<Vec<_>>();
+    let overlap = |a: usize, b: usize| {
+        let (a, b) = (bounds[a], bounds[b]);
+        a[0] <= b[2] && b[0] <= a[2] && a[1] <= b[3] && b[1] <= a[3]
+    };
+    let mut simplified: Vec<Vec<f64>> = Vec::with_capacity(parts.len());
+    for (n, part) in parts.iter().enumerate() {
+        let obstacles: Vec<&[f64]> = (0..parts.len())
+            .filter(|&other| other != n && overlap(n, other))
+            .map(|other| simplified.get(other).unwrap_or(&parts[other]).as_slice())
+            .collect();
+        let obstacles = preserve_topology.then_some(obstacles.as_slice());
+        simplified.push(visvalingam_whyatt(
+            part, dims, tolerance, min_points, obstacles,
+        ));
+    }
+    simplified
+}
+
+fn simplify_vw_geometry<G: Geom>(
+    geom: &G,
+    tolerance: f64,
+    preserve_topology: bool,
+) -> GResult<Geometry> {
+    if geom.is_empty()? {
+        return Geom::clone(geom);
+    }
+    let has_z = geom.has_z()?;
+    let has_m = geom.has_m()?;
+    let dims = 2 + usize::from(has_z) + usize::from(has_m);
+    let seq =
+        |coords: &Vec<f64>| CoordSeq::new_from_buffer(coords, coords.len() / dims, has_z, has_m);
+    let polygon = |rings: &[Vec<f64>]| {
+        let mut rings = rings
+            .iter()
+            .map(|ring| Geometry::create_linear_ring(seq(ring)?));
+        let exterior = rings.next().expect("polygon has an exterior ring")?;
+        Geometry::create_polygon(exterior, rings.collect::<GResult<_>>()?)
+    };
+    let parts = |geom: &G| {
+        (0..geom.get_num_geometries()?)
+            .map(|n| geom.get_geometry_n(n))
+            .collect::<GResult<Vec<_>>>()
+    };
+    let mut result = match geom.geometry_type()? {
+        LineString => {
+            let coords = geom.get_coord_seq()?.as_buffer(Some(dims))?;
+            let obstacles = preserve_topology.then_some(&[][..]);
+            let coords = visvalingam_whyatt(&coords, dims, tolerance, 2, obstacles);
+            Geometry::create_line_string(seq(&coords)?)?
+        }
+        Polygon => {
+            let rings = polygon_rings(geom, dims)?;
+            polygon(&simplify_vw_parts(
+                &rings,
+                dims,
+                tolerance,
+                4,
+                preserve_topology,
+            ))?
+        }
+        // The parts of collections are simplified together so that they don't cross
+        MultiLineString => {
+            let lines = parts(geom)?
+                .iter()
+                .map(|line| line.get_coord_seq()?.as_buffer(Some(dims)))
+                .collect::<GResult<Vec<_>>>()?;
+            let lines = simplify_vw_parts(&lines, dims, tolerance, 2, preserve_topology);
+            let lines = lines
+                .iter()
+                .map(|line| Geometry::create_line_string(seq(line)?));
+            Geometry::create_multiline_string(lines.collect::<GResult<_>>()?)?
+        }
+        MultiPolygon => {
+            let polygons = parts(geom)?
+                .iter()
+                .map(|polygon| polygon_rings(polygon, dims))
+                .collect::<GResult<Vec<_>>>()?;
+            let rings =
+                simplify_vw_parts(&polygons.concat(), dims, tolerance, 4, preserve_topology);
+            let mut start = 0;
+            let polygons = polygons.iter().map(|original| {
+                start += original.len();
+                polygon(&rings[start - original.len()..start])
+            });
+            Geometry::create_multipolygon(polygons.collect::<GResult<_>>()?)?
+        }
+        GeometryCollection => {
+            let parts = parts(geom)?
+                .iter()
+                .map(|part| simplify_vw_geometry(part, tolerance, preserve_topology))
+                .collect::<GResult<_>>()?;
+            Geometry::create_geometry_collection(parts)?
+        }
+        _ => return Geom::clone(geom),
+    };
+    result.set_srid(geom.get_srid()?);
+    Ok(result)
+}
+
+pub fn simplify_vw(
+    wkb: &BinaryChunked,
+    tolerance: &Float64Chunked,
+    preserve_topology: bool,
+) -> GResult<BinaryChunked> {
+    broadcast_try_binary_elementwise_values(wkb, tolerance, |wkb, tolerance| {
+        let geom = Geometry::new_from_wkb(wkb)?;
+        simplify_vw_geometry(&geom, tolerance, preserve_topology)?.to_ewkb()
     })
 }
 
+fn geometry_force_2d(geom: &Geometry) -> GResult<Geometry> {
+    let res = if geom.is_empty()? {
+        let mut res = match geom.geometry_type()? {
+            Point => Geometry::create_empty_point(),
+            LineString | LinearRing => Geometry::create_empty_line_string(),
+            Polygon => Geometry::create_empty_polygon(),
+            MultiPoint => Geometry::create_empty_collection(MultiPoint),
+            MultiLineString => Geometry::create_empty_collection(MultiLineString),
+            MultiPolygon => Geometry::create_empty_collection(MultiPolygon),
+            GeometryCollection => Geometry::create_empty_collection(GeometryCollection),
+            CircularString => Geometry::create_empty_circular_string(),
+            CompoundCurve => Geometry::create_empty_compound_curve(),
+            CurvePolygon => Geometry::create_empty_curve_polygon(),
+            MultiCurve => Geometry::create_empty_collection(MultiCurve),
+            MultiSurface => Geometry::create_empty_collection(MultiSurface),
+        }?;
+        res.set_srid(geom.get_srid()?);
+        res
+    } else {
+        geom.transform_xyz(|x, y, _z| Ok((x, y, f64::NAN)))?
+    };
+    Ok(res)
+}
+
 pub fn force_2d(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
     wkb.try_apply_nonnull_values_generic(|wkb| {
-        let geom = Geometry::new_from_wkb(wkb)?;
-        if geom.is_empty()? {
-            let mut res = match geom.geometry_type()? {
-                Point => Geometry::create_empty_point(),
-                LineString | LinearRing => Geometry::create_empty_line_string(),
-                Polygon => Geometry::create_empty_polygon(),
-                MultiPoint => Geometry::create_empty_collection(MultiPoint),
-                MultiLineString => Geometry::create_empty_collection(MultiLineString),
-                MultiPolygon => Geometry::create_empty_collection(MultiPolygon),
-                GeometryCollection => Geometry::create_empty_collection(GeometryCollection),
-                CircularString => Geometry::create_empty_circular_string(),
-                CompoundCurve => Geometry::create_empty_compound_curve(),
-                CurvePolygon => Geometry::create_empty_curve_polygon(),
-                MultiCurve => Geometry::create_empty_collection(MultiCurve),
-                MultiSurface => Geometry::create_empty_collection(MultiSurface),
-            }?;
-            res.set_srid(geom.get_srid()?);
-            res
-        } else {
-            geom.transform_xyz(|x, y, _z| Ok((x, y, f64::NAN)))?
-        }
-        .to_ewkb()
+        geometry_force_2d(&Geometry::new_from_wkb(wkb)?)?.to_ewkb()
     })
 }
 
-pub fn force_3d(wkb: &BinaryChunked, z: &Float64Chunked) -> GResult<BinaryChunked> {
+pub fn force_3d(
+    wkb: &BinaryChunked,
+    z: &Float64Chunked,
+    params: &Force3DKwargs,
+) -> GResult<BinaryChunked> {
     broadcast_try_binary_elementwise_values(wkb, z, |wkb, new_z| {
-        Geometry::new_from_wkb(wkb)?
-            .transform_xyz(|x, y, z| Ok((x, y, if z.is_nan() { new_z } else { z })))?
+        let geom = Geometry::new_from_wkb(wkb)?;
+        let has_z = geom.has_z()?;
+        if geom.is_empty()? || has_z && !params.fill_nan {
+            return geom.to_ewkb();
+        }
+        // 2D geometries get `new_z` everywhere, 3D ones only where their Z is NaN
+        geom.transform_xyz(|x, y, z| Ok((x, y, if has_z && !z.is_nan() { z } else { new_z })))?
             .to_ewkb()
     })
 }
@@ -1559,6 +3967,29 @@ pub fn minimum_rotated_rectangle(wkb: &BinaryChunked) -> GResult<BinaryChunked>
     })
 }
 
+fn with_empty_line<F>(wkb: &BinaryChunked, func: F) -> GResult<BinaryChunked>
+where
+    F: Fn(&Geometry) -> GResult<Geometry>,
+{
+    wkb.try_apply_nonnull_values_generic(|wkb| {
+        let geom = Geometry::new_from_wkb(wkb)?;
+        let mut line = match geom.is_empty()? {
+            true => Geometry::create_empty_line_string()?,
+            false => func(&geom)?,
+        };
+        line.set_srid(geom.get_srid()?);
+        line.to_ewkb()
+    })
+}
+
+pub fn minimum_width(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
+    with_empty_line(wkb, Geom::minimum_width)
+}
+
+pub fn minimum_clearance_line(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
+    with_empty_line(wkb, Geom::minimum_clearance_line)
+}
+
 pub fn translate(wkb: &BinaryChunked, factors: &ArrayChunked) -> GResult<BinaryChunked> {
     broadcast_try_binary_elementwise_values(wkb, factors, |wkb, factors| {
         let geom = Geometry::new_from_wkb(wkb)?;
@@ -1589,6 +4020,21 @@ pub fn rotate_around_centroid(
     })
 }
 
+/// Return the coordinates of a transform origin point, with a Z of 0 for 2D points.
+fn origin_coordinates(origin: &[u8]) -> GResult<(f64, f64, f64)> {
+    let origin = Geometry::new_from_wkb(origin)?;
+    if origin.geometry_type()? != Point || origin.is_empty()? {
+        return Err(GError::GenericError(
+            "Origin must be a non-empty point".into(),
+        ));
+    }
+    let z = match origin.has_z()? {
+        true => origin.get_z()?,
+        false => 0.0,
+    };
+    Ok((origin.get_x()?, origin.get_y()?, z))
+}
+
 pub fn rotate_around_center(wkb: &BinaryChunked, angle: &Float64Chunked) -> GResult<BinaryChunked> {
     broadcast_try_binary_elementwise_values(wkb, angle, |wkb, angle| {
         let geom = Geometry::new_from_wkb(wkb)?;
@@ -1615,6 +4061,21 @@ pub fn rotate_around_point(
     })
 }
 
+pub fn rotate_around_geom(
+    wkb: &BinaryChunked,
+    angle: &Float64Chunked,
+    origin: &BinaryChunked,
+) -> GResult<BinaryChunked> {
+    broadcast_try_ternary_elementwise_values(wkb, angle, origin, |wkb, angle, origin| {
+        let geom = Geometry::new_from_wkb(wkb)?;
+        if geom.is_empty()? {
+            return geom.to_ewkb();
+        }
+        let (x0, y0, _) = origin_coordinates(origin)?;
+        geom.rotate(angle, x0, y0)?.to_ewkb()
+    })
+}
+
 pub fn scale_from_centroid(wkb: &BinaryChunked, factors: &ArrayChunked) -> GResult<BinaryChunked> {
     broadcast_try_binary_elementwise_values(wkb, factors, |wkb, factors| {
         let geom = Geometry::new_from_wkb(wkb)?;
@@ -1668,6 +4129,25 @@ pub fn scale_from_point(
     })
 }
 
+pub fn scale_from_geom(
+    wkb: &BinaryChunked,
+    factors: &ArrayChunked,
+    origin: &BinaryChunked,
+) -> GResult<BinaryChunked> {
+    broadcast_try_ternary_elementwise_values(wkb, factors, origin, |wkb, factors, origin| {
+        let geom = Geometry::new_from_wkb(wkb)?;
+        if geom.is_empty()? {
+            return geom.to_ewkb();
+        }
+        let factors = factors.as_any().downcast_ref::<Float64Array>().unwrap();
+        let x = unsafe { factors.get_unchecked(0) }.unwrap_or(f64::NAN);
+        let y = unsafe { factors.get_unchecked(1) }.unwrap_or(f64::NAN);
+        let z = unsafe { factors.get_unchecked(2) }.unwrap_or(f64::NAN);
+        let (x0, y0, z0) = origin_coordinates(origin)?;
+        geom.scale(x, y, z, x0, y0, z0)?.to_ewkb()
+    })
+}
+
 pub fn skew_from_centroid(wkb: &BinaryChunked, factors: &ArrayChunked) -> GResult<BinaryChunked> {
     broadcast_try_binary_elementwise_values(wkb, factors, |wkb, factors| {
         let geom = Geometry::new_from_wkb(wkb)?;
@@ -1721,50 +4201,139 @@ pub fn skew_from_point(
     })
 }
 
-pub fn affine_transform_2d(wkb: &BinaryChunked, matrix: &ArrayChunked) -> GResult<BinaryChunked> {
-    broadcast_try_binary_elementwise_values(wkb, matrix, |wkb, matrix| {
-        let matrix = matrix.as_any().downcast_ref::<Float64Array>().unwrap();
-        Geometry::new_from_wkb(wkb)?
-            .apply_affine_transform(
-                unsafe { matrix.get_unchecked(0) }.unwrap_or(f64::NAN),
-                unsafe { matrix.get_unchecked(1) }.unwrap_or(f64::NAN),
-                0.0,
-                unsafe { matrix.get_unchecked(2) }.unwrap_or(f64::NAN),
-                unsafe { matrix.get_unchecked(3) }.unwrap_or(f64::NAN),
-                0.0,
-                0.0,
-                0.0,
-                1.0,
-                unsafe { matrix.get_unchecked(4) }.unwrap_or(f64::NAN),
-                unsafe { matrix.get_unchecked(5) }.unwrap_or(f64::NAN),
-                0.0,
-            )?
-            .to_ewkb()
+pub fn skew_from_geom(
+    wkb: &BinaryChunked,
+    factors: &ArrayChunked,
+    origin: &BinaryChunked,
+) -> GResult<BinaryChunked> {
+    broadcast_try_ternary_elementwise_values(wkb, factors, origin, |wkb, factors, origin| {
+        let geom = Geometry::new_from_wkb(wkb)?;
+        if geom.is_empty()? {
+            return geom.to_ewkb();
+        }
+        let factors = factors.as_any().downcast_ref::<Float64Array>().unwrap();
+        let x = unsafe { factors.get_unchecked(0) }.unwrap_or(f64::NAN);
+        let y = unsafe { factors.get_unchecked(1) }.unwrap_or(f64::NAN);
+        let z = unsafe { factors.get_unchecked(2) }.unwrap_or(f64::NAN);
+        let (x0, y0, z0) = origin_coordinates(origin)?;
+        geom.skew(x, y, z, x0, y0, z0)?.to_ewkb()
     })
 }
 
-pub fn affine_transform_3d(wkb: &BinaryChunked, matrix: &ArrayChunked) -> GResult<BinaryChunked> {
-    broadcast_try_binary_elementwise_values(wkb, matrix, |wkb, matrix| {
-        let matrix = matrix.as_any().downcast_ref::<Float64Array>().unwrap();
-        Geometry::new_from_wkb(wkb)?
-            .apply_affine_transform(
-                unsafe { matrix.get_unchecked(0) }.unwrap_or(f64::NAN),
-                unsafe { matrix.get_unchecked(1) }.unwrap_or(f64::NAN),
-                unsafe { matrix.get_unchecked(2) }.unwrap_or(f64::NAN),
-                unsafe { matrix.get_unchecked(3) }.unwrap_or(f64::NAN),
-                unsafe { matrix.get_unchecked(4) }.unwrap_or(f64::NAN),
-                unsafe { matrix.get_unchecked(5) }.unwrap_or(f64::NAN),
-                unsafe { matrix.get_unchecked(6) }.unwrap_or(f64::NAN),
-                unsafe { matrix.get_unchecked(7) }.unwrap_or(f64::NAN),
-                unsafe { matrix.get_unchecked(8) }.unwrap_or(f64::NAN),
-                unsafe { matrix.get_unchecked(9) }.unwrap_or(f64::NAN),
-                unsafe { matrix.get_unchecked(10) }.unwrap_or(f64::NAN),
-                unsafe { matrix.get_unchecked(11) }.unwrap_or(f64::NAN),
-            )?
-            .to_ewkb()
+pub fn snap_to_grid(wkb: &BinaryChunked, grid: &ArrayChunked) -> GResult<BinaryChunked> {
+    broadcast_try_binary_elementwise_values(wkb, grid, |wkb, grid| {
+        let geom = Geometry::new_from_wkb(wkb)?;
+        if geom.is_empty()? {
+            return geom.to_ewkb();
+        }
+        let grid = grid.as_any().downcast_ref::<Float64Array>().unwrap();
+        let get = |i| unsafe { grid.get_unchecked(i) }.unwrap_or(0.0);
+        let (size_x, size_y, size_z) = (get(0), get(1), get(2));
+        let (origin_x, origin_y, origin_z) = (get(3), get(4), get(5));
+        let snap = |value: f64, size: f64, origin: f64| {
+            if size > 0.0 {
+                ((value - origin) / size).round() * size + origin
+            } else {
+                value
+            }
+        };
+        // M values are left untouched
+        let has_z = geom.has_z()?;
+        let snap_coords = |coords: &[f64], dims: usize| {
+            let mut coords = coords.to_vec();
+            for coord in coords.chunks_exact_mut(dims) {
+                coord[0] = snap(coord[0], size_x, origin_x);
+                coord[1] = snap(coord[1], size_y, origin_y);
+                if has_z {
+                    coord[2] = snap(coord[2], size_z, origin_z);
+                }
+            }
+            coords
+        };
+        segmentized(&geom, &snap_coords)?.to_ewkb()
     })
 }
 
+/// Return the elements of the affine matrix stored at `row` of `matrix` as a 3D matrix,
+/// read straight from the list values. The row must hold 6 non-null values for a 2D
+/// transform or 12 for a 3D transform; `index` is the geometry row used in errors.
+fn affine_matrix(matrix: &LargeListArray, row: usize, index: usize) -> GResult<Option<[f64; 12]>> {
+    let invalid = |reason: String| {
+        GError::GenericError(format!("Invalid affine matrix at row {index}: {reason}"))
+    };
+    if !matrix.is_valid(row) {
+        return Ok(None);
+    }
+    let (start, end) = matrix.offsets().start_end(row);
+    let values = matrix
+        .values()
+        .as_any()
+        .downcast_ref::<Float64Array>()
+        .unwrap();
+    if values.null_count() > 0 && (start..end).any(|i| !values.is_valid(i)) {
+        return Err(invalid("elements must not be null".into()));
+    }
+    match values.values()[start..end] {
+        [m11, m12, m21, m22, tx, ty] => Ok(Some([
+            m11, m12, 0.0, m21, m22, 0.0, 0.0, 0.0, 1.0, tx, ty, 0.0,
+        ])),
+        [m11, m12, m13, m21, m22, m23, m31, m32, m33, tx, ty, tz] => Ok(Some([
+            m11, m12, m13, m21, m22, m23, m31, m32, m33, tx, ty, tz,
+        ])),
+        ref elements => {
+            let reason = format!("expected 6 or 12 elements, got {}", elements.len());
+            Err(invalid(reason))
+        }
+    }
+}
+
+pub fn affine_transform(
+    wkb: &BinaryChunked,
+    matrix: &ListChunked,
+    params: &AffineTransformKwargs,
+) -> GResult<BinaryChunked> {
+    let len = match (wkb.len(), matrix.len()) {
+        (1, len) | (len, 1) => len,
+        (a, b) if a == b => a,
+        _ => {
+            return Err(GError::GenericError(
+                "Matrices must have the same length as the geometries".into(),
+            ))
+        }
+    };
+    let matrix = matrix.rechunk();
+    let Some(matrix) = matrix.downcast_iter().next() else {
+        return Ok(BinaryChunked::full_null(wkb.name().clone(), len));
+    };
+    (0..len)
+        .map(|index| {
+            let Some(wkb) = wkb.get(if wkb.len() == 1 { 0 } else { index }) else {
+                return Ok(None);
+            };
+            let row = if matrix.len() == 1 { 0 } else { index };
+            let Some([m11, m12, m13, m21, m22, m23, m31, m32, m33, tx, ty, tz]) =
+                affine_matrix(matrix, row, index)?
+            else {
+                return Ok(None);
+            };
+            let geom = Geometry::new_from_wkb(wkb)?;
+            #[rustfmt::skip]
+            let mut result = geom.apply_affine_transform(
+                m11, m12, m13,
+                m21, m22, m23,
+                m31, m32, m33,
+                tx,  ty,  tz,
+            )?;
+            result.set_srid(match params.keep_srid {
+                true => geom.get_srid()?,
+                false => 0,
+            });
+            result.to_ewkb().map(Some)
+        })
+        .collect::<GResult<BinaryChunked>>()
+        .map(|result| result.with_name(wkb.name().clone()))
+}
+
 pub fn interpolate(wkb: &BinaryChunked, distance: &Float64Chunked) -> GResult<BinaryChunked> {
     broadcast_try_binary_elementwise_values(wkb, distance, |wkb, distance| {
         Geometry::new_from_wkb(wkb)?
@@ -1784,6 +4353,49 @@ pub fn interpolate_normalized(
     })
 }
 
+pub fn interpolate_many(
+    wkb: &BinaryChunked,
+    distances: &ListChunked,
+    normalized: bool,
+) -> GResult<ListChunked> {
+    if distances.len() != 1 && distances.len() != wkb.len() {
+        return Err(GError::GenericError(
+            "Distances must have the same length as the geometries".into(),
+        ));
+    }
+    wkb.iter()
+        .enumerate()
+        .map(|(index, wkb)| {
+            let distances = match distances.len() {
+                1 => distances.get_as_series(0),
+                _ => distances.get_as_series(index),
+            };
+            let (Some(wkb), Some(distances)) = (wkb, distances) else {
+                return Ok(None);
+            };
+            let distances = distances
+                .f64()
+                .map_err(|e| GError::GenericError(e.to_string()))?;
+            let geom = Geometry::new_from_wkb(wkb)?;
+            // GEOS clamps distances beyond the length of the line to its endpoint
+            let points = distances
+                .iter()
+                .map(|distance| {
+                    let Some(distance) = distance else {
+                        return Ok(None);
+                    };
+                    let point = match normalized {
+                        true => geom.interpolate_normalized(distance)?,
+                        false => geom.interpolate(distance)?,
+                    };
+                    point.to_ewkb().map(Some)
+                })
+                .collect::<GResult<BinaryChunked>>()?;
+            Ok(Some(points.into_series()))
+        })
+        .collect()
+}
+
 pub fn project(a: &BinaryChunked, b: &BinaryChunked) -> GResult<Float64Chunked> {
     broadcast_try_binary_elementwise_values(a, b, |a, b| {
         let a = Geometry::new_from_wkb(a)?;
@@ -1810,39 +4422,222 @@ pub fn project_normalized(a: &BinaryChunked, b: &BinaryChunked) -> GResult<Float
     })
 }
 
-pub fn substring(
+pub fn line_substring(
     wkb: &BinaryChunked,
     start: &Float64Chunked,
     end: &Float64Chunked,
+    normalized: bool,
 ) -> GResult<BinaryChunked> {
     broadcast_try_ternary_elementwise_values(wkb, start, end, |wkb, start, end| {
-        Geometry::new_from_wkb(wkb)?
-            .line_substring(start, end)?
-            .to_ewkb()
+        let geom = Geometry::new_from_wkb(wkb)?;
+        let (start, end) = match normalized {
+            true => (start, end),
+            false => match geom.length()? {
+                length if length > 0.0 => (start / length, end / length),
+                _ => (0.0, 0.0),
+            },
+        };
+        let start = start.clamp(0.0, 1.0);
+        let end = end.clamp(0.0, 1.0);
+        if start > end {
+            geom.line_substring(end, start)?.reverse()?.to_ewkb()
+        } else {
+            geom.line_substring(start, end)?.to_ewkb()
+        }
     })
 }
 
-pub fn line_merge(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
-    wkb.try_apply_nonnull_values_generic(|wkb| Geometry::new_from_wkb(wkb)?.line_merge()?.to_ewkb())
+/// Move the endpoints of the lines of a geometry onto an endpoint of a previous line
+/// within `tolerance`, so that nearly touching lines can be merged. Other geometries are
+/// returned unchanged.
+fn sew_line_endpoints(geom: &Geometry, tolerance: f64) -> GResult<Geometry> {
+    if !matches!(geom.geometry_type()?, LineString | MultiLineString) {
+        return Geom::clone(geom);
+    }
+    let has_z = geom.has_z()?;
+    let has_m = geom.has_m()?;
+    let dimensions = 2 + usize::from(has_z) + usize::from(has_m);
+    let mut anchors: Vec<(f64, f64)> = vec![];
+    let mut lines = vec![];
+    for n in 0..geom.get_num_geometries()? {
+        let line = geom.get_geometry_n(n)?;
+        if line.is_empty()? {
+            continue;
+        }
+        let mut coords = line.get_coord_seq()?.as_buffer(Some(dimensions))?;
+        for offset in [0, coords.len() - dimensions] {
+            let (x, y) = (coords[offset], coords[offset + 1]);
+            match anchors
+                .iter()
+                .find(|(ax, ay)| (ax - x).hypot(ay - y) <= tolerance)
+            {
+                Some(&(ax, ay)) => (coords[offset], coords[offset + 1]) = (ax, ay),
+                None => anchors.push((x, y)),
+            }
+        }
+        let size = coords.len() / dimensions;
+        let coords = CoordSeq::new_from_buffer(&coords, size, has_z, has_m)?;
+        lines.push(Geometry::create_line_string(coords)?);
+    }
+    let mut result = Geometry::create_multiline_string(lines)?;
+    result.set_srid(geom.get_srid()?);
+    Ok(result)
 }
 
-pub fn line_merge_directed(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
-    wkb.try_apply_nonnull_values_generic(|wkb| {
-        Geometry::new_from_wkb(wkb)?
-            .line_merge_directed()?
-            .to_ewkb()
+/// A null tolerance merges lines whose endpoints are exactly equal, rather than giving a
+/// null result.
+pub fn line_merge_snap(
+    wkb: &BinaryChunked,
+    tolerance: &Float64Chunked,
+    directed: bool,
+) -> GResult<BinaryChunked> {
+    broadcast_try_binary_elementwise(wkb, tolerance, |wkb, tolerance| {
+        let Some(wkb) = wkb else {
+            return Ok(None);
+        };
+        let mut geom = Geometry::new_from_wkb(wkb)?;
+        if let Some(tolerance) = tolerance {
+            geom = sew_line_endpoints(&geom, tolerance)?;
+        }
+        let merged = match directed {
+            true => geom.line_merge_directed()?,
+            false => geom.line_merge()?,
+        };
+        merged.to_ewkb().map(Some)
     })
 }
 
-pub fn shared_paths(a: &BinaryChunked, b: &BinaryChunked) -> GResult<BinaryChunked> {
+pub fn shared_paths(
+    a: &BinaryChunked,
+    b: &BinaryChunked,
+    direction: SharedPathsDirection,
+) -> GResult<BinaryChunked> {
     broadcast_try_binary_elementwise_values(a, b, |a, b| {
         let a = Geometry::new_from_wkb(a)?;
         let b = Geometry::new_from_wkb(b)?;
-        a.shared_paths(&b)?.to_ewkb()
+        // GEOS returns a collection of the forward and backward paths, in that order
+        let paths = a.shared_paths(&b)?;
+        let mut paths = match direction {
+            SharedPathsDirection::Both => paths,
+            SharedPathsDirection::Forward => Geom::clone(&paths.get_geometry_n(0)?)?,
+            SharedPathsDirection::Backward => Geom::clone(&paths.get_geometry_n(1)?)?,
+        };
+        paths.set_srid(a.get_srid()?);
+        paths.to_ewkb()
+    })
+}
+
+fn split_into(
+    geom: &Geometry,
+    blade_points: &[Geometry],
+    blade_lines: Option<&Geometry>,
+    pieces: &mut Vec<Geometry>,
+) -> GResult<()> {
+    if geom.is_empty()? {
+        return Ok(());
+    }
+    if geom.geometry_type()?.is_collection() {
+        for n in 0..geom.get_num_geometries()? {
+            let part = geom.get_geometry_n(n)?.clone()?;
+            split_into(&part, blade_points, blade_lines, pieces)?;
+        }
+        return Ok(());
+    }
+    match (geom.get_num_dimensions()?, blade_lines) {
+        (1, None) => {
+            // Cut the line at the linear positions of the blade points lying on it
+            let length = geom.length()?;
+            let mut fractions = vec![0.0, 1.0];
+            for point in blade_points {
+                if length > 0.0 && geom.intersects(point)? {
+                    fractions.push(geom.project(point)? / length);
+                }
+            }
+            fractions.sort_by(f64::total_cmp);
+            fractions.dedup_by(|a, b| a <= b);
+            for range in fractions.windows(2) {
+                pieces.push(geom.line_substring(range[0], range[1])?);
+            }
+        }
+        (1, Some(blade)) => {
+            // The overlay nodes the line at every crossing with the blade
+            collect_parts_with_dimension(&geom.difference(blade)?, 1, pieces)?;
+        }
+        (2, Some(blade)) => {
+            // Polygonize the boundary noded with the blade, and only keep the faces
+            // inside the original polygon, leaving holes out
+            let noded = geom.boundary()?.union(blade)?;
+            let mut faces = vec![];
+            collect_parts_with_dimension(&Geometry::polygonize(&[noded])?, 2, &mut faces)?;
+            let prepared = geom.to_prepared_geom()?;
+            for face in faces {
+                if prepared.contains(&face.point_on_surface()?)? {
+                    pieces.push(face);
+                }
+            }
+        }
+        _ => pieces.push(Geom::clone(geom)?),
+    }
+    Ok(())
+}
+
+pub fn split(wkb: &BinaryChunked, blade: &BinaryChunked) -> GResult<BinaryChunked> {
+    broadcast_try_binary_elementwise_values(wkb, blade, |wkb, blade| {
+        let geom = Geometry::new_from_wkb(wkb)?;
+        let blade = Geometry::new_from_wkb(blade)?;
+        let mut blade_points = vec![];
+        let blade_lines = match blade.get_num_dimensions()? {
+            _ if blade.is_empty()? => None,
+            0 => {
+                collect_parts_with_dimension(&blade, 0, &mut blade_points)?;
+                None
+            }
+            1 => Some(Geom::clone(&blade)?),
+            _ => Some(blade.boundary()?),
+        };
+        let mut pieces = vec![];
+        split_into(&geom, &blade_points, blade_lines.as_ref(), &mut pieces)?;
+        let mut res = Geometry::create_geometry_collection(pieces)?;
+        res.set_srid(geom.get_srid()?);
+        res.to_ewkb()
+    })
+}
+
+/// Return the shortest line from each geometry to a single geometry `other`, which is
+/// only parsed and prepared once. `reverse` makes lines go from `other` to each geometry.
+fn shortest_line_to_scalar(
+    wkb: &BinaryChunked,
+    other: Option<&[u8]>,
+    reverse: bool,
+) -> GResult<BinaryChunked> {
+    let Some(other) = other else {
+        return Ok(BinaryChunked::full_null(wkb.name().clone(), wkb.len()));
+    };
+    let other = Geometry::new_from_wkb(other)?;
+    let other_empty = other.is_empty()?;
+    let prepared = other.to_prepared_geom()?;
+    wkb.try_apply_nonnull_values_generic(|wkb| {
+        let geom = Geometry::new_from_wkb(wkb)?;
+        if other_empty || geom.is_empty()? {
+            return Geometry::create_empty_line_string()?.to_ewkb();
+        }
+        // Prepared nearest points start on the prepared geometry
+        let line = Geometry::create_line_string(prepared.nearest_points(&geom)?)?;
+        match reverse {
+            true => line.to_ewkb(),
+            false => line.reverse()?.to_ewkb(),
+        }
     })
 }
 
 pub fn shortest_line(a: &BinaryChunked, b: &BinaryChunked) -> GResult<BinaryChunked> {
+    if a.len() != 1 && b.len() == 1 {
+        return shortest_line_to_scalar(a, b.get(0), false);
+    }
+    if a.len() == 1 && b.len() != 1 {
+        let lines = shortest_line_to_scalar(b, a.get(0), true)?;
+        return Ok(lines.with_name(a.name().clone()));
+    }
     broadcast_try_binary_elementwise_values(a, b, |a, b| {
         let a = Geometry::new_from_wkb(a)?;
         let b = Geometry::new_from_wkb(b)?;
@@ -1856,15 +4651,31 @@ pub fn shortest_line(a: &BinaryChunked, b: &BinaryChunked) -> GResult<BinaryChun
     })
 }
 
+pub fn nearest_points(
+    a: &BinaryChunked,
+    b: &BinaryChunked,
+) -> GResult<(BinaryChunked, Float64Chunked)> {
+    let lines = shortest_line(a, b)?;
+    let distances = lines.try_apply_nonnull_values_generic(|line| {
+        let line = Geometry::new_from_wkb(line)?;
+        match line.is_empty()? {
+            true => Ok(f64::NAN), // Match `distance` behavior
+            false => line.length(),
+        }
+    })?;
+    Ok((lines, distances.with_name("distance".into())))
+}
+
 pub fn snap(
     a: &BinaryChunked,
     b: &BinaryChunked,
     tolerance: &Float64Chunked,
+    grid_size: Option<f64>,
 ) -> GResult<BinaryChunked> {
     broadcast_try_ternary_elementwise_values(a, b, tolerance, |a, b, tolerance| {
         let a = Geometry::new_from_wkb(a)?;
         let b = Geometry::new_from_wkb(b)?;
-        Geometry::snap(&a, &b, tolerance)?.to_ewkb()
+        snap_to_precision(Geometry::snap(&a, &b, tolerance)?, grid_size)?.to_ewkb()
     })
 }
 
@@ -1874,6 +4685,22 @@ pub fn voronoi_polygons(wkb: &BinaryChunked, params: &VoronoiKwargs) -> GResult<
         .as_ref()
         .map(|wkb| Geometry::new_from_wkb(wkb))
         .transpose()?;
+    if params.ordered {
+        if params.only_edges {
+            return Err(GError::GenericError(
+                "Ordered Voronoi cells cannot be returned as edges".into(),
+            ));
+        }
+        let cells = indexed_voronoi_cells(wkb, extend_to.as_ref(), params.tolerance)?
+            .into_iter()
+            .map(|(_, cell)| cell)
+            .collect();
+        let cells = Geometry::create_geometry_collection(cells)?;
+        return Ok(BinaryChunked::from_slice(
+            wkb.name().clone(),
+            &[cells.to_ewkb()?],
+        ));
+    }
     collect_geometry_vec(wkb)
         .and_then(Geometry::create_geometry_collection)
         .and_then(|geom| geom.voronoi(extend_to.as_ref(), params.tolerance, params.only_edges))
@@ -1888,7 +4715,39 @@ struct SIndex {
 
 type SindexQueryResult = GResult<(Vec<u32>, Vec<u32>)>;
 
+/// Index built by the last spatial join, with the geometries it was built from. Cloning
+/// the column only shares its buffers, and [`clear_sjoin_cache`] frees both.
+static SJOIN_INDEX_CACHE: Mutex<Option<(BinaryChunked, Arc<SIndex>)>> = Mutex::new(None);
+
+/// A poisoned lock only means that another join panicked, the cache is still valid.
+fn sjoin_index_cache() -> MutexGuard<'static, Option<(BinaryChunked, Arc<SIndex>)>> {
+    SJOIN_INDEX_CACHE
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+}
+
+pub fn clear_sjoin_cache() {
+    sjoin_index_cache().take();
+}
+
 impl SIndex {
+    /// Reuse the index of the previous spatial join when its left geometries are the
+    /// same, as when joining one frame against several others. Comparing the WKB is much
+    /// cheaper than parsing it and building the tree again.
+    fn cached(geom: &BinaryChunked) -> GResult<Arc<Self>> {
+        // Trailing nulls come from padding the shorter frame, they are not indexed anyway
+        let geom = geom.slice(0, geom.last_non_null().map_or(0, |i| i + 1));
+        if let Some((cached, index)) = sjoin_index_cache().as_ref() {
+            if cached.len() == geom.len() && cached.iter().eq(geom.iter()) {
+                return Ok(index.clone());
+            }
+        }
+        // The tree is built without holding the lock, so concurrent joins don't wait
+        let index = Arc::new(Self::try_new(&geom)?);
+        *sjoin_index_cache() = Some((geom, index.clone()));
+        Ok(index)
+    }
+
     fn try_new(geom: &BinaryChunked) -> GResult<Self> {
         let data = geom
             .iter()
@@ -1904,6 +4763,10 @@ impl SIndex {
         Ok(Self { data, tree })
     }
 
+    /// Call `cb` on each non-empty geometry of `other` in parallel, then merge the
+    /// resulting pairs. Geometries are parsed again by each task since they can't be sent
+    /// across threads. The pairs are ordered by `other` index, then by index in the tree,
+    /// so the output doesn't depend on the number of threads.
     fn query<F>(other: &BinaryChunked, cb: F) -> SindexQueryResult
     where
         F: Fn(usize, Geometry) -> SindexQueryResult + Sync,
@@ -1918,7 +4781,10 @@ impl SIndex {
                 if geom.is_empty()? {
                     return Ok((vec![], vec![]));
                 }
-                cb(index, geom)
+                // All pairs share the same `other` index, only the tree indices need sorting
+                let (mut indices, other_indices) = cb(index, geom)?;
+                indices.sort_unstable();
+                Ok((indices, other_indices))
             })
             .try_reduce(
                 || (vec![], vec![]),
@@ -1930,6 +4796,34 @@ impl SIndex {
             )
     }
 
+    fn nearest(&self, geom: &Geometry) -> GResult<Option<(usize, &Geometry)>> {
+        let extent = geom.get_extent()?;
+        let x = (extent[0] + extent[2]) / 2.0;
+        let y = (extent[1] + extent[3]) / 2.0;
+        let Some(&first) = self.tree.neighbors(x, y, Some(1), None).first() else {
+            return Ok(None);
+        };
+        // No candidate can be closer than the exact distance to the nearest bounding box,
+        // so only the candidates intersecting the expanded extent need to be checked.
+        let bound = geom.distance(&self.data[first as usize].1)?;
+        let xmin = extent[0] - bound;
+        let ymin = extent[1] - bound;
+        let xmax = extent[2] + bound;
+        let ymax = extent[3] + bound;
+        let mut nearest: Option<(usize, &Geometry, f64)> = None;
+        for hit in self.tree.search(xmin, ymin, xmax, ymax) {
+            let (index, candidate) = &self.data[hit as usize];
+            let distance = geom.distance(candidate)?;
+            let is_nearer = nearest.is_none_or(|(nearest_index, _, nearest_distance)| {
+                (distance, *index) < (nearest_distance, nearest_index)
+            });
+            if is_nearer {
+                nearest = Some((*index, candidate, distance));
+            }
+        }
+        Ok(nearest.map(|(index, candidate, _)| (index, candidate)))
+    }
+
     fn sjoin(&self, other: &BinaryChunked, predicate: SjoinPredicate) -> SindexQueryResult {
         use SjoinPredicate::*;
         let predicate: fn(&PreparedGeometry<'_>, &Geometry) -> GResult<bool> = match predicate {
@@ -1970,9 +4864,15 @@ impl SIndex {
                 let coords = right_geom.get_coord_seq()?.as_buffer(None)?;
                 let (x, y) = (coords[0], coords[1]);
                 for hit in self.tree.neighbors(x, y, None, Some(distance)) {
-                    let (left_index, _) = &self.data[hit as usize];
-                    left_indicies.push(*left_index as _);
-                    right_indicies.push(right_index as _);
+                    let (left_index, left_geom) = &self.data[hit as usize];
+                    // The tree distance is measured to bounding boxes, which is only exact
+                    // for points
+                    if left_geom.geometry_type()? == Point
+                        || right_geom.dwithin(left_geom, distance)?
+                    {
+                        left_indicies.push(*left_index as _);
+                        right_indicies.push(right_index as _);
+                    }
                 }
                 return Ok((left_indicies, right_indicies));
             }
@@ -1999,7 +4899,7 @@ pub fn sjoin(
     right: &BinaryChunked,
     predicate: SjoinPredicate,
 ) -> SindexQueryResult {
-    SIndex::try_new(left)?.sjoin(right, predicate)
+    SIndex::cached(left)?.sjoin(right, predicate)
 }
 
 pub fn sjoin_dwithin(
@@ -2007,78 +4907,357 @@ pub fn sjoin_dwithin(
     right: &BinaryChunked,
     distance: f64,
 ) -> SindexQueryResult {
-    SIndex::try_new(left)?.sjoin_dwithin(right, distance)
+    SIndex::cached(left)?.sjoin_dwithin(right, distance)
+}
+
+/// Join the geometries with themselves, leaving out the pairs of a geometry with itself.
+/// With `distinct`, a pair is also left out when its mirrored pair was already kept.
+pub fn self_sjoin(
+    wkb: &BinaryChunked,
+    predicate: SjoinPredicate,
+    distinct: bool,
+) -> SindexQueryResult {
+    let (left, right) = match predicate {
+        SjoinPredicate::Dwithin(distance) => sjoin_dwithin(wkb, wkb, distance),
+        predicate => sjoin(wkb, wkb, predicate),
+    }?;
+    let pairs: HashSet<(u32, u32)> = match distinct {
+        true => left.iter().copied().zip(right.iter().copied()).collect(),
+        false => HashSet::new(),
+    };
+    Ok(left
+        .into_iter()
+        .zip(right)
+        .filter(|&(left, right)| left != right && (left < right || !pairs.contains(&(right, left))))
+        .unzip())
+}
+
+fn unmatched_indices(indices: &[u32], len: usize) -> Vec<u32> {
+    let mut matched = vec![false; len];
+    for &index in indices {
+        matched[index as usize] = true;
+    }
+    (0..len as u32).filter(|&i| !matched[i as usize]).collect()
+}
+
+/// Append the indices without any match as pairs with a null index on the other side,
+/// so outer joins keep the unmatched rows.
+pub fn sjoin_with_unmatched(
+    (left, right): (Vec<u32>, Vec<u32>),
+    left_len: usize,
+    right_len: usize,
+    how: SjoinHow,
+) -> (Vec<Option<u32>>, Vec<Option<u32>>) {
+    let left_unmatched = match how {
+        SjoinHow::Left | SjoinHow::Full => unmatched_indices(&left, left_len),
+        SjoinHow::Inner | SjoinHow::Right => vec![],
+    };
+    let right_unmatched = match how {
+        SjoinHow::Right | SjoinHow::Full => unmatched_indices(&right, right_len),
+        SjoinHow::Inner | SjoinHow::Left => vec![],
+    };
+    let num_left_unmatched = left_unmatched.len();
+    let num_right_unmatched = right_unmatched.len();
+    let left = left
+        .into_iter()
+        .chain(left_unmatched)
+        .map(Some)
+        .chain(std::iter::repeat_n(None, num_right_unmatched))
+        .collect();
+    let right = right
+        .into_iter()
+        .map(Some)
+        .chain(std::iter::repeat_n(None, num_left_unmatched))
+        .chain(right_unmatched.into_iter().map(Some))
+        .collect();
+    (left, right)
+}
+
+/// Compute the Voronoi cells of all geometries, each with the row index of the first
+/// geometry it intersects, sorted by that index.
+fn indexed_voronoi_cells(
+    wkb: &BinaryChunked,
+    extend_to: Option<&Geometry>,
+    tolerance: f64,
+) -> GResult<Vec<(Option<u32>, Geometry)>> {
+    let sindex = SIndex::try_new(wkb)?;
+    let cells = collect_geometry_vec(wkb)
+        .and_then(Geometry::create_geometry_collection)
+        .and_then(|geom| geom.voronoi(extend_to, tolerance, false))?;
+    let mut indexed_cells = (0..cells.get_num_geometries()?)
+        .map(|n| {
+            let cell = cells.get_geometry_n(n)?;
+            let cell_prepared = cell.to_prepared_geom()?;
+            let extent = cell.get_extent()?;
+            let mut seed_index = None;
+            for hit in sindex
+                .tree
+                .search(extent[0], extent[1], extent[2], extent[3])
+            {
+                let (index, geom) = &sindex.data[hit as usize];
+                if seed_index.is_none_or(|seed_index| *index < seed_index)
+                    && cell_prepared.intersects(geom)?
+                {
+                    seed_index = Some(*index);
+                }
+            }
+            Ok((seed_index.map(|index| index as u32), Geom::clone(&cell)?))
+        })
+        .collect::<GResult<Vec<_>>>()?;
+    indexed_cells.sort_by_key(|(index, _)| *index);
+    Ok(indexed_cells)
+}
+
+pub fn voronoi_with_index(
+    wkb: &BinaryChunked,
+    params: &VoronoiWithIndexKwargs,
+) -> GResult<(BinaryChunked, UInt32Chunked)> {
+    let extend_to = params
+        .extend_to
+        .as_ref()
+        .map(|wkb| Geometry::new_from_wkb(wkb))
+        .transpose()?;
+    let mut indices = vec![];
+    let mut cells = vec![];
+    for (index, cell) in indexed_voronoi_cells(wkb, extend_to.as_ref(), params.tolerance)? {
+        indices.push(index);
+        cells.push(cell.to_ewkb()?);
+    }
+    Ok((
+        BinaryChunked::from_iter_values(wkb.name().clone(), cells.into_iter()),
+        UInt32Chunked::from_iter_options("index".into(), indices.into_iter()),
+    ))
+}
+
+pub fn nearest_point_in_set(
+    wkb: &BinaryChunked,
+    candidates: &BinaryChunked,
+) -> GResult<(BinaryChunked, UInt32Chunked)> {
+    let sindex = SIndex::try_new(candidates)?;
+    let nearest = (0..wkb.len())
+        .into_par_iter()
+        .map(|index| {
+            let Some(wkb) = (unsafe { wkb.get_unchecked(index) }) else {
+                return Ok((None, None));
+            };
+            let geom = Geometry::new_from_wkb(wkb)?;
+            if geom.is_empty()? {
+                return Ok((None, None));
+            }
+            let Some((candidate_index, candidate)) = sindex.nearest(&geom)? else {
+                return Ok((None, None));
+            };
+            let mut line = Geometry::create_line_string(geom.nearest_points(candidate)?)?;
+            line.set_srid(geom.get_srid()?);
+            Ok((Some(line.to_ewkb()?), Some(candidate_index as u32)))
+        })
+        .collect::<GResult<Vec<_>>>()?;
+    let (lines, indices): (Vec<_>, Vec<_>) = nearest.into_iter().unzip();
+    Ok((
+        BinaryChunked::from_iter_options(wkb.name().clone(), lines.into_iter()),
+        UInt32Chunked::from_iter_options("index".into(), indices.into_iter()),
+    ))
+}
+
+pub fn distance_matrix(a: &BinaryChunked, b: &BinaryChunked) -> GResult<ListChunked> {
+    let parse_others = || {
+        b.iter()
+            .map(|wkb| {
+                let Some(wkb) = wkb else { return Ok(None) };
+                let geom = Geometry::new_from_wkb(wkb)?;
+                let is_empty = geom.is_empty()?;
+                Ok(Some((geom, is_empty)))
+            })
+            .collect::<GResult<Vec<_>>>()
+    };
+    // Surface parsing errors before spawning the tasks
+    parse_others()?;
+    let rows = (0..a.len())
+        .into_par_iter()
+        // Geometries can't be shared across threads, so each task parses its own copy
+        .map_init(parse_others, |others, index| {
+            let others = others
+                .as_ref()
+                .map_err(|e| GError::GenericError(e.to_string()))?;
+            let Some(wkb) = (unsafe { a.get_unchecked(index) }) else {
+                return Ok(None);
+            };
+            let geom = Geometry::new_from_wkb(wkb)?;
+            let is_empty = geom.is_empty()?;
+            let distances = others
+                .iter()
+                .map(|other| match other {
+                    None => Ok(None),
+                    // Match `distance` behavior
+                    Some((_, other_empty)) if is_empty || *other_empty => Ok(Some(f64::NAN)),
+                    Some((other, _)) => geom.distance(other).map(Some),
+                })
+                .collect::<GResult<Float64Chunked>>()?;
+            Ok(Some(distances.into_series()))
+        })
+        .collect::<GResult<Vec<_>>>()?;
+    // The dtype is explicit, as it can't be inferred when every row is null
+    let mut builder = ListPrimitiveChunkedBuilder::<Float64Type>::new(
+        a.name().clone(),
+        a.len(),
+        a.len() * b.len(),
+        DataType::Float64,
+    );
+    for row in rows {
+        match row {
+            Some(distances) => builder
+                .append_series(&distances)
+                .map_err(|e| GError::GenericError(e.to_string()))?,
+            None => builder.append_null(),
+        }
+    }
+    Ok(builder.finish())
 }
 
-fn apply_proj_transform(src: &Proj, dst: &Proj, geom: &Geometry) -> GResult<Geometry> {
+/// Unless `always_xy` is set, coordinates of geographic CRS follow the EPSG axis order,
+/// with the latitude first.
+fn apply_proj_transform(
+    src: &Proj,
+    dst: &Proj,
+    geom: &Geometry,
+    always_xy: bool,
+) -> GResult<Geometry> {
     use proj4rs::adaptors::{transform_xy, transform_xyz};
-    geom.transform_xyz(|x, y, z| {
+    let swap_src = !always_xy && src.is_latlong();
+    let swap_dst = !always_xy && dst.is_latlong();
+    geom.transform_xyz(|coord_x, coord_y, z| {
+        let (x, y) = if swap_src {
+            (coord_y, coord_x)
+        } else {
+            (coord_x, coord_y)
+        };
+        let to_err = |e: proj4rs::errors::Error| {
+            GError::GenericError(format!("{e} for coordinate ({coord_x}, {coord_y})"))
+        };
         let has_z = !z.is_nan();
         let mut new_x: f64;
         let mut new_y: f64;
-        let mut new_z: f64;
+        // Z is an elevation, only X and Y are angular in geographic coordinates
+        let mut new_z = z;
 
         if src.is_latlong() {
             new_x = x.to_radians();
             new_y = y.to_radians();
-            new_z = z.to_radians();
         } else {
             new_x = x;
             new_y = y;
-            new_z = z;
         }
         if has_z {
-            (new_x, new_y, new_z) = transform_xyz(src, dst, new_x, new_y, new_z)
-                .map_err(|e| GError::GenericError(e.to_string()))?;
+            (new_x, new_y, new_z) = transform_xyz(src, dst, new_x, new_y, new_z).map_err(to_err)?;
         } else {
-            (new_x, new_y) = transform_xy(src, dst, new_x, new_y)
-                .map_err(|e| GError::GenericError(e.to_string()))?;
+            (new_x, new_y) = transform_xy(src, dst, new_x, new_y).map_err(to_err)?;
         }
         if dst.is_latlong() {
             new_x = new_x.to_degrees();
             new_y = new_y.to_degrees();
-            new_z = new_z.to_degrees();
+        }
+        if swap_dst {
+            (new_x, new_y) = (new_y, new_x);
         }
         Ok((new_x, new_y, new_z))
     })
 }
-struct ProjCache(HashMap<u16, Proj>);
+
+struct ProjCache {
+    projs: HashMap<i64, Rc<Proj>>,
+    /// Projections of the previous row, which usually has the same SRIDs as the next one.
+    last: Option<(i64, i64, Rc<Proj>, Rc<Proj>)>,
+}
 
 impl ProjCache {
     fn new() -> Self {
-        Self(HashMap::<u16, Proj>::new())
+        Self {
+            projs: HashMap::new(),
+            last: None,
+        }
+    }
+
+    fn load(&mut self, srid: i64) -> GResult<Rc<Proj>> {
+        match self.projs.entry(srid) {
+            Entry::Occupied(e) => Ok(e.get().clone()),
+            Entry::Vacant(e) => {
+                let proj = u16::try_from(srid)
+                    .ok()
+                    .and_then(|srid| Proj::from_epsg_code(srid).ok())
+                    .ok_or_else(|| GError::GenericError(format!("Unknown SRID: {srid}")))?;
+                Ok(e.insert(Rc::new(proj)).clone())
+            }
+        }
     }
 
-    #[allow(clippy::ref_as_ptr)]
-    fn get(&mut self, srid: u16) -> Result<*const Proj, ProjError> {
-        Ok(match self.0.entry(srid) {
-            std::collections::hash_map::Entry::Occupied(e) => e.into_mut(),
-            std::collections::hash_map::Entry::Vacant(e) => e.insert(Proj::from_epsg_code(srid)?),
-        } as *const Proj)
+    /// Rows with the same SRIDs as the previous one don't need any lookup.
+    fn get_pair(&mut self, src: i64, dst: i64) -> GResult<(&Proj, &Proj)> {
+        let last = match self.last.take() {
+            Some(last) if last.0 == src && last.1 == dst => last,
+            _ => (src, dst, self.load(src)?, self.load(dst)?),
+        };
+        let (_, _, proj_src, proj_dst) = self.last.insert(last);
+        Ok((proj_src, proj_dst))
     }
 }
 
-pub fn to_srid(wkb: &BinaryChunked, srid: &Int64Chunked) -> GResult<BinaryChunked> {
+pub fn to_srid(
+    wkb: &BinaryChunked,
+    srid: &Int64Chunked,
+    params: &ToSridKwargs,
+) -> GResult<BinaryChunked> {
     let mut cache = ProjCache::new();
+    let mut row = 0;
 
-    broadcast_try_binary_elementwise_values(wkb, srid, |wkb, dest_srid| {
+    broadcast_try_binary_elementwise(wkb, srid, |wkb, dest_srid| {
+        let index = row;
+        row += 1;
+        let (Some(wkb), Some(dest_srid)) = (wkb, dest_srid) else {
+            return Ok(None);
+        };
         let geom = Geometry::new_from_wkb(wkb)?;
         let geom_srid: i64 = geom.get_srid()?.into();
 
         if geom_srid == dest_srid || geom.is_empty()? {
-            return Ok(wkb.into());
+            return Ok(Some(wkb.to_vec()));
         }
 
-        let Ok(Ok(proj_src)) = geom_srid.try_into().map(|srid| cache.get(srid)) else {
-            return Err(GError::GenericError(format!("Unknown SRID: {geom_srid}")));
-        };
+        let (proj_src, proj_dst) = cache.get_pair(geom_srid, dest_srid)?;
+        let mut transformed =
+            match apply_proj_transform(proj_src, proj_dst, &geom, params.always_xy) {
+                Ok(transformed) => transformed,
+                Err(_) if matches!(params.on_error, ToSridOnError::Null) => return Ok(None),
+                Err(e) => {
+                    let msg = format!("Cannot reproject row {index} from SRID {geom_srid}: {e}");
+                    return Err(GError::GenericError(msg));
+                }
+            };
+        transformed.set_srid(dest_srid as _);
+        transformed.to_ewkb().map(Some)
+    })
+}
 
-        let Ok(Ok(proj_dst)) = dest_srid.try_into().map(|srid| cache.get(srid)) else {
-            return Err(GError::GenericError(format!("Unknown SRID: {dest_srid}")));
+pub fn to_crs(
+    wkb: &BinaryChunked,
+    source: &str,
+    target: &str,
+    always_xy: bool,
+) -> GResult<BinaryChunked> {
+    let get_proj = |definition: &str| {
+        crs::proj_from_definition(definition)
+            .ok_or_else(|| GError::GenericError(format!("Invalid CRS definition: {definition}")))
+    };
+    let proj_src = get_proj(source)?;
+    let proj_dst = get_proj(target)?;
+    let srid = crs::get_epsg_code(target).map_or(0, i32::from);
+    wkb.try_apply_nonnull_values_generic(|wkb| {
+        let geom = Geometry::new_from_wkb(wkb)?;
+        let mut transformed = if geom.is_empty()? {
+            geom
+        } else {
+            apply_proj_transform(&proj_src, &proj_dst, &geom, always_xy)?
         };
-
-        let mut transformed = unsafe { apply_proj_transform(&*proj_src, &*proj_dst, &geom)? };
-        transformed.set_srid(dest_srid as _);
+        transformed.set_srid(srid);
         transformed.to_ewkb()
     })
 }
@@ -0,0 +1,64 @@
+const BASE32: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+/// Encode a longitude / latitude pair as a geohash of the given length.
+/// Returns `None` if the coordinates are out of range.
+pub fn encode(lon: f64, lat: f64, precision: usize) -> Option<String> {
+    if !(-180.0..=180.0).contains(&lon) || !(-90.0..=90.0).contains(&lat) {
+        return None;
+    }
+    let mut lon_range = (-180.0, 180.0);
+    let mut lat_range = (-90.0, 90.0);
+    let mut is_lon = true;
+    let mut hash = String::with_capacity(precision);
+    for _ in 0..precision {
+        let mut index = 0;
+        for _ in 0..5 {
+            let (range, value) = match is_lon {
+                true => (&mut lon_range, lon),
+                false => (&mut lat_range, lat),
+            };
+            let mid = (range.0 + range.1) / 2.0;
+            index <<= 1;
+            if value >= mid {
+                index |= 1;
+                range.0 = mid;
+            } else {
+                range.1 = mid;
+            }
+            is_lon = !is_lon;
+        }
+        hash.push(BASE32[index] as char);
+    }
+    Some(hash)
+}
+
+/// Decode a geohash into the longitude / latitude of its cell center.
+/// Returns `None` if the geohash is empty or contains invalid characters.
+pub fn decode(hash: &str) -> Option<(f64, f64)> {
+    if hash.is_empty() {
+        return None;
+    }
+    let mut lon_range = (-180.0, 180.0);
+    let mut lat_range = (-90.0, 90.0);
+    let mut is_lon = true;
+    for c in hash.bytes() {
+        let c = c.to_ascii_lowercase();
+        let index = BASE32.iter().position(|&b| b == c)?;
+        for bit in (0..5).rev() {
+            let range = match is_lon {
+                true => &mut lon_range,
+                false => &mut lat_range,
+            };
+            let mid = (range.0 + range.1) / 2.0;
+            if (index >> bit) & 1 == 1 {
+                range.0 = mid;
+            } else {
+                range.1 = mid;
+            }
+            is_lon = !is_lon;
+        }
+    }
+    let lon = (lon_range.0 + lon_range.1) / 2.0;
+    let lat = (lat_range.0 + lat_range.1) / 2.0;
+    Some((lon, lat))
+}
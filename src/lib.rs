@@ -17,8 +17,10 @@ mod arity;
 mod crs;
 mod expressions;
 mod functions;
+mod geohash;
 mod utils;
 mod wkb;
+mod xml;
 
 #[global_allocator]
 static ALLOC: PolarsAllocator = PolarsAllocator::new();
@@ -28,6 +30,10 @@ fn _lib(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add("__version__", env!("CARGO_PKG_VERSION"))?;
     m.add_function(wrap_pyfunction!(crs::get_crs_authority, m)?)?;
     m.add_function(wrap_pyfunction!(crs::get_crs_from_code, m)?)?;
+    m.add_function(wrap_pyfunction!(crs::geoparquet_metadata, m)?)?;
+    m.add_function(wrap_pyfunction!(crs::parse_geoparquet_metadata, m)?)?;
     m.add_function(wrap_pyfunction!(expressions::to_python_dict, m)?)?;
+    m.add_function(wrap_pyfunction!(expressions::apply_coordinates, m)?)?;
+    m.add_function(wrap_pyfunction!(expressions::clear_sjoin_cache, m)?)?;
     Ok(())
 }
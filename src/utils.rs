@@ -1,3 +1,9 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::f64::consts::TAU;
+
+use geo_index::rtree::{sort::STRSort, RTree, RTreeBuilder, RTreeIndex};
+
 pub fn try_reduce<I, F, T, E>(mut iter: I, mut func: F) -> Result<Option<T>, E>
 where
     I: Iterator<Item = T>,
@@ -11,3 +17,461 @@ where
     }
     Ok(Some(acc))
 }
+
+fn circle_contains(center: [f64; 2], radius: f64, point: [f64; 2]) -> bool {
+    let distance = (point[0] - center[0]).hypot(point[1] - center[1]);
+    distance <= radius * (1.0 + 1e-12) + f64::EPSILON
+}
+
+fn circle_from_two(a: [f64; 2], b: [f64; 2]) -> ([f64; 2], f64) {
+    let center = [(a[0] + b[0]) / 2.0, (a[1] + b[1]) / 2.0];
+    (center, (a[0] - b[0]).hypot(a[1] - b[1]) / 2.0)
+}
+
+fn circle_from_three(a: [f64; 2], b: [f64; 2], c: [f64; 2]) -> ([f64; 2], f64) {
+    let (bx, by) = (b[0] - a[0], b[1] - a[1]);
+    let (cx, cy) = (c[0] - a[0], c[1] - a[1]);
+    let d = 2.0 * (bx * cy - by * cx);
+    let b2 = bx * bx + by * by;
+    let c2 = cx * cx + cy * cy;
+    let ux = (cy * b2 - by * c2) / d;
+    let uy = (bx * c2 - cx * b2) / d;
+    if !(ux.is_finite() && uy.is_finite()) {
+        // Collinear points: the circle is defined by the two farthest ones.
+        return [
+            circle_from_two(a, b),
+            circle_from_two(a, c),
+            circle_from_two(b, c),
+        ]
+        .into_iter()
+        .max_by(|first, second| first.1.total_cmp(&second.1))
+        .unwrap();
+    }
+    ([a[0] + ux, a[1] + uy], ux.hypot(uy))
+}
+
+/// Shuffle the items in place with a fixed-seed xorshift generator, so that results are
+/// reproducible without pulling in a random number crate.
+fn shuffle<T>(items: &mut [T]) {
+    let mut state: u64 = 0x9E37_79B9_7F4A_7C15;
+    for i in (1..items.len()).rev() {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        let j = (state % (i as u64 + 1)) as usize;
+        items.swap(i, j);
+    }
+}
+
+/// Compute the smallest circle enclosing all points, as a center and a radius.
+///
+/// This is Welzl's algorithm in its iterative form. The points are shuffled first, which
+/// makes the expected running time linear instead of cubic for adversarial orderings.
+pub fn minimum_enclosing_circle(points: &[[f64; 2]]) -> Option<([f64; 2], f64)> {
+    let mut points = points.to_vec();
+    shuffle(&mut points);
+    let (&first, _) = points.split_first()?;
+    let (mut center, mut radius) = (first, 0.0);
+    for (i, &p) in points.iter().enumerate().skip(1) {
+        if circle_contains(center, radius, p) {
+            continue;
+        }
+        (center, radius) = (p, 0.0);
+        for (j, &q) in points[..i].iter().enumerate() {
+            if circle_contains(center, radius, q) {
+                continue;
+            }
+            (center, radius) = circle_from_two(p, q);
+            for &r in &points[..j] {
+                if !circle_contains(center, radius, r) {
+                    (center, radius) = circle_from_three(p, q, r);
+                }
+            }
+        }
+    }
+    Some((center, radius))
+}
+
+fn cross(o: [f64; 2], a: [f64; 2], b: [f64; 2]) -> f64 {
+    (a[0] - o[0]) * (b[1] - o[1]) - (a[1] - o[1]) * (b[0] - o[0])
+}
+
+fn triangle_contains(a: [f64; 2], b: [f64; 2], c: [f64; 2], p: [f64; 2]) -> bool {
+    let (d1, d2, d3) = (cross(a, b, p), cross(b, c, p), cross(c, a, p));
+    let has_negative = d1 < 0.0 || d2 < 0.0 || d3 < 0.0;
+    let has_positive = d1 > 0.0 || d2 > 0.0 || d3 > 0.0;
+    !(has_negative && has_positive)
+}
+
+fn sub_3d(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn dot_3d(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+/// Return the 3D distance between two points.
+pub fn point_distance_3d(a: [f64; 3], b: [f64; 3]) -> f64 {
+    let delta = sub_3d(a, b);
+    dot_3d(delta, delta).sqrt()
+}
+
+/// Return the 3D distance between the segments `p1q1` and `p2q2`, which may be reduced
+/// to a point. See Ericson, Real-Time Collision Detection, section 5.1.9.
+pub fn segment_distance_3d(p1: [f64; 3], q1: [f64; 3], p2: [f64; 3], q2: [f64; 3]) -> f64 {
+    let (d1, d2, offset) = (sub_3d(q1, p1), sub_3d(q2, p2), sub_3d(p1, p2));
+    let (len1, len2) = (dot_3d(d1, d1), dot_3d(d2, d2));
+    let (proj1, proj2) = (dot_3d(d1, offset), dot_3d(d2, offset));
+    let (s, t) = if len1 <= 0.0 && len2 <= 0.0 {
+        (0.0, 0.0)
+    } else if len1 <= 0.0 {
+        (0.0, (proj2 / len2).clamp(0.0, 1.0))
+    } else if len2 <= 0.0 {
+        ((-proj1 / len1).clamp(0.0, 1.0), 0.0)
+    } else {
+        let directions = dot_3d(d1, d2);
+        let denominator = len1 * len2 - directions * directions;
+        // Parallel segments: any point of the first one will do
+        let s = if denominator == 0.0 {
+            0.0
+        } else {
+            ((directions * proj2 - proj1 * len2) / denominator).clamp(0.0, 1.0)
+        };
+        let t = (directions * s + proj2) / len2;
+        if t < 0.0 {
+            ((-proj1 / len1).clamp(0.0, 1.0), 0.0)
+        } else if t > 1.0 {
+            (((directions - proj1) / len1).clamp(0.0, 1.0), 1.0)
+        } else {
+            (s, t)
+        }
+    };
+    let on_first = [p1[0] + d1[0] * s, p1[1] + d1[1] * s, p1[2] + d1[2] * s];
+    let on_second = [p2[0] + d2[0] * t, p2[1] + d2[1] * t, p2[2] + d2[2] * t];
+    point_distance_3d(on_first, on_second)
+}
+
+/// Return the distance between two axis-aligned boxes given by their lower and upper
+/// corners, which is a lower bound of the distance between anything they contain.
+pub fn box_distance_3d(a: ([f64; 3], [f64; 3]), b: ([f64; 3], [f64; 3])) -> f64 {
+    let gap = |axis: usize| (a.0[axis] - b.1[axis]).max(b.0[axis] - a.1[axis]).max(0.0);
+    let (dx, dy, dz) = (gap(0), gap(1), gap(2));
+    (dx * dx + dy * dy + dz * dz).sqrt()
+}
+
+/// Polygon lying in a plane, with its rings projected on the axis-aligned plane closest
+/// to it for point-in-polygon tests.
+pub struct PlanarPolygon {
+    normal: [f64; 3],
+    origin: [f64; 3],
+    dropped_axis: usize,
+    rings: Vec<Vec<[f64; 2]>>,
+}
+
+impl PlanarPolygon {
+    /// Fit the plane of the exterior ring with Newell's method. Return `None` for rings
+    /// without area, such as vertical polygons seen from above collapsed to a line.
+    pub fn new(rings: &[Vec<[f64; 3]>]) -> Option<Self> {
+        let exterior = rings.first()?;
+        let mut normal = [0.0; 3];
+        for edge in exterior.windows(2) {
+            let (a, b) = (edge[0], edge[1]);
+            normal[0] += (a[1] - b[1]) * (a[2] + b[2]);
+            normal[1] += (a[2] - b[2]) * (a[0] + b[0]);
+            normal[2] += (a[0] - b[0]) * (a[1] + b[1]);
+        }
+        let length = dot_3d(normal, normal).sqrt();
+        if !(length > 0.0 && length.is_finite()) {
+            return None;
+        }
+        let normal = normal.map(|n| n / length);
+        let dropped_axis = (0..3).max_by(|&i, &j| normal[i].abs().total_cmp(&normal[j].abs()))?;
+        let project = |p: &[f64; 3]| match dropped_axis {
+            0 => [p[1], p[2]],
+            1 => [p[2], p[0]],
+            _ => [p[0], p[1]],
+        };
+        let rings = rings
+            .iter()
+            .map(|ring| ring.iter().map(project).collect())
+            .collect();
+        Some(Self {
+            normal,
+            origin: exterior[0],
+            dropped_axis,
+            rings,
+        })
+    }
+
+    fn signed_distance(&self, point: [f64; 3]) -> f64 {
+        dot_3d(sub_3d(point, self.origin), self.normal)
+    }
+
+    /// Whether a point of the plane is inside the polygon, by the even-odd rule.
+    fn contains(&self, point: [f64; 3]) -> bool {
+        let [x, y] = match self.dropped_axis {
+            0 => [point[1], point[2]],
+            1 => [point[2], point[0]],
+            _ => [point[0], point[1]],
+        };
+        let mut inside = false;
+        for ring in &self.rings {
+            for edge in ring.windows(2) {
+                let (a, b) = (edge[0], edge[1]);
+                if (a[1] > y) != (b[1] > y) && x < a[0] + (y - a[1]) / (b[1] - a[1]) * (b[0] - a[0])
+                {
+                    inside = !inside;
+                }
+            }
+        }
+        inside
+    }
+
+    /// Return the distance from a point to the interior of the polygon, if its orthogonal
+    /// projection on the plane falls inside. Otherwise the closest point of the polygon
+    /// is on its boundary.
+    pub fn interior_distance(&self, point: [f64; 3]) -> Option<f64> {
+        let distance = self.signed_distance(point);
+        let projected = [0, 1, 2].map(|axis| point[axis] - distance * self.normal[axis]);
+        self.contains(projected).then_some(distance.abs())
+    }
+
+    /// Whether the segment `pq` goes through the polygon.
+    pub fn intersects_segment(&self, p: [f64; 3], q: [f64; 3]) -> bool {
+        let (dp, dq) = (self.signed_distance(p), self.signed_distance(q));
+        if dp * dq > 0.0 || (dp == 0.0 && dq == 0.0) {
+            // Segments lying in the plane are handled by the distance to the boundary
+            return false;
+        }
+        let t = dp / (dp - dq);
+        self.contains([0, 1, 2].map(|axis| p[axis] + (q[axis] - p[axis]) * t))
+    }
+}
+
+/// Index points by their position, or return `None` when there are none.
+fn point_tree(points: &[[f64; 2]]) -> Option<RTree<f64>> {
+    if points.is_empty() {
+        return None;
+    }
+    let mut tree = RTreeBuilder::new(points.len() as u32);
+    for &[x, y] in points {
+        tree.add(x, y, x, y);
+    }
+    Some(tree.finish::<STRSort>())
+}
+
+/// Simplify a line or ring with the Visvalingam-Whyatt algorithm, removing the vertices
+/// whose effective area is below `tolerance` while keeping at least `min_points`. The
+/// endpoints are always kept, so rings stay closed.
+///
+/// With `obstacles`, a vertex is only removed if no other vertex of the line, nor of the
+/// obstacles, lies in the triangle it forms with its neighbors. As long as the line and
+/// the obstacles don't already cross, this is enough for the removal not to make them
+/// cross. The vertices are indexed so that only those near the triangle are tested.
+pub fn visvalingam_whyatt(
+    coords: &[f64],
+    dimensions: usize,
+    tolerance: f64,
+    min_points: usize,
+    obstacles: Option<&[&[f64]]>,
+) -> Vec<f64> {
+    const NONE: usize = usize::MAX;
+    let count = coords.len() / dimensions;
+    if count <= min_points.max(2) {
+        return coords.to_vec();
+    }
+    let to_points = |coords: &[f64]| -> Vec<[f64; 2]> {
+        let chunks = coords.chunks_exact(dimensions);
+        chunks.map(|c| [c[0], c[1]]).collect()
+    };
+    let points = to_points(coords);
+    let point = |i: usize| points[i];
+    let closed = point(0).map(f64::to_bits) == point(count - 1).map(f64::to_bits);
+    // The last vertex of a ring is the same as the first one
+    let alias = |i: usize| if closed && i == count - 1 { 0 } else { i };
+    let trees = obstacles.map(|obstacles| {
+        let obstacles = obstacles.iter().flat_map(|coords| to_points(coords));
+        let obstacles = obstacles.collect::<Vec<_>>();
+        (point_tree(&points), point_tree(&obstacles), obstacles)
+    });
+
+    let mut prev: Vec<usize> = (0..count)
+        .map(|i| i.checked_sub(1).unwrap_or(NONE))
+        .collect();
+    let mut next: Vec<usize> = (1..=count)
+        .map(|i| if i == count { NONE } else { i })
+        .collect();
+    let area = |prev: &[usize], next: &[usize], i: usize| {
+        cross(point(prev[i]), point(i), point(next[i])).abs() / 2.0
+    };
+    let blocked = |prev: &[usize], next: &[usize], versions: &[u32], i: usize| {
+        let Some((line_tree, obstacle_tree, obstacles)) = &trees else {
+            return false;
+        };
+        let (pa, pi, pb) = (point(prev[i]), point(i), point(next[i]));
+        if cross(pa, pi, pb) == 0.0 {
+            return false;
+        }
+        let (xmin, xmax) = (pa[0].min(pi[0]).min(pb[0]), pa[0].max(pi[0]).max(pb[0]));
+        let (ymin, ymax) = (pa[1].min(pi[1]).min(pb[1]), pa[1].max(pi[1]).max(pb[1]));
+        let involved = [prev[i], i, next[i]].map(alias);
+        let search = |tree: &Option<RTree<f64>>| {
+            let hits = tree
+                .as_ref()
+                .map(|tree| tree.search(xmin, ymin, xmax, ymax));
+            hits.unwrap_or_default().into_iter().map(|hit| hit as usize)
+        };
+        search(line_tree)
+            .filter(|&v| versions[v] != u32::MAX && !involved.contains(&alias(v)))
+            .any(|v| triangle_contains(pa, pi, pb, point(v)))
+            || search(obstacle_tree).any(|v| triangle_contains(pa, pi, pb, obstacles[v]))
+    };
+
+    // Areas are positive, so the ordering of their bits is the ordering of their values
+    let mut versions = vec![0u32; count];
+    let mut heap: BinaryHeap<_> = (1..count - 1)
+        .map(|i| Reverse((area(&prev, &next, i).to_bits(), i, 0)))
+        .collect();
+    let mut remaining = count;
+    while let Some(Reverse((area_bits, i, version))) = heap.pop() {
+        if version != versions[i] {
+            continue;
+        }
+        if f64::from_bits(area_bits) >= tolerance || remaining <= min_points {
+            break;
+        }
+        if blocked(&prev, &next, &versions, i) {
+            continue;
+        }
+        let (before, after) = (prev[i], next[i]);
+        (next[before], prev[after]) = (after, before);
+        versions[i] = u32::MAX;
+        remaining -= 1;
+        for neighbor in [before, after] {
+            if neighbor != 0 && neighbor != count - 1 {
+                versions[neighbor] += 1;
+                let area = area(&prev, &next, neighbor).to_bits();
+                heap.push(Reverse((area, neighbor, versions[neighbor])));
+            }
+        }
+    }
+
+    let mut result = Vec::with_capacity(remaining * dimensions);
+    let mut i = 0;
+    while i != NONE {
+        result.extend_from_slice(&coords[i * dimensions..(i + 1) * dimensions]);
+        i = next[i];
+    }
+    result
+}
+
+/// Insert evenly spaced vertices in each segment of a line or ring. `pieces` returns the
+/// number of pieces a segment is split into along with any value computed once for the
+/// segment, and `interpolate` appends the vertex at a fraction of it.
+pub fn split_segments<S, P, I>(
+    coords: &[f64],
+    dimensions: usize,
+    mut pieces: P,
+    mut interpolate: I,
+) -> Vec<f64>
+where
+    P: FnMut(&[f64], &[f64]) -> (f64, S),
+    I: FnMut(&[f64], &[f64], &S, f64, &mut Vec<f64>),
+{
+    let mut result = Vec::with_capacity(coords.len());
+    let mut vertices = coords.chunks_exact(dimensions).peekable();
+    while let Some(start) = vertices.next() {
+        result.extend_from_slice(start);
+        let Some(end) = vertices.peek() else {
+            break;
+        };
+        // Segments with non-finite coordinates are left as they are
+        let (pieces, segment) = pieces(start, end);
+        let mut piece = 1.0;
+        while pieces.is_finite() && piece < pieces {
+            interpolate(start, end, &segment, piece / pieces, &mut result);
+            piece += 1.0;
+        }
+    }
+    result
+}
+
+/// Replace the arcs of a circular string by segments no longer than `max_length`. Each arc
+/// is sampled at even angles, and the extra ordinates (Z and M) are linearly interpolated
+/// along each half of the arc.
+pub fn linearize_arcs(coords: &[f64], dimensions: usize, max_length: f64) -> Vec<f64> {
+    let vertices = coords.chunks_exact(dimensions).collect::<Vec<_>>();
+    let Some(first) = vertices.first() else {
+        return vec![];
+    };
+    let mut result = first.to_vec();
+    for arc in vertices.windows(3).step_by(2) {
+        let [start, middle, end] = [arc[0], arc[1], arc[2]];
+        let (dx1, dy1) = (middle[0] - start[0], middle[1] - start[1]);
+        let (dx2, dy2) = (end[0] - start[0], end[1] - start[1]);
+        let cross = dx1 * dy2 - dy1 * dx2;
+        // Center relative to the start of the arc, a full circle is centered on its middle
+        let (cx, cy, sweep) = if dx2 == 0.0 && dy2 == 0.0 {
+            (dx1 / 2.0, dy1 / 2.0, TAU)
+        } else if cross == 0.0 {
+            result.extend_from_slice(middle);
+            result.extend_from_slice(end);
+            continue;
+        } else {
+            let (l1, l2) = (dx1 * dx1 + dy1 * dy1, dx2 * dx2 + dy2 * dy2);
+            let cx = (dy2 * l1 - dy1 * l2) / (2.0 * cross);
+            let cy = (dx1 * l2 - dx2 * l1) / (2.0 * cross);
+            let angle = |dx: f64, dy: f64| (dy - cy).atan2(dx - cx);
+            let sweep = (angle(dx2, dy2) - angle(0.0, 0.0)).rem_euclid(TAU);
+            (cx, cy, if cross > 0.0 { sweep } else { sweep - TAU })
+        };
+        let radius = cx.hypot(cy);
+        let start_angle = (-cy).atan2(-cx);
+        let middle_angle = ((dy1 - cy).atan2(dx1 - cx) - start_angle).rem_euclid(TAU);
+        let middle_fraction = match sweep > 0.0 {
+            true => middle_angle / sweep,
+            false => (middle_angle - TAU) / sweep,
+        };
+        let pieces = (radius * sweep.abs() / max_length).ceil();
+        let mut piece = 1.0;
+        while pieces.is_finite() && piece < pieces {
+            let fraction = piece / pieces;
+            let angle = start_angle + sweep * fraction;
+            result.extend([
+                start[0] + cx + radius * angle.cos(),
+                start[1] + cy + radius * angle.sin(),
+            ]);
+            let (from, to, fraction) = match fraction <= middle_fraction {
+                true => (start, middle, fraction / middle_fraction),
+                false => (
+                    middle,
+                    end,
+                    (fraction - middle_fraction) / (1.0 - middle_fraction),
+                ),
+            };
+            let ordinates = from.iter().zip(to.iter()).skip(2);
+            result.extend(ordinates.map(|(from, to)| from + (to - from) * fraction));
+            piece += 1.0;
+        }
+        result.extend_from_slice(end);
+    }
+    result
+}
+
+/// Insert evenly spaced vertices in each segment of a line or ring so that none is longer
+/// than `max_length` in XY. The extra ordinates (Z and M) are linearly interpolated.
+pub fn segmentize_coords(coords: &[f64], dimensions: usize, max_length: f64) -> Vec<f64> {
+    split_segments(
+        coords,
+        dimensions,
+        |start, end| {
+            let length = (end[0] - start[0]).hypot(end[1] - start[1]);
+            ((length / max_length).ceil(), ())
+        },
+        |start, end, (), fraction, result| {
+            let ordinates = start.iter().zip(end.iter());
+            result.extend(ordinates.map(|(from, to)| from + (to - from) * fraction));
+        },
+    )
+}
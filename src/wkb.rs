@@ -1,6 +1,6 @@
 use geos::GeometryTypes;
 use num_enum::{IntoPrimitive, TryFromPrimitive};
-use scroll::{Endian, IOread};
+use scroll::{Endian, IOread, IOwrite, Pread};
 use serde::{Deserialize, Serialize};
 use std::io;
 
@@ -51,6 +51,189 @@ impl TryFrom<&[u8]> for WKBHeader {
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WKBFlavor {
+    Iso,
+    Ewkb,
+}
+
+/// Split a type id of either flavor into the base geometry type id, the Z and M flags
+/// and whether an SRID follows.
+fn split_type_id(type_id: u32) -> (u32, bool, bool, bool) {
+    let iso_type_id = type_id & 0x1FFF_FFFF;
+    let iso_dimensions = iso_type_id / 1000;
+    let has_z = type_id & 0x8000_0000 != 0 || matches!(iso_dimensions, 1 | 3);
+    let has_m = type_id & 0x4000_0000 != 0 || matches!(iso_dimensions, 2 | 3);
+    let has_srid = type_id & 0x2000_0000 != 0;
+    (iso_type_id % 1000, has_z, has_m, has_srid)
+}
+
+/// Describe the encoding of a WKB from its outer header, as `{flavor}_{byte order}`,
+/// e.g. `ewkb_ndr`. Geometries without Z, M or SRID are valid in both flavors and are
+/// reported as ISO.
+pub fn wkb_encoding(wkb: &[u8]) -> Result<&'static str, geos::Error> {
+    let invalid = || geos::Error::GenericError("Invalid WKB Header".into());
+    let is_little_endian = *wkb.first().ok_or_else(invalid)? != 0;
+    let type_id: u32 = wkb
+        .pread_with(1, Endian::from(is_little_endian))
+        .map_err(|_| invalid())?;
+    Ok(match (type_id & 0xE000_0000 != 0, is_little_endian) {
+        (false, false) => "iso_xdr",
+        (false, true) => "iso_ndr",
+        (true, false) => "ewkb_xdr",
+        (true, true) => "ewkb_ndr",
+    })
+}
+
+/// Copy a geometry from `wkb` to `out`, rewriting every header and value with the
+/// given byte order and flavor. The SRID of the outer geometry is kept in EWKB and
+/// dropped in ISO WKB, which cannot encode it.
+fn transcode(
+    wkb: &mut &[u8],
+    out: &mut Vec<u8>,
+    is_little_endian: bool,
+    flavor: WKBFlavor,
+    is_outer: bool,
+) -> Result<(), io::Error> {
+    let endian = Endian::from(is_little_endian);
+    let source_endian = Endian::from(wkb.ioread::<u8>()? != 0);
+    let (type_id, has_z, has_m, has_srid) = split_type_id(wkb.ioread_with(source_endian)?);
+    let srid = match has_srid {
+        true => wkb.ioread_with::<i32>(source_endian)?,
+        false => 0,
+    };
+    let srid = Some(srid).filter(|&srid| is_outer && srid != 0 && flavor == WKBFlavor::Ewkb);
+    let out_type_id = match flavor {
+        WKBFlavor::Iso => type_id + 1000 * u32::from(has_z) + 2000 * u32::from(has_m),
+        WKBFlavor::Ewkb => {
+            type_id
+                | (u32::from(has_z) << 31)
+                | (u32::from(has_m) << 30)
+                | (u32::from(srid.is_some()) << 29)
+        }
+    };
+    out.push(u8::from(is_little_endian));
+    out.iowrite_with(out_type_id, endian)?;
+    if let Some(srid) = srid {
+        out.iowrite_with(srid, endian)?;
+    }
+
+    let dimensions = 2 + usize::from(has_z) + usize::from(has_m);
+    let copy_count = |wkb: &mut &[u8], out: &mut Vec<u8>| {
+        let count = wkb.ioread_with::<u32>(source_endian)?;
+        out.iowrite_with(count, endian)?;
+        Ok::<_, io::Error>(count)
+    };
+    let copy_coords = |wkb: &mut &[u8], out: &mut Vec<u8>, count: usize| {
+        for _ in 0..count * dimensions {
+            out.iowrite_with(wkb.ioread_with::<f64>(source_endian)?, endian)?;
+        }
+        Ok::<_, io::Error>(())
+    };
+    match type_id {
+        1 => copy_coords(wkb, out, 1)?,
+        2 | 8 => {
+            let count = copy_count(wkb, out)?;
+            copy_coords(wkb, out, count as usize)?;
+        }
+        3 | 17 => {
+            for _ in 0..copy_count(wkb, out)? {
+                let count = copy_count(wkb, out)?;
+                copy_coords(wkb, out, count as usize)?;
+            }
+        }
+        4..=7 | 9..=12 | 15 | 16 => {
+            for _ in 0..copy_count(wkb, out)? {
+                transcode(wkb, out, is_little_endian, flavor, false)?;
+            }
+        }
+        _ => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("unsupported geometry type id {type_id}"),
+            ))
+        }
+    }
+    Ok(())
+}
+
+/// Rewrite a WKB of any byte order and flavor with the given byte order and flavor.
+pub fn transcode_wkb(
+    mut wkb: &[u8],
+    is_little_endian: bool,
+    flavor: WKBFlavor,
+) -> Result<Vec<u8>, geos::Error> {
+    let mut out = Vec::with_capacity(wkb.len());
+    transcode(&mut wkb, &mut out, is_little_endian, flavor, true)
+        .map_err(|err| geos::Error::GenericError(format!("Invalid WKB: {err}")))?;
+    Ok(out)
+}
+
+/// Remove the SRID from an EWKB header, leaving the other bytes untouched.
+pub fn strip_ewkb_srid(wkb: &[u8]) -> Result<Vec<u8>, geos::Error> {
+    let invalid = || geos::Error::GenericError("Invalid WKB Header".into());
+    let is_little_endian = *wkb.first().ok_or_else(invalid)? != 0;
+    let type_id: u32 = wkb
+        .pread_with(1, Endian::from(is_little_endian))
+        .map_err(|_| invalid())?;
+    if type_id & 0x2000_0000 == 0 {
+        return Ok(wkb.to_vec());
+    }
+    if wkb.len() < 9 {
+        return Err(invalid());
+    }
+    let type_id = type_id & !0x2000_0000;
+    let type_id = match is_little_endian {
+        true => type_id.to_le_bytes(),
+        false => type_id.to_be_bytes(),
+    };
+    let mut stripped = Vec::with_capacity(wkb.len() - 4);
+    stripped.push(wkb[0]);
+    stripped.extend_from_slice(&type_id);
+    stripped.extend_from_slice(&wkb[9..]);
+    Ok(stripped)
+}
+
+/// Whether a geometry can be known to be valid from its EWKB without parsing it: empty
+/// geometries, and points with finite X and Y. Returns `false` when GEOS is needed to
+/// decide, including when the WKB cannot be read.
+pub fn is_trivially_valid(mut wkb: &[u8]) -> bool {
+    fn read(wkb: &mut &[u8]) -> Result<bool, io::Error> {
+        use WKBGeometryType::*;
+        let byte_order = wkb.ioread::<u8>()?;
+        let endian = Endian::from(byte_order != 0);
+        let type_id = wkb.ioread_with::<u32>(endian)?;
+        if type_id & 0x2000_0000 == 0x2000_0000 {
+            wkb.ioread_with::<i32>(endian)?;
+        }
+        let geometry_type = u8::try_from(type_id & 0x0FFF_FFFF)
+            .ok()
+            .and_then(|type_id| WKBGeometryType::try_from(type_id).ok());
+        match geometry_type {
+            Some(Point) => {
+                let has_z = type_id & 0x8000_0000 != 0;
+                let has_m = type_id & 0x4000_0000 != 0;
+                let dimensions = 2 + usize::from(has_z) + usize::from(has_m);
+                let ordinates = (0..dimensions)
+                    .map(|_| wkb.ioread_with::<f64>(endian))
+                    .collect::<Result<Vec<_>, _>>()?;
+                // Empty points are encoded with NaN ordinates
+                Ok(ordinates[..2].iter().all(|ordinate| ordinate.is_finite())
+                    || ordinates.iter().all(|ordinate| ordinate.is_nan()))
+            }
+            Some(
+                LineString | Polygon | MultiPoint | MultiLineString | MultiPolygon
+                | GeometryCollection | CircularString | CompoundCurve | CurvePolygon | MultiCurve
+                | MultiSurface,
+            ) => Ok(wkb.ioread_with::<u32>(endian)? == 0),
+            _ => Ok(false),
+        }
+    }
+
+    read(&mut wkb).unwrap_or(false)
+}
+
 #[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive, Serialize, Deserialize)]
 #[repr(u8)]
 pub enum WKBGeometryType {
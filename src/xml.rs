@@ -0,0 +1,201 @@
+use geos::{Error as GError, GResult, Geom, GeometryTypes::*};
+
+/// Join the coordinates of a point, line or ring, dropping M. Ordinates are joined by
+/// `separator` and tuples by spaces.
+fn coordinates<T: Geom>(geom: &T, separator: &str) -> GResult<String> {
+    let has_z = geom.has_z()?;
+    let dimensions = 2 + usize::from(has_z) + usize::from(geom.has_m()?);
+    let buffer = geom.get_coord_seq()?.as_buffer(Some(dimensions))?;
+    let tuples: Vec<String> = buffer
+        .chunks_exact(dimensions)
+        .map(|coord| {
+            coord[..2 + usize::from(has_z)]
+                .iter()
+                .map(f64::to_string)
+                .collect::<Vec<_>>()
+                .join(separator)
+        })
+        .collect();
+    Ok(tuples.join(" "))
+}
+
+fn unsupported(format: &str, geometry_type: geos::GeometryTypes) -> GError {
+    GError::GenericError(format!(
+        "Unsupported geometry type for {format}: {geometry_type:?}"
+    ))
+}
+
+/// Serialize a geometry as a KML geometry element.
+pub fn to_kml<T: Geom>(geom: &T) -> GResult<String> {
+    let geometry_type = geom.geometry_type()?;
+    let tag = match geometry_type {
+        Point => "Point",
+        LineString => "LineString",
+        LinearRing => "LinearRing",
+        Polygon => "Polygon",
+        MultiPoint | MultiLineString | MultiPolygon | GeometryCollection => "MultiGeometry",
+        t => return Err(unsupported("KML", t)),
+    };
+    if geom.is_empty()? {
+        return Ok(format!("<{tag}/>"));
+    }
+    let content = match geometry_type {
+        Point | LineString | LinearRing => {
+            format!("<coordinates>{}</coordinates>", coordinates(geom, ",")?)
+        }
+        Polygon => {
+            let exterior = to_kml(&geom.get_exterior_ring()?)?;
+            let interiors = (0..geom.get_num_interior_rings()?)
+                .map(|n| {
+                    let ring = to_kml(&geom.get_interior_ring_n(n)?)?;
+                    Ok(format!("<innerBoundaryIs>{ring}</innerBoundaryIs>"))
+                })
+                .collect::<GResult<String>>()?;
+            format!("<outerBoundaryIs>{exterior}</outerBoundaryIs>{interiors}")
+        }
+        _ => (0..geom.get_num_geometries()?)
+            .map(|n| to_kml(&geom.get_geometry_n(n)?))
+            .collect::<GResult<String>>()?,
+    };
+    Ok(format!("<{tag}>{content}</{tag}>"))
+}
+
+/// Serialize a geometry as a GML 2 or 3 geometry element, with `attributes` appended to
+/// the outermost tag.
+fn gml<T: Geom>(geom: &T, version: u8, attributes: &str) -> GResult<String> {
+    let geometry_type = geom.geometry_type()?;
+    let (tag, member) = match (geometry_type, version) {
+        (Point, _) => ("Point", ""),
+        (LineString, _) => ("LineString", ""),
+        (LinearRing, _) => ("LinearRing", ""),
+        (Polygon, _) => ("Polygon", ""),
+        (MultiPoint, _) => ("MultiPoint", "pointMember"),
+        (MultiLineString, 2) => ("MultiLineString", "lineStringMember"),
+        (MultiLineString, _) => ("MultiCurve", "curveMember"),
+        (MultiPolygon, 2) => ("MultiPolygon", "polygonMember"),
+        (MultiPolygon, _) => ("MultiSurface", "surfaceMember"),
+        (GeometryCollection, _) => ("MultiGeometry", "geometryMember"),
+        (t, _) => return Err(unsupported("GML", t)),
+    };
+    if geom.is_empty()? {
+        return Ok(format!("<gml:{tag}{attributes}/>"));
+    }
+    let dimension = match geom.has_z()? {
+        true => r#" srsDimension="3""#,
+        false => "",
+    };
+    let content = match (geometry_type, version) {
+        (Point | LineString | LinearRing, 2) => {
+            format!(
+                "<gml:coordinates>{}</gml:coordinates>",
+                coordinates(geom, ",")?
+            )
+        }
+        (Point, _) => format!("<gml:pos{dimension}>{}</gml:pos>", coordinates(geom, " ")?),
+        (LineString | LinearRing, _) => format!(
+            "<gml:posList{dimension}>{}</gml:posList>",
+            coordinates(geom, " ")?
+        ),
+        (Polygon, _) => {
+            let (outer, inner) = match version {
+                2 => ("outerBoundaryIs", "innerBoundaryIs"),
+                _ => ("exterior", "interior"),
+            };
+            let exterior = gml(&geom.get_exterior_ring()?, version, "")?;
+            let interiors = (0..geom.get_num_interior_rings()?)
+                .map(|n| {
+                    let ring = gml(&geom.get_interior_ring_n(n)?, version, "")?;
+                    Ok(format!("<gml:{inner}>{ring}</gml:{inner}>"))
+                })
+                .collect::<GResult<String>>()?;
+            format!("<gml:{outer}>{exterior}</gml:{outer}>{interiors}")
+        }
+        _ => (0..geom.get_num_geometries()?)
+            .map(|n| {
+                let part = gml(&geom.get_geometry_n(n)?, version, "")?;
+                Ok(format!("<gml:{member}>{part}</gml:{member}>"))
+            })
+            .collect::<GResult<String>>()?,
+    };
+    Ok(format!("<gml:{tag}{attributes}>{content}</gml:{tag}>"))
+}
+
+/// Serialize a geometry as a GML geometry element, with its SRID as `srsName`.
+pub fn to_gml<T: Geom>(geom: &T, version: u8) -> GResult<String> {
+    let attributes = match geom.get_srid()? {
+        0 => String::new(),
+        srid => format!(r#" srsName="EPSG:{srid}""#),
+    };
+    gml(geom, version, &attributes)
+}
+
+/// Return the XY coordinates of a point, line or ring with Y flipped, rounded by `factor`
+/// if given.
+fn svg_coordinates<T: Geom>(geom: &T, factor: Option<f64>) -> GResult<Vec<(f64, f64)>> {
+    let dimensions = 2 + usize::from(geom.has_z()?) + usize::from(geom.has_m()?);
+    let buffer = geom.get_coord_seq()?.as_buffer(Some(dimensions))?;
+    // Adding 0.0 turns -0.0 into 0.0, which would otherwise be written as "-0"
+    let round = |value: f64| match factor {
+        Some(factor) if (value * factor).is_finite() => (value * factor).round() / factor + 0.0,
+        _ => value + 0.0,
+    };
+    let coords = buffer
+        .chunks_exact(dimensions)
+        .map(|coord| (round(coord[0]), round(-coord[1])))
+        .collect();
+    Ok(coords)
+}
+
+fn svg_points(coords: &[(f64, f64)]) -> String {
+    let points: Vec<String> = coords.iter().map(|(x, y)| format!("{x},{y}")).collect();
+    points.join(" ")
+}
+
+fn svg_ring<T: Geom>(ring: &T, factor: Option<f64>) -> GResult<String> {
+    let coords = svg_coordinates(ring, factor)?;
+    Ok(match coords.split_first() {
+        Some(((x, y), rest)) => format!("M {x},{y} L {} Z", svg_points(rest)),
+        None => String::new(),
+    })
+}
+
+fn svg<T: Geom>(geom: &T, factor: Option<f64>) -> GResult<String> {
+    if geom.is_empty()? {
+        return Ok("<g/>".into());
+    }
+    match geom.geometry_type()? {
+        Point => {
+            let (x, y) = svg_coordinates(geom, factor)?[0];
+            Ok(format!(r#"<circle cx="{x}" cy="{y}" r="1"/>"#))
+        }
+        LineString | LinearRing => {
+            let points = svg_points(&svg_coordinates(geom, factor)?);
+            Ok(format!(r#"<polyline fill="none" points="{points}"/>"#))
+        }
+        Polygon => {
+            let mut rings = vec![svg_ring(&geom.get_exterior_ring()?, factor)?];
+            for n in 0..geom.get_num_interior_rings()? {
+                rings.push(svg_ring(&geom.get_interior_ring_n(n)?, factor)?);
+            }
+            Ok(format!(
+                r#"<path fill-rule="evenodd" d="{}"/>"#,
+                rings.join(" ")
+            ))
+        }
+        MultiPoint | MultiLineString | MultiPolygon | GeometryCollection => {
+            let parts = (0..geom.get_num_geometries()?)
+                .map(|n| svg(&geom.get_geometry_n(n)?, factor))
+                .collect::<GResult<String>>()?;
+            Ok(format!("<g>{parts}</g>"))
+        }
+        t => Err(unsupported("SVG", t)),
+    }
+}
+
+/// Serialize a geometry as an SVG fragment, with Y flipped so that it renders upright.
+/// Points become circles, lines polylines, and polygons paths using the even-odd fill
+/// rule for holes.
+pub fn to_svg<T: Geom>(geom: &T, rounding_precision: Option<u32>) -> GResult<String> {
+    let factor = rounding_precision.map(|precision| 10f64.powi(precision as i32));
+    svg(geom, factor)
+}
@@ -0,0 +1,44 @@
+"""Benchmark joining one left frame against several right frames.
+
+The spatial index of the left frame is reused across joins, so only the first join pays for
+building it. Alternating between two left frames rebuilds it every time, which gives the
+baseline. Run with `python -m tests.bench_sjoin` against a release build.
+"""
+
+import time
+
+import numpy as np
+
+import polars_st as st
+
+
+def random_points(n: int, seed: int) -> st.GeoDataFrame:
+    coords = np.random.default_rng(seed).uniform(0, 1000, (n, 2))
+    return st.GeoDataFrame({"geometry": coords.tolist()}, geometry_format="point")
+
+
+def random_polygons(n: int, seed: int) -> st.GeoDataFrame:
+    return random_points(n, seed).select(st.geom().st.buffer(1.0))
+
+
+def timed(func) -> float:
+    start = time.perf_counter()
+    func()
+    return time.perf_counter() - start
+
+
+def main() -> None:
+    left = random_points(500_000, 0)
+    other_left = random_points(500_000, 1)
+    rights = [random_polygons(10_000, seed) for seed in range(2, 7)]
+
+    cached = sum(timed(lambda right=right: left.st.sjoin(right)) for right in rights)
+    rebuilt = sum(
+        timed(lambda right=right, i=i: (left if i % 2 else other_left).st.sjoin(right))
+        for i, right in enumerate(rights)
+    )
+    print(f"reused index: {cached:.2f}s, rebuilt index: {rebuilt:.2f}s")  # noqa: T201
+
+
+if __name__ == "__main__":
+    main()
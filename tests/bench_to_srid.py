@@ -0,0 +1,24 @@
+"""Benchmark reprojecting a large column of points from Web Mercator to WGS 84.
+
+Run with `python -m tests.bench_to_srid` against a release build.
+"""
+
+import time
+
+import numpy as np
+
+import polars_st as st
+
+
+def main() -> None:
+    coords = np.random.default_rng(0).uniform(-2e7, 2e7, (1_000_000, 2))
+    gdf = st.GeoDataFrame({"geometry": coords.tolist()}, geometry_format="point")
+    gdf = gdf.select(st.set_srid(srid=3857))
+
+    start = time.perf_counter()
+    gdf.select(st.to_srid(srid=4326))
+    print(f"to_srid 3857 -> 4326: {time.perf_counter() - start:.2f}s")  # noqa: T201
+
+
+if __name__ == "__main__":
+    main()
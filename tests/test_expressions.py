@@ -1,9 +1,11 @@
 # ruff: noqa: E501
 
+import json
+import math
 import warnings
 from collections.abc import Callable
 from dataclasses import dataclass, field
-from typing import Any, Concatenate, ParamSpec
+from typing import Any, Concatenate, Literal, ParamSpec
 
 import numpy as np
 import polars as pl
@@ -92,8 +94,12 @@ functions = [
     Function(Geo.dimensions, pl.Int32()),
     Function(Geo.coordinate_dimension, pl.UInt32()),
     Function(Geo.srid, pl.Int32()),
+    Function(Geo.srid_authority, pl.String()),
     Function(Geo.set_srid, pl.Binary(), {"srid": 3857}),
+    Function(Geo.has_srid, pl.Boolean()),
+    Function(Geo.strip_srid, pl.Binary()),
     Function(Geo.to_srid, pl.Binary(), {"srid": 3857}),
+    Function(Geo.to_crs, pl.Binary(), {"source": "EPSG:4326", "target": "EPSG:3857"}),
     Function(Geo.x, pl.Float64()),
     Function(Geo.y, pl.Float64()),
     Function(Geo.z, pl.Float64()),
@@ -103,6 +109,12 @@ functions = [
     Function(Geo.count_interior_rings, pl.UInt32()),
     Function(Geo.count_geometries, pl.UInt32()),
     Function(Geo.get_point, pl.Binary(), {"index": 0}),
+    Function(Geo.start_point, pl.Binary()),
+    Function(Geo.end_point, pl.Binary()),
+    Function(Geo.set_point, pl.Binary(), {"index": 0, "point": dummy_point}),
+    Function(Geo.add_point, pl.Binary(), {"point": dummy_point}),
+    Function(Geo.add_point, pl.Binary(), {"point": dummy_point, "index": 0}),
+    Function(Geo.remove_point, pl.Binary(), {"index": 0}),
     Function(Geo.get_interior_ring, pl.Binary(), {"index": 0}),
     Function(Geo.get_geometry, pl.Binary(), {"index": 0}),
     Function(Geo.parts, pl.List(pl.Binary())),
@@ -114,18 +126,28 @@ functions = [
     Function(Geo.to_wkt, pl.String()),
     Function(Geo.to_ewkt, pl.String()),
     Function(Geo.to_wkb, pl.Binary()),
+    Function(Geo.wkb_flavor, pl.String()),
+    Function(Geo.normalize_wkb, pl.Binary()),
+    Function(Geo.normalize_wkb, pl.Binary(), {"byte_order": 0, "flavor": "iso"}),
+    Function(Geo.to_wkb_base64, pl.String()),
     Function(Geo.to_geojson, pl.String()),
+    Function(Geo.to_kml, pl.String()),
+    Function(Geo.to_gml, pl.String()),
+    Function(Geo.to_svg, pl.String()),
     Function(Geo.to_dict, pl.Object()),
     Function(Geo.to_shapely, pl.Object()),
     Function(Geo.area, pl.Float64()),
     Function(Geo.bounds, pl.Array(pl.Float64, 4)),
     Function(Geo.length, pl.Float64()),
+    Function(Geo.length_3d, pl.Float64()),
     Function(Geo.distance, pl.Float64(), {"other": dummy_point}),
+    Function(Geo.distance_3d, pl.Float64(), {"other": dummy_point}),
     Function(Geo.hausdorff_distance, pl.Float64(), {"other": dummy_point, "densify": None}),
     Function(Geo.hausdorff_distance, pl.Float64(), {"other": dummy_point, "densify": 0.5}),
     Function(Geo.frechet_distance, pl.Float64(), {"other": dummy_point, "densify": None}),
     Function(Geo.frechet_distance, pl.Float64(), {"other": dummy_point, "densify": 0.5}),
     Function(Geo.minimum_clearance, pl.Float64()),
+    Function(Geo.minimum_bounding_radius, pl.Float64()),
     Function(Geo.has_z, pl.Boolean()),
     Function(Geo.has_m, pl.Boolean()),
     Function(Geo.is_ccw, pl.Boolean()),
@@ -135,14 +157,20 @@ functions = [
     Function(Geo.is_simple, pl.Boolean()),
     Function(Geo.is_valid, pl.Boolean()),
     Function(Geo.is_valid_reason, pl.String()),
+    Function(
+        Geo.is_valid_detail,
+        pl.Struct({"is_valid": pl.Boolean, "reason": pl.String, "location": pl.Binary}),
+    ),
     Function(Geo.crosses, pl.Boolean(), {"other": dummy_point}),
     Function(Geo.contains, pl.Boolean(), {"other": dummy_point}),
+    Function(Geo.contains_points, pl.Boolean(), {"points": dummy_point}),
     Function(Geo.contains_properly, pl.Boolean(), {"other": dummy_point}),
     Function(Geo.covered_by, pl.Boolean(), {"other": dummy_point}),
     Function(Geo.covers, pl.Boolean(), {"other": dummy_point}),
     Function(Geo.disjoint, pl.Boolean(), {"other": dummy_point}),
     Function(Geo.dwithin, pl.Boolean(), {"other": dummy_point, "distance": 1.0}),
     Function(Geo.intersects, pl.Boolean(), {"other": dummy_point}),
+    Function(Geo.intersects_points, pl.Boolean(), {"points": dummy_point}),
     Function(Geo.overlaps, pl.Boolean(), {"other": dummy_point}),
     Function(Geo.touches, pl.Boolean(), {"other": dummy_point}),
     Function(Geo.within, pl.Boolean(), {"other": dummy_point}),
@@ -151,6 +179,11 @@ functions = [
     Function(Geo.equals_identical, pl.Boolean(), {"other": dummy_point}),
     Function(Geo.relate, pl.String(), {"other": dummy_point}),
     Function(Geo.relate_pattern, pl.Boolean(), {"other": dummy_point, "pattern": "*********"}),
+    Function(Geo.adjacent, pl.Boolean(), {"other": dummy_point}),
+    Function(Geo.touches_at_point, pl.Boolean(), {"other": dummy_point}),
+    Function(Geo.interiors_intersect, pl.Boolean(), {"other": dummy_point}),
+    Function(Geo.contains_boundary, pl.Boolean(), {"other": dummy_point}),
+    Function(Geo.equal_boundary, pl.Boolean(), {"other": dummy_point}),
     Function(Geo.difference, pl.Binary(), {"other": dummy_point, "grid_size": None}),
     Function(Geo.difference, pl.Binary(), {"other": dummy_point, "grid_size": 0.5}),
     Function(Geo.intersection, pl.Binary(), {"other": dummy_point, "grid_size": None}),
@@ -164,44 +197,83 @@ functions = [
     Function(Geo.cast, pl.Binary(), {"into": "GeometryCollection"}),
     Function(Geo.multi, pl.Binary()),
     Function(Geo.boundary, pl.Binary()),
+    Function(Geo.boundary, pl.Binary(), {"grid_size": 0.5}),
     Function(Geo.coverage_union, pl.Binary()),
     Function(Geo.buffer, pl.Binary(), {"distance": 1.0}),
+    Function(Geo.buffer, pl.Binary(), {"distance": -1.0, "on_empty": "null"}),
+    Function(Geo.buffer, pl.Binary(), {"distance": 1.0, "output_precision": 0.1}),
+    Function(Geo.buffer_with_columns, pl.Binary(), {"distance": 1.0}),
     Function(Geo.offset_curve, pl.Binary(), {"distance": 1.0}),
+    Function(Geo.offset_curve, pl.Binary(), {"distance": 1.0, "boundary": True}),
+    Function(Geo.offset_curve, pl.Binary(), {"distance": 1.0, "single_sided": True}),
     Function(Geo.centroid, pl.Binary()),
+    Function(Geo.largest_part_centroid, pl.Binary()),
     Function(Geo.center, pl.Binary()),
     Function(Geo.clip_by_rect, pl.Binary(), {"bounds": [0.0, 0.0, 1.0, 1.0]}),
+    Function(Geo.clip_by_rect, pl.Binary(), {"bounds": [0.0, 0.0, 1.0, 1.0], "grid_size": 0.5}),
+    Function(Geo.clip, pl.Binary(), {"mask": dummy_point}),
     Function(Geo.concave_hull, pl.Binary()),
+    Function(Geo.concave_hull_by_length, pl.Binary(), {"max_length": 1.0}),
     Function(Geo.convex_hull, pl.Binary()),
     Function(Geo.segmentize, pl.Binary(), {"max_segment_length": 1.0}),
+    Function(Geo.subdivide, pl.List(pl.Binary()), {"max_vertices": 5}),
     Function(Geo.envelope, pl.Binary()),
     Function(Geo.extract_unique_points, pl.Binary()),
+    Function(Geo.extract_unique_points, pl.Binary(), {"tolerance": 0.1}),
     Function(Geo.build_area, pl.Binary()),
     Function(Geo.make_valid, pl.Binary()),
+    Function(Geo.make_valid, pl.Binary(), {"method": "structure", "keep_collapsed": False}),
     Function(Geo.normalize, pl.Binary()),
+    Function(Geo.geometry_hash, pl.UInt64()),
     Function(Geo.node, pl.Binary()),
+    Function(Geo.node_prec, pl.Binary(), {"grid_size": 1.0}),
     Function(Geo.point_on_surface, pl.Binary()),
+    Function(Geo.points_on_surface, pl.List(pl.Binary())),
+    Function(Geo.orient, pl.Binary()),
+    Function(Geo.orient, pl.Binary(), {"exterior_cw": True}),
+    Function(Geo.remove_holes, pl.Binary()),
     Function(Geo.remove_repeated_points, pl.Binary()),
     Function(Geo.reverse, pl.Binary()),
+    Function(Geo.reverse, pl.Binary(), {"lines_only": True}),
     Function(Geo.snap, pl.Binary(), {"other": dummy_point, "tolerance": 1.0}),
+    Function(Geo.snap, pl.Binary(), {"other": dummy_point, "tolerance": 1.0, "grid_size": 0.5}),
     Function(Geo.simplify, pl.Binary(), {"tolerance": 1.0, "preserve_topology": False}),
     Function(Geo.simplify, pl.Binary(), {"tolerance": 1.0, "preserve_topology": True}),
+    Function(Geo.simplify, pl.Binary(), {"tolerance": 1.0, "preserve_endpoints": True}),
+    Function(Geo.simplify_vw, pl.Binary(), {"tolerance": 1.0}),
+    Function(Geo.force_3d, pl.Binary()),
+    Function(Geo.force_3d, pl.Binary(), {"fill_nan": True}),
     Function(Geo.flip_coordinates, pl.Binary()),
     Function(Geo.minimum_rotated_rectangle, pl.Binary()),
+    Function(Geo.minimum_width, pl.Binary()),
+    Function(Geo.minimum_clearance_line, pl.Binary()),
+    Function(Geo.affine_transform, pl.Binary(), {"matrix": [1, 0, 0, 1, 0, 0]}),
     Function(Geo.translate, pl.Binary()),
     Function(Geo.rotate, pl.Binary(), {"angle": 90}),
     Function(Geo.scale, pl.Binary()),
     Function(Geo.skew, pl.Binary()),
+    Function(Geo.snap_to_grid, pl.Binary(), {"size_x": 1.0}),
     Function(Geo.interpolate, pl.Binary(), {"distance": 1.0, "normalized": False}),
     Function(Geo.interpolate, pl.Binary(), {"distance": 1.0, "normalized": True}),
     Function(Geo.project, pl.Float64(), {"other": dummy_point, "normalized": False}),
     Function(Geo.project, pl.Float64(), {"other": dummy_point, "normalized": True}),
     Function(Geo.substring, pl.Binary(), {"start": 0.0, "end": 0.0}),
+    Function(Geo.substring, pl.Binary(), {"start": 2.0, "end": -1.0, "normalized": False}),
     Function(Geo.line_merge, pl.Binary(), {"directed": True}),
     Function(Geo.line_merge, pl.Binary(), {"directed": False}),
+    Function(Geo.line_merge, pl.Binary(), {"directed": False, "tolerance": 0.1}),
     Function(Geo.shared_paths, pl.Binary(), {"other": dummy_line}),
+    Function(Geo.split, pl.Binary(), {"blade": dummy_line}),
     Function(Geo.shortest_line, pl.Binary(), {"other": dummy_point}),
     Function(Geo.count_coordinates, pl.UInt32()),
+    Function(Geo.num_vertices, pl.UInt32()),
+    Function(Geo.count_coordinates_per_part, pl.List(pl.UInt32())),
     Function(Geo.coordinates, pl.List(pl.List(pl.Float64))),
+    Function(Geo.coordinates, pl.List(pl.List(pl.List(pl.List(pl.Float64)))), {"structured": True}),
+    Function(
+        Geo.coordinates_struct,
+        pl.List(pl.Struct({"x": pl.Float64, "y": pl.Float64, "z": pl.Float64, "m": pl.Float64})),
+    ),
 ]
 
 
@@ -215,6 +287,7 @@ class Aggregate:
 aggregates = [
     Aggregate(Geo.voronoi_polygons, pl.Binary(), collection_empty.item()),
     Aggregate(Geo.delaunay_triangles, pl.Binary(), collection_empty.item()),
+    Aggregate(Geo.constrained_delaunay_all, pl.Binary(), collection_empty.item()),
     Aggregate(Geo.polygonize, pl.Binary(), collection_empty.item()),
     Aggregate(Geo.total_bounds, pl.Array(pl.Float64(), 4), np.full(4, np.nan)),
     Aggregate(Geo.intersection_all, pl.Binary(), collection_empty.item()),
@@ -342,6 +415,20 @@ def test_functions_all_types_frame(frame: pl.DataFrame, func: Function):  # noqa
     if func.call == Geo.get_interior_ring and geom_type not in {"Polygon", "CurvePolygon"}:
         error = "IllegalArgumentException: Argument is not a Surface"
 
+    if (
+        func.call == Geo.buffer
+        and func.args["distance"] < 0
+        and not geom_empty
+        and (
+            geom_type not in {"Polygon", "MultiPolygon", "GeometryCollection"}
+            or frame is collection_2d
+        )
+    ):
+        error = "Negative buffer distance requires a polygonal geometry"
+
+    if func.call in {Geo.set_point, Geo.add_point, Geo.remove_point} and geom_type != "LineString":
+        error = "Geometry must be a LineString"
+
     if func.call == Geo.get_point and geom_type not in {"LineString"}:
         error = "IllegalArgumentException: Argument is not a SimpleCurve"
 
@@ -396,3 +483,1741 @@ def test_functions_all_types_frame(frame: pl.DataFrame, func: Function):  # noqa
         result = frame.select(func())
 
     assert result.schema == pl.Schema([("geometry", func.dtype)])
+
+
+def test_unique_geometries():
+    """Geometries equal after normalization should be deduplicated."""
+    gdf = st.GeoDataFrame([
+        "LINESTRING (0 0, 1 1)",
+        None,
+        "POINT (0 0)",
+        "LINESTRING (1 1, 0 0)",
+        "POINT (0.1 0)",
+    ])
+    result = gdf.select(st.geom().st.unique_geometries()).unnest("geometry")
+    assert result.get_column("index").to_list() == [0, 2, 4]
+    result = gdf.select(st.geom().st.unique_geometries(grid_size=1.0)).unnest("geometry")
+    assert result.get_column("index").to_list() == [0, 2]
+
+
+def test_geometry_hash():
+    """Geometries equal after normalization should share the same hash."""
+    gdf = st.GeoDataFrame([
+        "POLYGON ((0 0, 1 0, 1 1, 0 0))",
+        "POLYGON ((1 1, 0 0, 1 0, 1 1))",
+        "POLYGON ((0 0, 1 1, 1 0, 0 0))",
+        "POINT (0 0)",
+        None,
+    ])
+    hashes = gdf.select(st.geom().st.geometry_hash()).to_series().to_list()
+    assert hashes[0] == hashes[1] == hashes[2]
+    assert hashes[3] != hashes[0]
+    assert hashes[4] is None
+    little_endian = gdf.select(st.geom().st.to_wkb(byte_order=1)).to_series()
+    big_endian = gdf.select(st.geom().st.to_wkb(byte_order=0)).to_series()
+    assert (
+        little_endian.st.geometry_hash().to_list() == big_endian.st.geometry_hash().to_list()
+    )
+
+
+def test_box():
+    df = pl.DataFrame({
+        "xmin": [0.0, 3.0, 1.0, 1.0, None],
+        "ymin": [0.0, 2.0, 0.0, 2.0, 0.0],
+        "xmax": [1.0, 1.0, 1.0, 1.0, 1.0],
+        "ymax": [2.0, 0.0, 5.0, 2.0, 1.0],
+    })
+    bounds = [pl.col("xmin"), pl.col("ymin"), pl.col("xmax"), pl.col("ymax")]
+    result = df.select(st.box(*bounds, srid=4326).alias("geometry"))
+    assert result.select(st.to_wkt()).to_series().to_list() == [
+        "POLYGON ((0 0, 1 0, 1 2, 0 2, 0 0))",
+        "POLYGON ((1 0, 3 0, 3 2, 1 2, 1 0))",
+        "LINESTRING (1 0, 1 5)",
+        "POINT (1 2)",
+        None,
+    ]
+    assert result.select(st.srid()).to_series().to_list() == [4326] * 4 + [None]
+
+
+def test_explode():
+    """Parts should keep the index of their source row."""
+    gdf = st.GeoDataFrame([
+        "MULTIPOINT ((0 0), (1 1))",
+        None,
+        "GEOMETRYCOLLECTION EMPTY",
+        "POINT (2 2)",
+    ])
+    result = gdf.select(st.geom().st.explode()).unnest("geometry")
+    assert result.get_column("index").to_list() == [0, 0, 1, 3]
+    assert result.get_column("geometry").is_null().to_list() == [False, False, True, False]
+
+
+def test_buffer_negative():
+    """Negative buffers should honor on_empty when a geometry is fully eroded."""
+    gdf = st.GeoDataFrame([
+        "POLYGON ((0 0, 1 0, 1 1, 0 1, 0 0))",
+        "POLYGON ((0 0, 9 0, 9 9, 0 9, 0 0))",
+    ])
+    result = gdf.select(st.geom().st.buffer(-1).st.is_empty())
+    assert result.to_series().to_list() == [True, False]
+    result = gdf.select(st.geom().st.buffer(-1, on_empty="null").is_null())
+    assert result.to_series().to_list() == [True, False]
+    result = gdf.select(st.geom().st.buffer(-1, on_empty="original").st.equals(st.geom()))
+    assert result.to_series().to_list() == [True, False]
+
+
+def test_buffer_output_precision():
+    gdf = st.GeoDataFrame(["POINT (0 0)"])
+    result = gdf.select(st.geom().st.buffer(1.3, quad_segs=1, output_precision=0.5))
+    coordinates = result.select(st.coordinates()).to_series().explode().to_list()
+    assert {tuple(coord) for coord in coordinates} == {(1.5, 0), (0, 1.5), (-1.5, 0), (0, -1.5)}
+    assert result.select(st.area()).item() == pytest.approx(4.5)
+    result = gdf.select(st.geom().st.buffer(1.3, quad_segs=1).st.area())
+    assert result.item() == pytest.approx(3.38)
+
+
+def test_offset_curve_non_linear():
+    """Offset curves of points and polygons should be null unless offsetting the boundary."""
+    gdf = st.GeoDataFrame([
+        "POINT (0 0)",
+        "LINESTRING (0 0, 1 0)",
+        "POLYGON ((0 0, 1 0, 1 1, 0 0))",
+    ])
+    result = gdf.select(st.geom().st.offset_curve(1).is_null())
+    assert result.to_series().to_list() == [True, False, True]
+    result = gdf.select(st.geom().st.offset_curve(1, boundary=True).is_null())
+    assert result.to_series().to_list() == [True, False, False]
+
+
+def test_remove_holes():
+    """Holes should be removed from every member of a multipolygon."""
+    gdf = st.GeoDataFrame([
+        "MULTIPOLYGON (((0 0, 4 0, 4 4, 0 4, 0 0), (1 1, 2 1, 2 2, 1 1)), ((5 5, 6 5, 6 6, 5 5)))",
+        "LINESTRING (0 0, 1 1)",
+    ])
+    result = gdf.select(st.geom().st.remove_holes().st.to_wkt())
+    assert result.to_series().to_list() == [
+        "MULTIPOLYGON (((0 0, 4 0, 4 4, 0 4, 0 0)), ((5 5, 6 5, 6 6, 5 5)))",
+        "LINESTRING (0 0, 1 1)",
+    ]
+
+
+def test_is_ccw_polygon():
+    """Polygons should be tested on their exterior ring, regardless of their holes."""
+    gdf = st.GeoDataFrame([
+        "POLYGON ((0 0, 1 0, 1 1, 0 1, 0 0))",
+        "POLYGON ((0 0, 0 1, 1 1, 1 0, 0 0))",
+        "POLYGON ((0 0, 0 4, 4 4, 4 0, 0 0), (1 1, 2 1, 2 2, 1 1))",
+        "POLYGON EMPTY",
+    ])
+    result = gdf.select(st.is_ccw()).to_series().to_list()
+    assert result == [True, False, False, False]
+
+
+@pytest.mark.parametrize("exterior_cw", [False, True])
+def test_orient(exterior_cw: bool):
+    """Exterior rings should follow `exterior_cw` and interior rings the opposite."""
+    gdf = st.GeoDataFrame([
+        "MULTIPOLYGON (((0 0, 0 4, 4 4, 4 0, 0 0), (1 1, 2 1, 2 2, 1 1)), ((5 5, 6 5, 6 6, 5 5)))",
+        "LINESTRING (0 0, 1 1)",
+    ])
+    oriented = gdf.select(st.orient(exterior_cw=exterior_cw)).to_series()
+    polygons = oriented.head(1).st.parts().explode()
+    assert polygons.st.is_ccw().to_list() == [not exterior_cw, not exterior_cw]
+    holes = polygons.head(1).st.interior_rings().explode()
+    assert holes.st.is_ccw().to_list() == [exterior_cw]
+    assert oriented.st.to_wkt()[1] == "LINESTRING (0 0, 1 1)"
+
+
+def test_nearest_point_in_set():
+    """Each geometry should be linked to its nearest candidate."""
+    gdf = st.GeoDataFrame(["POINT (0 1)", None, "POINT (4 4)", "LINESTRING (9 0, 9 9)"])
+    candidates = st.GeoSeries(["POINT (0 0)", "POINT (10 10)", "POINT (5 5)", "POINT (3 3)"])
+    result = gdf.select(st.geom().st.nearest_point_in_set(pl.lit(candidates))).unnest("geometry")
+    assert result.get_column("index").to_list() == [0, None, 2, 1]
+    assert result.select(st.length()).to_series().to_list()[:2] == [1.0, None]
+
+
+def test_geometry_type_names():
+    """Geometry types should be returned by name."""
+    gdf = st.GeoDataFrame(["POINT (0 0)", "MULTIPOLYGON EMPTY", "GEOMETRYCOLLECTION EMPTY", None])
+    result = gdf.select(st.geometry_type().cast(pl.String)).to_series().to_list()
+    assert result == ["Point", "MultiPolygon", "GeometryCollection", None]
+
+
+def test_named_relate_predicates():
+    """Named DE-9IM predicates should match their documented masks."""
+    gdf = st.GeoDataFrame([
+        "POLYGON ((1 0, 2 0, 2 1, 1 1, 1 0))",
+        "POLYGON ((1 1, 2 1, 2 2, 1 2, 1 1))",
+        "POLYGON ((0.5 0, 1.5 0, 1.5 1, 0.5 1, 0.5 0))",
+        "LINESTRING (0.2 0.2, 0.8 0.8)",
+    ])
+    square = st.from_wkt(pl.lit("POLYGON ((0 0, 1 0, 1 1, 0 1, 0 0))"))
+    result = gdf.select(
+        adjacent=st.geom().st.adjacent(square),
+        touches_at_point=st.geom().st.touches_at_point(square),
+        interiors_intersect=st.geom().st.interiors_intersect(square),
+        contains_boundary=square.st.contains_boundary(st.geom()),
+    )
+    assert result.to_dict(as_series=False) == {
+        "adjacent": [True, False, False, False],
+        "touches_at_point": [False, True, False, False],
+        "interiors_intersect": [False, False, True, True],
+        "contains_boundary": [False, False, False, True],
+    }
+
+
+def test_equal_boundary():
+    gdf = st.GeoDataFrame({
+        "geometry": [
+            "POLYGON ((0 0, 1 0, 1 1, 0 1, 0 0))",
+            "POLYGON ((0 0, 1 0, 1 1, 0 1, 0 0))",
+            "POLYGON ((0 0, 1 0, 1 1, 0 1, 0 0))",
+            "LINESTRING (0 0, 1 1)",
+            None,
+        ],
+        "other": [
+            "POLYGON ((1 1, 0 1, 0 0, 1 0, 1 1))",
+            "LINESTRING (0 0, 1 0, 1 1, 0 1, 0 0)",
+            "POLYGON ((0 0, 1 0, 1 1, 0 1, 0 0), (0.2 0.2, 0.8 0.2, 0.8 0.8, 0.2 0.2))",
+            "LINESTRING (0 0, 0.5 3, 1 1)",
+            "POINT (0 0)",
+        ],
+    })
+    result = gdf.select(st.geom().st.equal_boundary(st.from_wkt(pl.col("other"))))
+    assert result.to_series().to_list() == [True, False, False, True, None]
+
+
+def test_scalar_predicates():
+    """Predicates against a scalar should match the row-wise results on both sides."""
+    gdf = st.GeoDataFrame([
+        "POINT (0.5 0.5)",
+        "POLYGON ((0 0, 2 0, 2 2, 0 2, 0 0))",
+        "POINT (5 5)",
+        "POINT EMPTY",
+        None,
+    ])
+    square = st.from_wkt(pl.lit("POLYGON ((0 0, 1 0, 1 1, 0 1, 0 0))"))
+    result = gdf.select(
+        intersects=st.geom().st.intersects(square),
+        contains=st.geom().st.contains(square),
+        within=st.geom().st.within(square),
+        contained=square.st.contains(st.geom()),
+        contains_null=st.geom().st.contains(pl.lit(None, pl.Binary)),
+    )
+    assert result.to_dict(as_series=False) == {
+        "intersects": [True, True, False, False, None],
+        "contains": [False, True, False, False, None],
+        "within": [True, False, False, False, None],
+        "contained": [True, False, False, False, None],
+        "contains_null": [None] * 5,
+    }
+
+
+def test_points_predicates():
+    """Repeated polygons should give the same results as the row-wise predicates."""
+    square = "POLYGON ((0 0, 2 0, 2 2, 0 2, 0 0))"
+    gdf = st.GeoDataFrame({
+        "polygon": [square, square, "POLYGON ((5 5, 6 5, 6 6, 5 5))", square, None],
+        "point": ["POINT (1 1)", "POINT (2 1)", "POINT (5.5 5.2)", None, "POINT (1 1)"],
+    }, geometry_name="polygon").with_columns(st.from_wkt("point"))
+    result = gdf.select(
+        contains=st.geom("polygon").st.contains_points(st.geom("point")),
+        intersects=st.geom("polygon").st.intersects_points(st.geom("point")),
+        expected_contains=st.geom("polygon").st.contains(st.geom("point")),
+        expected_intersects=st.geom("polygon").st.intersects(st.geom("point")),
+        scalar=st.from_wkt(pl.lit(square)).st.contains_points(st.geom("point")),
+    )
+    assert result["contains"].to_list() == [True, False, True, None, None]
+    assert result["intersects"].to_list() == [True, True, True, None, None]
+    assert result["contains"].equals(result["expected_contains"])
+    assert result["intersects"].equals(result["expected_intersects"])
+    assert result["scalar"].to_list() == [True, False, False, None, True]
+
+
+def test_dump_points():
+    """Vertices should keep their row, part and ring indices, and their Z coordinate."""
+    gdf = st.GeoDataFrame([
+        "MULTIPOLYGON (((0 0, 4 0, 0 4, 0 0), (1 1, 2 1, 1 2, 1 1)), ((5 5, 6 5, 5 6, 5 5)))",
+        None,
+        "POINT EMPTY",
+        "LINESTRING Z (0 0 1, 1 1 2)",
+    ])
+    result = gdf.select(st.geom().st.dump_points()).unnest("geometry")
+    assert result.get_column("index").to_list() == [0] * 12 + [1, 3, 3]
+    assert result.get_column("part").to_list() == [0] * 8 + [1] * 4 + [None, 0, 0]
+    assert result.get_column("ring").to_list() == [0] * 4 + [1] * 4 + [0] * 4 + [None, 0, 0]
+    assert result.select(st.z()).to_series().to_list()[-2:] == [1.0, 2.0]
+
+
+def test_edit_points():
+    """Out of range indices should return null, or raise in strict mode."""
+    gdf = st.GeoDataFrame(["LINESTRING Z (0 0 0, 1 1 1)"])
+    point = st.point(pl.lit([5.0, 5.0]))
+    assert gdf.select(st.geom().st.set_point(2, point)).item() is None
+    assert gdf.select(st.geom().st.add_point(point, 3)).item() is None
+    assert gdf.select(st.geom().st.remove_point(0)).item() is None
+    with pytest.raises(pl.exceptions.ComputeError, match="Point index out of range"):
+        gdf.select(st.geom().st.set_point(2, point, strict=True))
+    result = gdf.select(st.geom().st.add_point(point, 1).st.get_point(1).st.x()).item()
+    assert result == 5.0
+
+
+def test_edit_points_ordinates():
+    """Point Z and M values should be matched by name, not by position."""
+    gdf = st.GeoDataFrame(["LINESTRING Z (0 0 0, 1 1 1)", "LINESTRING M (0 0 0, 1 1 1)"])
+    point_m = st.from_wkt(pl.lit("POINT M (5 5 9)"))
+    point_z = st.from_wkt(pl.lit("POINT Z (5 5 7)"))
+    point_zm = st.from_wkt(pl.lit("POINT ZM (5 5 7 9)"))
+    result = gdf.select(
+        m_z=st.geom().st.set_point(0, point_m).st.get_point(0).st.z(),
+        m_m=st.geom().st.set_point(0, point_m).st.get_point(0).st.m(),
+        z_z=st.geom().st.add_point(point_z, 0).st.get_point(0).st.z(),
+        z_m=st.geom().st.add_point(point_z, 0).st.get_point(0).st.m(),
+        zm_z=st.geom().st.set_point(1, point_zm).st.get_point(1).st.z(),
+        zm_m=st.geom().st.set_point(1, point_zm).st.get_point(1).st.m(),
+    )
+    assert math.isnan(result["m_z"][0])
+    assert result["m_m"][1] == 9.0
+    assert result["z_z"][0] == 7.0
+    assert math.isnan(result["z_m"][1])
+    assert result["zm_z"][0] == 7.0
+    assert result["zm_m"][1] == 9.0
+
+
+def test_minimum_bounding_radius():
+    """The radius should match the circle defined by two or three of the points."""
+    ring = ", ".join(
+        f"{10 + 5 * math.cos(i * math.pi / 32)} {-3 + 5 * math.sin(i * math.pi / 32)}"
+        for i in range(64)
+    )
+    gdf = st.GeoDataFrame([
+        "POLYGON ((0 0, 6 0, 3 4, 0 0))",
+        "MULTIPOINT ((0 0), (10 0), (5 1), (4 -1))",
+        f"MULTIPOINT ({ring})",
+        "POINT EMPTY",
+    ])
+    result = gdf.select(st.minimum_bounding_radius()).to_series().to_list()
+    assert result[:3] == pytest.approx([3.125, 5.0, 5.0])
+    assert math.isnan(result[3])
+
+
+def test_snap_to_grid():
+    """X, Y and Z should be snapped to the grid while M is left untouched."""
+    gdf = st.GeoDataFrame([
+        "POINT (0.4 1.6)",
+        "LINESTRING Z (0.4 1.6 0.3, 2.6 3.3 0.8)",
+        "LINESTRING M (0.4 1.6 0.3, 2.6 3.3 0.8)",
+        "MULTIPOINT ZM ((0.4 1.6 0.3 0.3))",
+        "POLYGON EMPTY",
+    ])
+    result = gdf.select(st.snap_to_grid(size_x=1.0, size_z=0.5, origin_x=0.5).st.to_wkt())
+    assert result.to_series().to_list() == [
+        "POINT (0.5 2)",
+        "LINESTRING Z (0.5 2 0.5, 2.5 3 1)",
+        "LINESTRING M (0.5 2 0.3, 2.5 3 0.8)",
+        "MULTIPOINT ZM ((0.5 2 0.5 0.3))",
+        "POLYGON EMPTY",
+    ]
+
+
+def test_concave_hull_degenerate():
+    """Concave hull of degenerate inputs should fall back to the convex hull."""
+    gdf = st.GeoDataFrame([
+        "POINT (0 0)",
+        "MULTIPOINT ((0 0), (1 1))",
+        "MULTIPOINT ((0 0), (1 1), (2 2))",
+    ])
+    result = gdf.select(st.concave_hull().st.to_wkt()).to_series().to_list()
+    assert result == ["POINT (0 0)", "LINESTRING (0 0, 1 1)", "LINESTRING (0 0, 2 2)"]
+
+
+def test_from_ewkt_invalid_prefix():
+    """Malformed SRID prefixes should raise a clear error."""
+    with pytest.raises(pl.exceptions.ComputeError, match="missing `;` after the SRID prefix"):
+        pl.select(st.from_ewkt(pl.lit("SRID=4326 POINT (0 0)")))
+    with pytest.raises(pl.exceptions.ComputeError, match="invalid SRID `abc`"):
+        pl.select(st.from_ewkt(pl.lit("SRID=abc;POINT (0 0)")))
+
+
+def test_from_srid():
+    wkt = pl.lit("POINT (0 0)")
+    ewkt = pl.lit("SRID=3857;POINT (0 0)")
+    geojson = pl.lit('{"type": "Point", "coordinates": [0, 0]}')
+    df = pl.select(
+        wkt=st.from_wkt(wkt, srid=4326),
+        ewkt_default=st.from_ewkt(ewkt),
+        ewkt_override=st.from_ewkt(ewkt, srid=4326),
+        geojson=st.from_geojson(geojson, srid=4326),
+    )
+    df = df.with_columns(
+        wkb_default=st.from_wkb(st.geom("ewkt_default")),
+        wkb_override=st.from_wkb(st.geom("ewkt_default"), srid=4326),
+    )
+    assert df.select(pl.all().st.srid()).row(0) == (4326, 3857, 4326, 4326, 3857, 4326)
+
+
+@pytest.mark.parametrize("byte_order", [0, 1])
+def test_strip_srid(byte_order: Literal[0, 1]):
+    gdf = st.GeoDataFrame(
+        ["SRID=4326;LINESTRING Z (0 0 1, 1 1 2)", "LINESTRING Z (0 0 1, 1 1 2)", None],
+        geometry_format="ewkt",
+    )
+    gdf = gdf.select(st.to_wkb(byte_order=byte_order, include_srid=True))
+    assert gdf.select(st.has_srid()).to_series().to_list() == [True, False, None]
+    stripped = gdf.select(st.strip_srid()).to_series()
+    assert stripped.st.has_srid().to_list() == [False, False, None]
+    assert stripped.to_list() == [gdf.item(1, 0), gdf.item(1, 0), None]
+
+
+@pytest.mark.parametrize("byte_order", [0, 1])
+def test_srid_authority(byte_order: Literal[0, 1]):
+    gdf = st.GeoDataFrame(
+        [
+            "SRID=4326;POINT (0 0)",
+            "SRID=3857;LINESTRING (0 0, 1 1)",
+            "SRID=1;POINT (0 0)",
+            "POINT (0 0)",
+            None,
+        ],
+        geometry_format="ewkt",
+    )
+    gdf = gdf.select(st.to_wkb(byte_order=byte_order, include_srid=True))
+    result = gdf.select(st.srid_authority()).to_series().to_list()
+    assert result == ["EPSG:4326", "EPSG:3857", None, None, None]
+
+
+def test_constrained_delaunay():
+    polygon = st.from_wkt(pl.lit("POLYGON ((0 0, 2 0, 2 2, 1 1, 0 2, 0 0))"), srid=3857)
+    df = pl.select(
+        triangles=polygon.st.constrained_delaunay(),
+        edges=polygon.st.constrained_delaunay(only_edges=True),
+    )
+    assert df.select(pl.all().st.srid()).row(0) == (3857, 3857)
+    assert df.select(st.geom("triangles").st.count_geometries()).item() == 3
+    assert df.select(st.geom("triangles").st.area()).item() == 3.0
+    assert df.select(st.geom("edges").st.geometry_type()).item() == "MultiLineString"
+    assert df.select(st.geom("edges").st.length()).item() == pytest.approx(6 + 4 * 2**0.5)
+
+
+def test_constrained_delaunay_all():
+    gdf = st.GeoDataFrame({
+        "group": [1, 1, 2],
+        "geometry": [
+            "POLYGON ((0 0, 2 0, 2 2, 1 1, 0 2, 0 0))",
+            "POLYGON ((3 0, 4 0, 4 1, 3 0))",
+            "LINESTRING (0 0, 1 1)",
+        ],
+    })
+    result = gdf.group_by("group").agg(st.constrained_delaunay_all()).sort("group")
+    assert result.select(st.count_geometries()).to_series().to_list() == [4, 0]
+    assert result.select(st.area()).to_series().to_list() == [3.5, 0.0]
+
+
+def test_apply_coordinates():
+    gdf = st.GeoDataFrame([
+        "POINT Z (1 2 3)",
+        None,
+        "POLYGON ((0 0, 1 0, 1 1, 0 0))",
+        "POINT EMPTY",
+    ])
+
+    def function(x, y, z):
+        return x + 1, y * 2, z - 1
+
+    result = gdf.select(st.apply_coordinates(function=function))
+    assert result.select(st.to_wkt()).to_series().to_list() == [
+        "POINT Z (2 4 2)",
+        None,
+        "POLYGON ((1 0, 2 0, 2 2, 1 0))",
+        "POINT EMPTY",
+    ]
+    assert gdf.select(st.apply_coordinates(function=function, vectorized=True)).equals(result)
+
+
+def test_apply_coordinates_multipart():
+    """Holes, empty parts and curves should keep their coordinates in order."""
+    gdf = st.GeoDataFrame([
+        "MULTIPOLYGON (((0 0, 4 0, 0 4, 0 0), (1 1, 2 1, 1 2, 1 1)), ((5 5, 6 5, 5 6, 5 5)))",
+        "GEOMETRYCOLLECTION (POLYGON EMPTY, CIRCULARSTRING (0 0, 1 1, 2 0), POINT (3 3))",
+    ])
+    result = gdf.select(st.apply_coordinates(function=lambda x, y, z: (x + 10, y, z)))
+    assert result.select(st.to_wkt()).to_series().to_list() == [
+        "MULTIPOLYGON (((10 0, 14 0, 10 4, 10 0), (11 1, 12 1, 11 2, 11 1)), "
+        "((15 5, 16 5, 15 6, 15 5)))",
+        "GEOMETRYCOLLECTION (POLYGON EMPTY, CIRCULARSTRING (10 0, 11 1, 12 0), POINT (13 3))",
+    ]
+
+
+def test_apply_coordinates_errors():
+    gdf = st.GeoDataFrame(["POINT (1 2)"])
+    with pytest.raises(pl.exceptions.ComputeError, match="ZeroDivisionError"):
+        gdf.select(st.apply_coordinates(function=lambda x, y, z: (x / 0, y, z)))
+    with pytest.raises(pl.exceptions.ComputeError, match="must return 1 coordinates"):
+        gdf.select(st.apply_coordinates(function=lambda x, y, z: ([], [], []), vectorized=True))
+
+
+def test_build_area_full():
+    gdf = st.GeoDataFrame([
+        "MULTILINESTRING ((0 0, 2 0, 2 2, 0 2, 0 0), (0 0, 2 2), (2 2, 3 3))",
+        "LINESTRING (0 0, 1 0, 1 1)",
+        None,
+    ])
+    result = gdf.select(st.build_area_full()).to_series()
+    assert result.is_null().to_list() == [False, False, True]
+    fields = result.struct.unnest()
+    assert fields.select(st.geom("area").st.area()).to_series().to_list() == [4.0, 0.0, None]
+    remainder = fields.select(st.geom("remainder").st.length()).to_series().to_list()
+    assert remainder[:2] == pytest.approx([3 * 2**0.5, 2.0])
+
+
+def test_node_prec():
+    gdf = st.GeoDataFrame({
+        "geometry": ["MULTILINESTRING ((0 0, 2 0), (1 0.001, 1 1))"] * 3,
+        "grid_size": [0.0001, 0.01, None],
+    })
+    result = gdf.select(st.node_prec(grid_size=pl.col("grid_size")).st.count_geometries())
+    assert result.to_series().to_list() == [2, 3, None]
+
+
+def test_polygonize_full():
+    gdf = st.GeoDataFrame({
+        "group": [1, 1, 1, 2],
+        "geometry": [
+            "LINESTRING (0 0, 1 0, 1 1)",
+            "LINESTRING (1 1, 0 1, 0 0)",
+            "LINESTRING (1 1, 2 2)",
+            "LINESTRING (0 0, 1 1)",
+        ],
+    })
+    result = gdf.group_by("group").agg(st.polygonize_full()).sort("group").unnest("geometry")
+    counts = result.select(pl.exclude("group").st.count_geometries())
+    assert counts.to_dicts() == [
+        {"polygons": 1, "dangles": 1, "cut_edges": 0, "invalid_rings": 0},
+        {"polygons": 0, "dangles": 1, "cut_edges": 0, "invalid_rings": 0},
+    ]
+    assert result.select(st.geom("polygons").st.area()).to_series().to_list() == [1.0, 0.0]
+
+
+def test_voronoi_with_index():
+    gdf = st.GeoDataFrame(["POINT (1 2)", None, "POINT (0 0)", "POINT (2 0)", "POINT (0 0)"])
+    cells = gdf.select(st.geom().st.voronoi_with_index()).unnest("geometry")
+    assert cells["index"].to_list() == [0, 2, 3]
+    seeds = gdf[cells["index"]]
+    assert cells.select(st.geom().st.contains(seeds["geometry"])).to_series().all()
+
+
+def test_voronoi_polygons_ordered():
+    gdf = st.GeoDataFrame(["POINT (2 0)", "POINT (1 2)", "POINT (0 0)", "POINT (1 0.5)"])
+    diagram = gdf.select(st.voronoi_polygons(ordered=True))
+    cells = diagram.select(st.parts()).explode("geometry")
+    assert cells.select(st.geom().st.contains(gdf["geometry"])).to_series().all()
+    with pytest.raises(pl.exceptions.ComputeError, match="cannot be returned as edges"):
+        gdf.select(st.voronoi_polygons(ordered=True, only_edges=True))
+
+
+def test_geohash():
+    gdf = st.GeoDataFrame(["POINT (-5.6 42.6)", "LINESTRING (-5.7 42.6, -5.5 42.6)", None])
+    hashes = gdf.select(st.geohash_encode(precision=5, use_centroid=True)).to_series()
+    assert hashes.to_list() == ["ezs42", "ezs42", None]
+    with pytest.raises(pl.exceptions.ComputeError, match="Geometry must be a Point"):
+        gdf.select(st.geohash_encode())
+    with pytest.raises(pl.exceptions.ComputeError, match="precision must be between 1 and 12"):
+        gdf.select(st.geohash_encode(precision=13))
+    points = pl.select(st.geohash_decode(pl.lit("ezs42e44yx96")))
+    assert points.select(st.srid()).item() == 4326
+    assert points.select(st.x()).item() == pytest.approx(-5.6, abs=1e-6)
+    assert points.select(st.y()).item() == pytest.approx(42.6, abs=1e-6)
+    with pytest.raises(pl.exceptions.ComputeError, match="Invalid geohash"):
+        pl.select(st.geohash_decode(pl.lit("ezs4a")))
+
+
+def test_collect_on_mismatch():
+    gdf = st.GeoDataFrame(["POLYGON ((0 0, 1 0, 1 1, 0 0))", "LINESTRING (0 0, 1 1)", None])
+    with pytest.raises(pl.exceptions.ComputeError, match="LineString into MultiPolygon"):
+        gdf.select(st.collect(into="MultiPolygon"))
+    skipped = gdf.select(st.collect(into="MultiPolygon", on_mismatch="skip"))
+    assert skipped.select(st.geometry_type(), st.count_geometries().alias("n")).row(0) == (
+        "MultiPolygon",
+        1,
+    )
+    wrapped = gdf.select(st.collect(into="MultiPolygon", on_mismatch="wrap"))
+    assert wrapped.select(st.geometry_type(), st.count_geometries().alias("n")).row(0) == (
+        "GeometryCollection",
+        2,
+    )
+
+
+def test_collect_line_and_polygon():
+    gdf = st.GeoDataFrame(["POINT (0 0)", None, "POINT (1 0)", "POINT EMPTY", "POINT (1 1)"])
+    line = gdf.select(st.collect_line().st.to_wkt()).item()
+    assert line == "LINESTRING (0 0, 1 0, 1 1)"
+    polygon = gdf.select(st.collect_polygon().st.to_wkt()).item()
+    assert polygon == "POLYGON ((0 0, 1 0, 1 1, 0 0))"
+    with pytest.raises(pl.exceptions.ComputeError, match="At least two points"):
+        gdf.head(1).select(st.collect_line())
+    with pytest.raises(pl.exceptions.ComputeError, match="Only points can be collected"):
+        st.GeoDataFrame(["LINESTRING (0 0, 1 1)"]).select(st.collect_line())
+
+
+def test_union_all():
+    gdf = st.GeoDataFrame(
+        ["POLYGON ((0 0, 2 0, 2 2, 0 2, 0 0))", None, "POLYGON ((1 0, 3 0, 3 2, 1 2, 1 0))"],
+    ).select(st.set_srid(srid=3857))
+    union = gdf.select(st.union_all())
+    assert union.select(st.geometry_type(), area=st.area(), srid=st.srid()).row(0) == (
+        "Polygon",
+        6.0,
+        3857,
+    )
+    assert gdf.select(st.union_all(grid_size=1.0).st.area()).item() == 6.0
+
+
+def test_intersection_all():
+    gdf = st.GeoDataFrame([
+        "POLYGON ((0 0, 1 0, 1 1, 0 1, 0 0))",
+        "POLYGON ((5 5, 6 5, 6 6, 5 5))",
+        None,
+        "POLYGON ((0 0, 1 0, 1 1, 0 1, 0 0))",
+    ])
+    assert gdf.select(st.intersection_all().st.is_empty()).item()
+    assert gdf.head(1).select(st.intersection_all().st.area()).item() == 1.0
+
+
+def test_h3():
+    gdf = st.GeoDataFrame(["POINT (-122.0553238 37.3615593)", None])
+    cells = gdf.select(st.h3_cell(resolution=7)).to_series()
+    assert cells.to_list() == [0x872830828FFFFFF, None]
+    polygons = pl.select(st.h3_to_polygon(cells))
+    assert polygons.select(st.srid()).to_series().to_list() == [4326, None]
+    assert gdf.select(st.geom().st.within(polygons.to_series())).item()
+    with pytest.raises(pl.exceptions.ComputeError, match="got SRID 3857"):
+        gdf.select(st.set_srid(srid=3857).st.h3_cell(resolution=7))
+    with pytest.raises(pl.exceptions.ComputeError, match="resolution must be between 0 and 15"):
+        gdf.select(st.h3_cell(resolution=16))
+
+
+def test_coordinates_structured():
+    gdf = st.GeoDataFrame([
+        "POLYGON ((0 0, 1 0, 1 1, 0 0), (0.2 0.1, 0.8 0.1, 0.8 0.7, 0.2 0.1))",
+        "GEOMETRYCOLLECTION (POINT (0 0), LINESTRING (0 0, 1 1))",
+        "POINT EMPTY",
+    ])
+    coords = gdf.select(st.coordinates(structured=True)).to_series().to_list()
+    assert coords == [
+        [[[[0, 0], [1, 0], [1, 1], [0, 0]], [[0.2, 0.1], [0.8, 0.1], [0.8, 0.7], [0.2, 0.1]]]],
+        [[[[0, 0]]], [[[0, 0], [1, 1]]]],
+        [],
+    ]
+
+
+def test_minimum_width_and_clearance_line():
+    gdf = st.GeoDataFrame(["POLYGON ((0 0, 4 0, 4 1, 2 0.2, 0 1, 0 0))", "POLYGON EMPTY"])
+    lines = gdf.select(
+        width=st.minimum_width().st.to_wkt(),
+        clearance=st.minimum_clearance_line().st.length(),
+    )
+    assert lines["width"][1] == "LINESTRING EMPTY"
+    assert lines["clearance"].to_list() == [pytest.approx(0.2), 0.0]
+
+
+def test_clip():
+    gdf = st.GeoDataFrame([
+        "POLYGON ((0 0, 2 0, 2 2, 0 2, 0 0))",
+        "POLYGON ((1 0, 2 0, 2 1, 1 1, 1 0))",
+        "GEOMETRYCOLLECTION (POINT (0.5 0.5), LINESTRING (0 0, 2 2), POINT (5 5))",
+        None,
+    ])
+    mask = st.from_wkt(pl.lit("POLYGON ((-1 -1, 1 -1, 1 1, -1 1, -1 -1))"))
+    clipped = gdf.select(st.geom().st.clip(mask))
+    assert clipped.select(st.geometry_type()).to_series().to_list() == [
+        "Polygon",
+        "Polygon",
+        "GeometryCollection",
+        None,
+    ]
+    assert clipped.select(st.area()).to_series().to_list() == [1.0, 0.0, 0.0, None]
+    assert clipped.select(st.count_geometries()).item(2, 0) == 2
+
+
+def test_split():
+    gdf = st.GeoDataFrame([
+        "POLYGON ((0 0, 2 0, 2 2, 0 2, 0 0))",
+        "LINESTRING (0 1, 4 1)",
+    ])
+    blade = st.from_wkt(pl.lit("LINESTRING (1 -1, 1 3)"))
+    pieces = gdf.select(st.geom().st.split(blade)).select(st.parts()).explode("geometry")
+    assert pieces.select(st.geometry_type()).to_series().to_list() == [
+        "Polygon",
+        "Polygon",
+        "LineString",
+        "LineString",
+    ]
+    assert sorted(pieces.head(2).select(st.area()).to_series().to_list()) == [2.0, 2.0]
+    assert sorted(pieces.tail(2).select(st.length()).to_series().to_list()) == [1.0, 3.0]
+
+    line = st.GeoDataFrame(["LINESTRING (0 0, 4 0)"])
+    blade = st.from_wkt(pl.lit("MULTIPOINT ((1 0), (3 0), (5 5))"))
+    pieces = line.select(st.geom().st.split(blade)).select(st.parts()).explode("geometry")
+    assert pieces.select(st.length()).to_series().to_list() == [1.0, 2.0, 1.0]
+
+
+def test_subdivide():
+    vertices = [(0, 0), *((i, 10) for i in range(11)), (10, 0), (0, 0)]
+    ring = ", ".join(f"{x} {y}" for x, y in vertices)
+    gdf = st.GeoDataFrame([f"POLYGON (({ring}))", "POINT (0 0)", "POLYGON EMPTY", None])
+    pieces = gdf.select(st.subdivide(max_vertices=8)).to_series()
+    assert pieces.list.len().to_list()[1:] == [1, 0, None]
+    assert pieces.list.len().item(0) > 1
+    polygon_pieces = pieces.head(1).explode().to_frame()
+    assert polygon_pieces.select(st.count_coordinates().max()).item() <= 8
+    assert polygon_pieces.select(st.area().sum()).item() == pytest.approx(100.0)
+    with pytest.raises(pl.exceptions.ComputeError, match="max_vertices must be at least 5"):
+        gdf.select(st.subdivide(max_vertices=4))
+
+
+def test_shared_paths_direction():
+    gdf = st.GeoDataFrame(["LINESTRING (0 0, 4 0, 4 4)"])
+    other = st.from_wkt(pl.lit("MULTILINESTRING ((0 0, 1 0), (4 4, 4 2))"))
+    result = gdf.select(
+        both=st.geom().st.shared_paths(other).st.geometry_type().cast(pl.String),
+        forward=st.geom().st.shared_paths(other, direction="forward").st.length(),
+        backward=st.geom().st.shared_paths(other, direction="backward").st.length(),
+    )
+    assert result.to_dict(as_series=False) == {
+        "both": ["GeometryCollection"],
+        "forward": [1.0],
+        "backward": [2.0],
+    }
+
+
+def test_simplify_preserve_endpoints():
+    gdf = st.GeoDataFrame([
+        "POLYGON ((5 0, 10 0, 10 10, 0 10, 0 0, 5 0))",
+        "LINESTRING M (0 0 1, 5 0.1 2, 10 0 3)",
+    ])
+    pinned = gdf.select(st.simplify(1.0, preserve_endpoints=True).st.to_wkt()).to_series()
+    assert pinned.to_list() == [
+        "POLYGON ((5 0, 10 0, 10 10, 0 10, 0 0, 5 0))",
+        "LINESTRING M (0 0 1, 10 0 3)",
+    ]
+    # The ring start vertex lies on a straight edge and is dropped without pinning
+    unpinned = gdf.select(st.simplify(1.0).st.to_wkt()).to_series()
+    assert unpinned[0] != pinned[0]
+
+
+def test_segmentize():
+    gdf = st.GeoDataFrame([
+        "LINESTRING Z (0 0 0, 3 0 6, 3 0.5 7)",
+        "POLYGON ((0 0, 2 0, 2 2, 0 2, 0 0))",
+        "POINT (0 0)",
+    ])
+    result = gdf.select(st.segmentize(max_segment_length=1.0).st.to_wkt()).to_series()
+    assert result.to_list() == [
+        "LINESTRING Z (0 0 0, 1 0 2, 2 0 4, 3 0 6, 3 0.5 7)",
+        "POLYGON ((0 0, 1 0, 2 0, 2 1, 2 2, 1 2, 0 2, 0 1, 0 0))",
+        "POINT (0 0)",
+    ]
+    with pytest.raises(pl.exceptions.ComputeError, match="must be greater than 0"):
+        gdf.select(st.segmentize(max_segment_length=0.0))
+
+
+def test_segmentize_curves():
+    """Arcs should be replaced by segments no longer than the maximum length."""
+    gdf = st.GeoDataFrame([
+        "CIRCULARSTRING (0 0, 1 1, 2 0)",
+        "COMPOUNDCURVE (CIRCULARSTRING (0 0, 1 1, 2 0), (2 0, 4 0))",
+        "CURVEPOLYGON (CIRCULARSTRING (0 0, 2 0, 0 0))",
+        "MULTICURVE ((0 0, 2 0), CIRCULARSTRING (0 0, 1 1, 2 0))",
+    ])
+    result = gdf.select(st.segmentize(max_segment_length=1.0))
+    assert result.select(st.geometry_type().cast(pl.String)).to_series().to_list() == [
+        "LineString",
+        "LineString",
+        "Polygon",
+        "MultiLineString",
+    ]
+    assert result.select(st.count_coordinates()).to_series().to_list() == [5, 7, 8, 8]
+    vertices = result.head(1).select(st.coordinates()).explode("geometry").to_series()
+    assert [(x - 1) ** 2 + y**2 for x, y in vertices.to_list()] == pytest.approx([1.0] * 5)
+
+
+def test_densify_geodesic():
+    gdf = st.GeoDataFrame(
+        [
+            "SRID=4326;LINESTRING (0 40, 90 40)",
+            "SRID=4326;LINESTRING Z (0 0 0, 3 0 6)",
+            "SRID=4326;POINT (0 0)",
+        ],
+        geometry_format="ewkt",
+    )
+    result = gdf.select(st.densify_geodesic(max_segment_length=4_000_000.0))
+    coords = result.select(st.coordinates()).to_series().to_list()
+    # The geodesic bulges towards the pole, away from the planar midpoint (45 40)
+    assert len(coords[0]) == 3
+    assert coords[0][1] == pytest.approx([45.0, 49.914], abs=1e-3)
+    assert coords[1] == [[0.0, 0.0, 0.0], [3.0, 0.0, 6.0]]
+    assert result.select(st.to_wkt()).item(2, 0) == "POINT (0 0)"
+    assert result.select(st.srid()).to_series().to_list() == [4326, 4326, 4326]
+    result = gdf.select(st.densify_geodesic(max_segment_length=200_000.0))
+    coords = result.select(st.coordinates()).to_series().to_list()
+    assert coords[1][1] == pytest.approx([1.5, 0.0, 3.0], abs=1e-9)
+    with pytest.raises(pl.exceptions.ComputeError, match="geographic SRID, got 0"):
+        st.GeoSeries(["LINESTRING (0 0, 1 1)"]).st.densify_geodesic(1000.0)
+    projected = st.GeoSeries(["SRID=3857;LINESTRING (0 0, 1 1)"], geometry_format="ewkt")
+    with pytest.raises(pl.exceptions.ComputeError, match="geographic SRID, got 3857"):
+        projected.st.densify_geodesic(1000.0)
+    with pytest.raises(pl.exceptions.ComputeError, match="must be greater than 0"):
+        gdf.select(st.densify_geodesic(max_segment_length=0.0))
+
+
+def test_sjoin_dwithin():
+    """Candidates from the tree should be refined against the actual geometries."""
+    left = st.GeoDataFrame({
+        "id": [0, 1, 2],
+        "geometry": ["LINESTRING (0 2, 2 0)", "POINT (0 0.5)", "POLYGON ((5 5, 6 5, 6 6, 5 5))"],
+    })
+    right = st.GeoDataFrame({
+        "id": [0, 1],
+        "geometry": ["POINT (0 0)", "LINESTRING (4 4, 4 6)"],
+    })
+    joined = left.st.sjoin(right, predicate="dwithin", distance=1.0).sort("id", "id_right")
+    # The bounding box of the first line contains POINT (0 0) but the line itself is
+    # sqrt(2) away from it, so the pair must not be joined
+    assert (0, 0) not in joined.select("id", "id_right").rows()
+    assert joined.select("id", "id_right").rows() == [(1, 0), (2, 1)]
+
+
+@pytest.mark.parametrize(
+    ("how", "expected"),
+    [
+        ("inner", [(0, 0)]),
+        ("left", [(0, 0), (1, None)]),
+        ("right", [(0, 0), (None, 1)]),
+        ("full", [(0, 0), (1, None), (None, 1)]),
+    ],
+)
+def test_sjoin_how(how: Literal["inner", "left", "right", "full"], expected: list):
+    left = st.GeoDataFrame({"id": [0, 1], "geometry": ["POINT (0 0)", "POINT (5 5)"]})
+    right = st.GeoDataFrame({"id": [0, 1], "geometry": ["POINT (0 0)", "POINT (9 9)"]})
+    joined = left.st.sjoin(right, how=how)
+    assert sorted(joined.select("id", "id_right").rows(), key=str) == sorted(expected, key=str)
+    assert joined.columns == ["id", "geometry", "id_right", "geometry_right"]
+
+
+def test_sjoin_order():
+    """Matches should be ordered by right row, then left row, without sorting."""
+    left = st.GeoDataFrame({
+        "id": list(range(50)),
+        "geometry": [f"POINT ({i % 5} {i % 7})" for i in range(50)],
+    })
+    right = st.GeoDataFrame({
+        "id": list(range(30)),
+        "geometry": [f"POLYGON ((0 0, {j % 6 + 1} 0, {j % 6 + 1} 7, 0 7, 0 0))" for j in range(30)],
+    })
+    joined = left.st.sjoin(right, predicate="intersects").select("id_right", "id").rows()
+    assert joined == [(j, i) for j in range(30) for i in range(50) if i % 5 <= j % 6 + 1]
+
+
+def test_self_sjoin():
+    gdf = st.GeoDataFrame({
+        "id": [0, 1, 2],
+        "geometry": ["POINT (0 0)", "POLYGON ((0 0, 2 0, 2 2, 0 2, 0 0))", "POINT (5 5)"],
+    })
+    pairs = gdf.st.self_sjoin().select("id", "id_right").rows()
+    assert sorted(pairs) == [(0, 1), (1, 0)]
+    assert gdf.st.self_sjoin(distinct=True).select("id", "id_right").rows() == [(0, 1)]
+    within = gdf.st.self_sjoin(predicate="within", distinct=True).select("id", "id_right")
+    assert within.rows() == [(1, 0)]
+
+
+def test_sjoin_reuses_index_for_same_left():
+    left = st.GeoDataFrame({"id": [0, 1], "geometry": ["POINT (0 0)", "POINT (5 5)"]})
+    shifted = st.GeoDataFrame({"id": [0, 1], "geometry": ["POINT (5 5)", "POINT (0 0)"]})
+    rights = [
+        st.GeoDataFrame({"geometry": ["POINT (0 0)"]}),
+        st.GeoDataFrame({"geometry": ["POINT (5 5)", "POINT (9 9)", "POINT (0 0)"]}),
+    ]
+    for right in rights:
+        for frame in (left, shifted, left):
+            joined = frame.st.sjoin(right).sort("id")
+            expected = frame.join(right, on="geometry").sort("id")
+            assert joined["id"].to_list() == expected["id"].to_list()
+
+
+def test_sjoin_does_not_share_index_between_frames():
+    right = st.GeoDataFrame({"geometry": ["POINT (0 0)", "POINT (7 7)"]})
+    first = st.GeoDataFrame({"id": [0, 1, 2], "geometry": ["POINT (0 0)", None, "POINT (1 1)"]})
+    second = st.GeoDataFrame({"id": [0, 1, 2], "geometry": ["POINT (2 2)", "POINT (7 7)", None]})
+    assert first.st.sjoin(right)["id"].to_list() == [0]
+    assert second.st.sjoin(right)["id"].to_list() == [1]
+    assert first.st.sjoin(right)["id"].to_list() == [0]
+    st.clear_sjoin_cache()
+    assert second.st.sjoin(right)["id"].to_list() == [1]
+    st.clear_sjoin_cache()
+    st.clear_sjoin_cache()
+
+
+def test_affine_transform():
+    gdf = st.GeoDataFrame({
+        "geometry": ["SRID=2154;POINT (1 2)", "POINT Z (1 2 3)", None],
+        "matrix": [
+            [2, 0, 0, 2, 10, 0],
+            [1, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 1],
+            [1, 0, 0, 1, 0, 0],
+        ],
+    })
+    result = gdf.select(st.affine_transform(matrix=pl.col("matrix")).st.to_ewkt())
+    assert result.to_series().to_list() == ["SRID=2154;POINT (12 4)", "POINT Z (1 2 7)", None]
+    result = gdf.select(st.affine_transform(matrix=[1, 0, 0, 1, 0, 0], keep_srid=False))
+    assert result.select(st.srid()).to_series().to_list() == [0, 0, None]
+
+
+@pytest.mark.parametrize(
+    ("matrix", "error"),
+    [
+        ([1, 0, 0, 1, 0], "row 0: expected 6 or 12 elements, got 5"),
+        ([1, 0, 0, 1, 0, 0, 0], "row 0: expected 6 or 12 elements, got 7"),
+        ([1, 0, 0, 1, 0, None], "row 0: elements must not be null"),
+    ],
+)
+def test_affine_transform_invalid_matrix(matrix: list[float | None], error: str):
+    gdf = st.GeoDataFrame(["POINT (1 2)"])
+    with pytest.raises(pl.exceptions.ComputeError, match=error):
+        gdf.select(st.affine_transform(matrix=matrix))
+    matrices = pl.Series([[1, 0, 0, 1, 0, 0], matrix])
+    gdf = st.GeoDataFrame({"geometry": ["POINT (1 2)", "POINT (3 4)"], "matrix": matrices})
+    with pytest.raises(pl.exceptions.ComputeError, match=error.replace("row 0", "row 1")):
+        gdf.select(st.affine_transform(matrix=pl.col("matrix")))
+
+
+def test_transform_origin_geometry():
+    gdf = st.GeoDataFrame({
+        "geometry": ["POINT (2 0)", "POINT (2 0)", None],
+        "anchor": ["POINT (1 0)", "POINT (3 0)", "POINT (0 0)"],
+    })
+    rotated = gdf.select(st.rotate(angle=90, origin=st.geom("anchor")).st.coordinates())
+    assert rotated.to_series().to_list()[2] is None
+    for coords, expected in zip(rotated.to_series().to_list()[:2], [[1, 1], [3, -1]], strict=True):
+        assert coords[0] == pytest.approx(expected)
+    scaled = gdf.select(st.scale(x=2, y=2, origin=st.geom("anchor")).st.to_wkt())
+    assert scaled.to_series().to_list() == ["POINT (3 0)", "POINT (1 0)", None]
+    skewed = gdf.select(st.skew(x=45, origin=pl.lit(st.GeoSeries(["POINT (0 -1)"]))))
+    assert skewed.select(st.to_wkt()).to_series().to_list()[2] is None
+    assert skewed.select(st.y()).to_series().to_list() == [0.0, 0.0, None]
+
+
+def test_transform_origin_not_point():
+    gdf = st.GeoDataFrame({"geometry": ["POINT (2 0)"], "anchor": ["LINESTRING (0 0, 1 1)"]})
+    with pytest.raises(pl.exceptions.ComputeError, match="Origin must be a non-empty point"):
+        gdf.select(st.rotate(angle=90, origin=st.geom("anchor")))
+
+
+def test_line_merge_tolerance():
+    gdf = st.GeoDataFrame({
+        "geometry": [
+            "MULTILINESTRING ((0 0, 1 0), (1.001 0, 2 0), (2 0.5, 3 0))",
+            "MULTILINESTRING ((0 0, 1 0), (1.001 0, 2 0), (2 0.5, 3 0))",
+            "MULTILINESTRING ((0 0, 1 0), (2 0.001, 1.001 0))",
+            "MULTILINESTRING ((0 0, 1 0), (2 0.001, 1.001 0))",
+        ],
+        "tolerance": [None, 0.01, 0.01, 0.01],
+        "directed": [False, False, False, True],
+    })
+    result = gdf.select(
+        pl.when(pl.col("directed"))
+        .then(st.line_merge(directed=True, tolerance=pl.col("tolerance")))
+        .otherwise(st.line_merge(tolerance=pl.col("tolerance")))
+    )
+    assert result.select(st.count_geometries()).to_series().to_list() == [3, 2, 1, 2]
+    assert result.select(st.length()).to_series().to_list() == pytest.approx(
+        gdf.select(st.length()).to_series().to_list(), abs=0.01
+    )
+
+
+def test_extract_unique_points_tolerance():
+    gdf = st.GeoDataFrame({
+        "geometry": ["POLYGON ((0 0, 1 0, 1.001 0.0001, 0 1, 0 0))"] * 3 + [None],
+        "tolerance": [None, 0.0001, 0.01, 0.01],
+    })
+    result = gdf.select(st.extract_unique_points(tolerance=pl.col("tolerance")))
+    assert result.select(st.count_geometries()).to_series().to_list() == [4, 4, 3, None]
+
+
+def test_reverse_lines_only():
+    gdf = st.GeoDataFrame([
+        "SRID=3857;MULTILINESTRING ((0 0, 1 0), (2 0, 3 0))",
+        "POLYGON ((0 0, 1 0, 1 1, 0 0))",
+        "MULTIPOINT ((0 0), (1 1))",
+        "GEOMETRYCOLLECTION (POINT (0 0), LINESTRING (0 0, 1 1))",
+    ])
+    result = gdf.select(st.reverse(lines_only=True).st.to_ewkt()).to_series()
+    assert result.to_list() == [
+        "SRID=3857;MULTILINESTRING ((1 0, 0 0), (3 0, 2 0))",
+        "POLYGON ((0 0, 1 0, 1 1, 0 0))",
+        "MULTIPOINT ((0 0), (1 1))",
+        "GEOMETRYCOLLECTION (POINT (0 0), LINESTRING (1 1, 0 0))",
+    ]
+
+
+def test_to_srid_keeps_z():
+    gdf = st.GeoDataFrame(["POINT Z (10 20 100)"]).select(st.set_srid(srid=4326))
+    projected = gdf.select(st.to_srid(srid=3857))
+    assert projected.select(st.z()).item() == pytest.approx(100.0)
+    back = projected.select(st.to_srid(srid=4326))
+    coords = back.select(st.x().alias("x"), st.y().alias("y"), st.z().alias("z")).row(0)
+    assert coords == pytest.approx((10.0, 20.0, 100.0))
+
+
+def test_to_srid_mixed_srids():
+    """Rows whose SRIDs differ from the previous row should use their own projections."""
+    gdf = st.GeoDataFrame(
+        {
+            "geometry": [
+                "SRID=4326;POINT (10 20)",
+                "SRID=4326;POINT (10 20)",
+                "SRID=3857;POINT (1113194.9 2273030.9)",
+                "SRID=4326;POINT (10 20)",
+                "SRID=4326;POINT (10 20)",
+            ],
+            "srid": [3857, 3857, 4326, 32632, 3857],
+        },
+        geometry_format="ewkt",
+    )
+    result = gdf.select(st.to_srid(srid=pl.col("srid")))
+    expected = pl.concat([
+        gdf.slice(i, 1).select(st.to_srid(srid=srid)) for i, srid in enumerate(gdf["srid"])
+    ])
+    assert result.select(st.srid()).to_series().to_list() == gdf["srid"].to_list()
+    for column in (st.x(), st.y()):
+        values = result.select(column).to_series().to_list()
+        assert values == pytest.approx(expected.select(column).to_series().to_list())
+    assert result.select(st.x()).to_series()[2] == pytest.approx(10.0)
+
+
+def test_to_crs():
+    gdf = st.GeoDataFrame(["POINT (10 20)"])
+    by_srid = gdf.select(st.set_srid(srid=4326)).select(st.to_srid(srid=3857))
+    by_code = gdf.select(st.to_crs(source="EPSG:4326", target="EPSG:3857"))
+    by_proj = gdf.select(st.to_crs(source="+proj=longlat +datum=WGS84", target="EPSG:3857"))
+    for result in (by_code, by_proj):
+        assert result.select(st.srid()).item() == 3857
+        assert result.select(st.x()).item() == pytest.approx(by_srid.select(st.x()).item())
+        assert result.select(st.y()).item() == pytest.approx(by_srid.select(st.y()).item())
+    with pytest.raises(pl.exceptions.ComputeError, match="Invalid CRS definition: foo"):
+        gdf.select(st.to_crs(source="foo", target="EPSG:3857"))
+
+
+def test_to_srid_always_xy():
+    lon_lat = st.GeoDataFrame(["POINT (45 10)"]).select(st.set_srid(srid=4326))
+    lat_lon = st.GeoDataFrame(["POINT (10 45)"]).select(st.set_srid(srid=4326))
+    expected = lon_lat.select(st.to_srid(srid=3857)).select(st.to_wkt()).item()
+    result = lat_lon.select(st.to_srid(srid=3857, always_xy=False)).select(st.to_wkt()).item()
+    assert result == expected
+
+    back = lon_lat.select(st.to_srid(srid=3857)).select(st.to_srid(srid=4326, always_xy=False))
+    assert back.select(st.to_wkt()).item() == "POINT (10 45)"
+
+    swapped = lat_lon.select(st.to_crs(source="EPSG:4326", target="EPSG:3857", always_xy=False))
+    assert swapped.select(st.to_wkt()).item() == expected
+
+
+def test_to_srid_on_error():
+    gdf = st.GeoDataFrame(["POINT (10 20)", "POINT (10 100)", None]).select(st.set_srid(srid=4326))
+    with pytest.raises(pl.exceptions.ComputeError, match=r"Cannot reproject row 1 .* \(10, 100\)"):
+        gdf.select(st.to_srid(srid=3857))
+    result = gdf.select(st.to_srid(srid=3857, on_error="null"))
+    assert result.select(st.srid()).to_series().to_list() == [3857, None, None]
+
+
+def test_to_geojson_feature_collection():
+    gdf = st.GeoDataFrame({
+        "name": ["Alice", "Bob", "Carol"],
+        "id": [1, 2, 3],
+        "geometry": ["POINT (0 0)", None, "LINESTRING (0 0, 1 1)"],
+    })
+    geojson = gdf.select(st.to_geojson_feature_collection(properties=["name", "id"])).item()
+    collection = json.loads(geojson)
+    assert collection["type"] == "FeatureCollection"
+    assert [f["properties"] for f in collection["features"]] == [
+        {"name": "Alice", "id": 1},
+        {"name": "Bob", "id": 2},
+        {"name": "Carol", "id": 3},
+    ]
+    assert list(collection["features"][0]["properties"]) == ["name", "id"]
+    assert [f["geometry"] and f["geometry"]["type"] for f in collection["features"]] == [
+        "Point",
+        None,
+        "LineString",
+    ]
+
+    indented = gdf.select(st.to_geojson_feature_collection(indent=2)).item()
+    assert indented.startswith('{\n  "type": "FeatureCollection",')
+    assert [f["properties"] for f in json.loads(indented)["features"]] == [{}, {}, {}]
+    newlines = gdf.select(st.to_geojson_feature_collection(indent=0)).item()
+    assert newlines.startswith('{\n"type": "FeatureCollection",')
+    assert json.loads(newlines) == json.loads(indented)
+
+
+def test_from_geojson_feature_collection():
+    collection = json.dumps({
+        "type": "FeatureCollection",
+        "features": [
+            {
+                "type": "Feature",
+                "properties": {"name": "type"},
+                "geometry": {"type": "Point", "coordinates": [1.5, 2.0]},
+            },
+            {
+                "type": "Feature",
+                "properties": {"name": "b"},
+                "geometry": {"type": "LineString", "coordinates": [[0, 0], [1, 1]]},
+            },
+        ],
+    })
+    feature = json.dumps({
+        "type": "Feature",
+        "properties": {},
+        "geometry": {"type": "Point", "coordinates": [3, 4]},
+    })
+    df = pl.DataFrame({"geometry": [feature, collection]})
+
+    with pytest.raises(pl.exceptions.ComputeError, match="FeatureCollection"):
+        df.select(st.from_geojson("geometry"))
+
+    collected = df.select(st.from_geojson("geometry", on_feature_collection="collect"))
+    assert collected.select(st.geometry_type()).to_series().to_list() == [
+        "Point",
+        "GeometryCollection",
+    ]
+
+    exploded = df.select(st.from_geojson("geometry", on_feature_collection="explode"))
+    assert exploded.select(st.to_wkt()).to_series().to_list() == [
+        "POINT (3 4)",
+        "POINT (1.5 2)",
+        "LINESTRING (0 0, 1 1)",
+    ]
+
+
+def test_geoparquet_metadata():
+    gdf = st.GeoDataFrame(["POINT (1 2)", "LINESTRING (0 0, 3 4)"])
+    gdf = gdf.select(st.geom().st.set_srid(3857))
+    metadata = json.loads(gdf.st.geoparquet_metadata())
+    assert metadata["primary_column"] == "geometry"
+    column = metadata["columns"]["geometry"]
+    assert column["encoding"] == "WKB"
+    assert column["bbox"] == [0.0, 0.0, 3.0, 4.0]
+    assert column["geometry_types"] == ["LineString", "Point"]
+    assert column["crs"]["id"] == {"authority": "EPSG", "code": 3857}
+    assert column["crs"]["type"] == "ProjectedCRS"
+    assert column["crs"]["base_crs"]["datum"]["ellipsoid"]["semi_major_axis"] == 6378137
+    assert column["crs"]["coordinate_system"]["subtype"] == "Cartesian"
+    assert len(column["crs"]["coordinate_system"]["axis"]) == 2
+    assert "method" in column["crs"]["conversion"]
+    geographic = json.loads(st.geoparquet_metadata("geometry", 4326))["columns"]["geometry"]
+    assert geographic["crs"]["type"] == "GeographicCRS"
+    ellipsoid = geographic["crs"]["datum"]["ellipsoid"]
+    assert ellipsoid["inverse_flattening"] == pytest.approx(298.257223563)
+    assert geographic["crs"]["coordinate_system"]["subtype"] == "ellipsoidal"
+    assert st.parse_geoparquet_metadata(json.dumps(metadata)) == ("geometry", 3857)
+
+    column["crs"] = None
+    assert st.parse_geoparquet_metadata(json.dumps(metadata)) == ("geometry", 0)
+    del column["crs"]
+    assert st.parse_geoparquet_metadata(json.dumps(metadata)) == ("geometry", 4326)
+
+    with pytest.raises(ValueError, match="primary column"):
+        st.parse_geoparquet_metadata("{}")
+
+
+def test_to_geojson_output_dimension():
+    gdf = st.GeoDataFrame(["POINT Z (1 2 3)", "LINESTRING Z (0 0 1, 1 1 2)", "POINT (4 5)"])
+    geojson = gdf.select(st.to_geojson(output_dimension=2)).to_series().to_list()
+    assert [json.loads(g)["coordinates"] for g in geojson] == [
+        [1, 2],
+        [[0, 0], [1, 1]],
+        [4, 5],
+    ]
+    geojson = gdf.select(st.to_geojson()).to_series().to_list()
+    assert json.loads(geojson[0])["coordinates"] == [1, 2, 3]
+
+    collection = gdf.select(st.to_geojson_feature_collection(output_dimension=2)).item()
+    assert json.loads(collection)["features"][0]["geometry"]["coordinates"] == [1, 2]
+
+
+def test_to_geojson_rounding_precision():
+    gdf = st.GeoDataFrame(["POINT (1.23456789 -2.98765)", "LINESTRING (0.5 0, 1 1.25)"])
+    geojson = gdf.select(st.to_geojson(rounding_precision=2)).to_series().to_list()
+    assert [json.loads(g)["coordinates"] for g in geojson] == [
+        [1.23, -2.99],
+        [[0.5, 0], [1, 1.25]],
+    ]
+
+
+def test_to_kml():
+    gdf = st.GeoDataFrame([
+        "POLYGON ((0 0, 4 0, 4 4, 0 0), (1 1, 2 1, 2 2, 1 1))",
+        "MULTIPOINT Z ((0 0 1), (1 1 2))",
+        "POLYGON EMPTY",
+    ])
+    assert gdf.select(st.to_kml()).to_series().to_list() == [
+        "<Polygon>"
+        "<outerBoundaryIs><LinearRing><coordinates>0,0 4,0 4,4 0,0</coordinates></LinearRing></outerBoundaryIs>"  # noqa: E501
+        "<innerBoundaryIs><LinearRing><coordinates>1,1 2,1 2,2 1,1</coordinates></LinearRing></innerBoundaryIs>"  # noqa: E501
+        "</Polygon>",
+        "<MultiGeometry>"
+        "<Point><coordinates>0,0,1</coordinates></Point>"
+        "<Point><coordinates>1,1,2</coordinates></Point>"
+        "</MultiGeometry>",
+        "<Polygon/>",
+    ]
+
+
+def test_to_gml():
+    gdf = st.GeoDataFrame([
+        "SRID=3857;MULTILINESTRING ((0 0, 1 1), (2 2, 3 3))",
+        "LINESTRING Z (0 0 1, 1 1 2)",
+        "SRID=4326;POINT EMPTY",
+    ])
+    assert gdf.select(st.to_gml()).to_series().to_list() == [
+        '<gml:MultiCurve srsName="EPSG:3857">'
+        "<gml:curveMember><gml:LineString><gml:posList>0 0 1 1</gml:posList></gml:LineString></gml:curveMember>"  # noqa: E501
+        "<gml:curveMember><gml:LineString><gml:posList>2 2 3 3</gml:posList></gml:LineString></gml:curveMember>"  # noqa: E501
+        "</gml:MultiCurve>",
+        '<gml:LineString><gml:posList srsDimension="3">0 0 1 1 1 2</gml:posList></gml:LineString>',
+        '<gml:Point srsName="EPSG:4326"/>',
+    ]
+    assert gdf.select(st.to_gml(version=2)).to_series().to_list() == [
+        '<gml:MultiLineString srsName="EPSG:3857">'
+        "<gml:lineStringMember><gml:LineString><gml:coordinates>0,0 1,1</gml:coordinates></gml:LineString></gml:lineStringMember>"  # noqa: E501
+        "<gml:lineStringMember><gml:LineString><gml:coordinates>2,2 3,3</gml:coordinates></gml:LineString></gml:lineStringMember>"  # noqa: E501
+        "</gml:MultiLineString>",
+        "<gml:LineString><gml:coordinates>0,0,1 1,1,2</gml:coordinates></gml:LineString>",
+        '<gml:Point srsName="EPSG:4326"/>',
+    ]
+
+
+def test_to_svg():
+    gdf = st.GeoDataFrame([
+        "POLYGON ((0 0, 4 0, 4 4, 0 0), (1 1, 2 1, 2 2, 1 1))",
+        "MULTIPOINT ((0.123456 1), (2 3))",
+        "LINESTRING EMPTY",
+    ])
+    assert gdf.select(st.to_svg(rounding_precision=2)).to_series().to_list() == [
+        '<path fill-rule="evenodd" d="M 0,0 L 4,0 4,-4 0,0 Z M 1,-1 L 2,-1 2,-2 1,-1 Z"/>',
+        '<g><circle cx="0.12" cy="-1" r="1"/><circle cx="2" cy="-3" r="1"/></g>',
+        "<g/>",
+    ]
+
+
+def test_normalize_wkb():
+    gdf = st.GeoDataFrame(
+        [
+            "SRID=4326;MULTILINESTRING Z ((0 0 1, 1 1 2), (2 2 3, 3 3 4))",
+            "POINT (0.5 1.5)",
+            "GEOMETRYCOLLECTION (POINT Z (0 0 1), LINESTRING Z (0 0 0, 1 1 1))",
+        ],
+        geometry_format="ewkt",
+    )
+    ndr = gdf.select(st.to_wkb(byte_order=1, include_srid=True)).to_series()
+    xdr = gdf.select(st.to_wkb(byte_order=0, include_srid=True)).to_series()
+    mixed = pl.Series("geometry", [xdr[0], ndr[1], xdr[2], None])
+    assert mixed.st.wkb_flavor().to_list() == ["ewkb_xdr", "iso_ndr", "ewkb_xdr", None]
+
+    assert mixed.st.normalize_wkb().to_list() == [*ndr.to_list(), None]
+    assert mixed.st.normalize_wkb(byte_order=0).to_list() == [*xdr.to_list(), None]
+
+    iso = mixed.st.normalize_wkb(flavor="iso")
+    assert iso.st.wkb_flavor().to_list() == ["iso_ndr", "iso_ndr", "iso_ndr", None]
+    assert iso.st.normalize_wkb().st.to_ewkt().to_list() == [
+        "MULTILINESTRING Z ((0 0 1, 1 1 2), (2 2 3, 3 3 4))",
+        "POINT (0.5 1.5)",
+        "GEOMETRYCOLLECTION (POINT Z (0 0 1), LINESTRING Z (0 0 0, 1 1 1))",
+        None,
+    ]
+
+
+def test_wkb_base64_round_trip():
+    gdf = st.GeoDataFrame(["SRID=3857;POINT (1 2)", "LINESTRING (0 0, 1 1)", None])
+    for alphabet in ["standard", "url_safe"]:
+        encoded = gdf.select(st.to_wkb_base64(alphabet=alphabet))
+        decoded = encoded.select(st.from_wkb_base64("geometry", alphabet=alphabet))
+        assert decoded.select(st.to_ewkt()).to_series().to_list() == [
+            "SRID=3857;POINT (1 2)",
+            "LINESTRING (0 0, 1 1)",
+            None,
+        ]
+
+    unpadded = pl.lit("AQIAAAACAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAPA/AAAAAAAA8D8")
+    decoded = pl.select(st.from_wkb_base64(unpadded).st.to_wkt())
+    assert decoded.item() == "LINESTRING (0 0, 1 1)"
+
+    invalid = pl.DataFrame({"geometry": ["AQEAAAAAAAAAAADwPwAAAAAAAABA", "not base64!"]})
+    with pytest.raises(pl.exceptions.ComputeError, match="row 1"):
+        invalid.select(st.from_wkb_base64("geometry"))
+
+
+def test_is_valid_points_and_empties():
+    gdf = st.GeoDataFrame([
+        "SRID=4326;POINT Z (0 1 2)",
+        "POINT EMPTY",
+        "POLYGON EMPTY",
+        "GEOMETRYCOLLECTION EMPTY",
+        "POLYGON ((0 0, 2 2, 2 0, 0 2, 0 0))",
+        None,
+    ])
+    nan_point = st.points_from_coords(pl.lit(float("nan")), pl.lit(0.0))
+    result = gdf.select(st.is_valid()).to_series().to_list()
+    assert result == [True, True, True, True, False, None]
+    assert gdf.head(1).select(nan_point.st.is_valid()).item() is False
+
+
+def test_is_valid_detail():
+    gdf = st.GeoDataFrame([
+        "SRID=3857;POLYGON ((0 0, 2 2, 2 0, 0 2, 0 0))",
+        "POLYGON ((0 0, 1 0, 1 1, 0 0))",
+        None,
+    ])
+    detail = gdf.select(st.is_valid_detail()).unnest("geometry")
+    assert detail["is_valid"].to_list() == [False, True, None]
+    assert detail["reason"].to_list() == ["Self-intersection", None, None]
+    location = detail.select(st.geom("location").st.to_ewkt()).to_series()
+    assert location.to_list() == ["SRID=3857;POINT (1 1)", None, None]
+
+
+@pytest.mark.parametrize("method", ["linework", "structure"])
+def test_make_valid_bowtie(method: Literal["linework", "structure"]):
+    gdf = st.GeoDataFrame(["POLYGON ((0 0, 2 2, 2 0, 0 2, 0 0))"])
+    result = gdf.select(st.make_valid(method=method))
+    assert result.select(st.is_valid()).item()
+    assert result.select(st.geometry_type()).item() == "MultiPolygon"
+    assert result.select(st.area()).item() == pytest.approx(2.0)
+
+
+def test_make_valid_keep_collapsed():
+    gdf = st.GeoDataFrame(["POLYGON ((0 0, 1 1, 1 2, 1 1, 0 0))"])
+    kept = gdf.select(st.make_valid(method="structure", keep_collapsed=True))
+    assert kept.select(st.dimensions()).item() == 1
+    dropped = gdf.select(st.make_valid(method="structure", keep_collapsed=False))
+    assert dropped.select(st.is_empty()).item()
+
+
+def test_coverage_is_valid_and_simplify():
+    gdf = st.GeoDataFrame([
+        "GEOMETRYCOLLECTION ("
+        "POLYGON ((0 0, 1 0, 1.01 0.5, 1 1, 0 1, 0 0)), "
+        "POLYGON ((1 0, 2 0, 2 1, 1 1, 1.01 0.5, 1 0)))",
+        "GEOMETRYCOLLECTION ("
+        "POLYGON ((0 0, 1 0, 1 1, 0 1, 0 0)), "
+        "POLYGON ((0.5 0, 2 0, 2 1, 0.5 1, 0.5 0)))",
+    ])
+    assert gdf.select(st.coverage_is_valid()).to_series().to_list() == [True, False]
+
+    simplified = gdf.head(1).select(st.coverage_simplify(tolerance=0.5, simplify_boundary=False))
+    assert simplified.select(st.coverage_is_valid()).item()
+    assert simplified.select(st.count_coordinates()).item() == 10
+    assert simplified.select(st.area()).item() == pytest.approx(2.0)
+
+    with pytest.raises(pl.exceptions.ComputeError, match="must be a collection"):
+        st.GeoDataFrame(["POINT (0 0)"]).select(st.coverage_is_valid())
+
+
+def test_simplify_vw():
+    gdf = st.GeoDataFrame([
+        "LINESTRING (0 0, 1 0.01, 2 0, 3 5, 4 0)",
+        "POLYGON ((0 0, 1 0.01, 2 0, 2 2, 0 2, 0 0))",
+    ])
+    result = gdf.select(st.simplify_vw(tolerance=0.1).st.to_wkt()).to_series().to_list()
+    assert result == [
+        "LINESTRING (0 0, 2 0, 3 5, 4 0)",
+        "POLYGON ((0 0, 2 0, 2 2, 0 2, 0 0))",
+    ]
+
+    collapsed = gdf.select(st.simplify_vw(tolerance=100.0))
+    assert collapsed.select(st.count_coordinates()).to_series().to_list() == [2, 4]
+    assert collapsed.select(st.is_valid()).to_series().to_list() == [True, True]
+
+
+def test_simplify_vw_multipolygon():
+    """Removing a vertex must not make a part overlap another one, and M is kept."""
+    gdf = st.GeoDataFrame([
+        "MULTIPOLYGON M ("
+        "((0 0 1, 10 0 2, 10 10 3, 5 6 4, 0 10 5, 0 0 1)),"
+        " ((4 8 0, 6 8 0, 5 9 0, 4 8 0)))"
+    ])
+    wkt = gdf.select(st.to_wkt()).item()
+    preserved = gdf.select(st.simplify_vw(tolerance=25.0).st.to_wkt()).item()
+    assert preserved == wkt
+    simplified = gdf.select(st.simplify_vw(tolerance=25.0, preserve_topology=False))
+    assert simplified.select(st.to_wkt()).item() == (
+        "MULTIPOLYGON M (((0 0 1, 10 0 2, 10 10 3, 0 10 5, 0 0 1)), ((4 8 0, 6 8 0, 5 9 0, 4 8 0)))"
+    )
+
+
+def test_concave_hull_by_length():
+    gdf = st.GeoDataFrame([
+        "MULTIPOINT ((0 0), (4 0), (4 4), (2 1), (0 4), (1 2), (3 2), (2 3))",
+    ])
+    convex = gdf.select(st.convex_hull()).to_series()
+    large = gdf.select(st.concave_hull_by_length(max_length=100.0)).to_series()
+    assert large.st.equals(convex).item()
+
+    small = gdf.select(st.concave_hull_by_length(max_length=2.5)).to_series()
+    assert small.st.area().item() < convex.st.area().item()
+
+
+@pytest.mark.parametrize(("distance", "ymin", "ymax"), [(1.0, 0.0, 1.0), (-1.0, -1.0, 0.0)])
+def test_offset_curve_single_sided(distance: float, ymin: float, ymax: float):
+    gdf = st.GeoDataFrame(["LINESTRING (0 0, 4 0)"])
+    curve = gdf.select(st.offset_curve(distance=distance)).to_series()
+    assert curve.st.bounds().item().to_list() == pytest.approx([0.0, distance, 4.0, distance])
+
+    side = gdf.select(st.offset_curve(distance=distance, single_sided=True)).to_series()
+    assert side.st.geometry_type().item() == "Polygon"
+    assert side.st.area().item() == pytest.approx(4.0)
+    assert side.st.bounds().item().to_list() == pytest.approx([0.0, ymin, 4.0, ymax])
+
+
+def test_buffer_with_columns():
+    gdf = st.GeoDataFrame({
+        "geometry": ["LINESTRING (0 0, 2 0)"] * 3,
+        "distance": [1.0, 1.0, None],
+        "cap_style": ["flat", "square", "round"],
+    })
+    result = gdf.select(
+        st.buffer_with_columns(distance="distance", cap_style="cap_style", quad_segs=pl.lit(1)),
+    )
+    assert result.select(st.area()).to_series().to_list() == [4.0, 8.0, None]
+
+    with pytest.raises(pl.exceptions.ComputeError, match="Invalid cap style: `butt`"):
+        gdf.select(st.buffer_with_columns(distance=1.0, cap_style=pl.lit("butt")))
+
+
+def test_hausdorff_frechet_densify():
+    gdf = st.GeoDataFrame({
+        "geometry": ["LINESTRING (130 0, 0 0, 0 150)", "LINESTRING (0 0, 100 0)", "POINT EMPTY"],
+        "other": [
+            "LINESTRING (10 10, 10 150, 130 10)",
+            "LINESTRING (0 0, 50 50, 100 0)",
+            "POINT (0 0)",
+        ],
+    })
+    other = st.geom("other")
+    result = gdf.select(
+        hausdorff=st.geom().st.hausdorff_distance(other),
+        hausdorff_densify=st.geom().st.hausdorff_distance(other, densify=0.5),
+        frechet=st.geom().st.frechet_distance(other),
+        frechet_densify=st.geom().st.frechet_distance(other, densify=0.5),
+    )
+    assert list(result.row(0)[:2]) == pytest.approx([14.142135623730951, 70.0])
+    assert list(result.row(1)[2:]) == pytest.approx([70.71067811865476, 50.0])
+    assert all(math.isnan(value) for value in result.row(2)[2:])
+    with pytest.raises(pl.exceptions.ComputeError, match="densify must be a fraction"):
+        gdf.select(st.geom().st.frechet_distance(other, densify=1.5))
+
+
+def test_distance_matrix():
+    gdf = st.GeoDataFrame(["POINT (0 0)", None, "POINT EMPTY", "LINESTRING (3 0, 3 4)"])
+    others = st.GeoSeries(["POINT (0 4)", None, "POINT EMPTY"])
+    result = gdf.select(st.geom().st.distance_matrix(pl.lit(others))).to_series().to_list()
+    assert result[0][:2] == [4.0, None]
+    assert result[1] is None
+    assert all(math.isnan(d) for d in [result[0][2], result[2][0], result[2][2]])
+    assert result[3][:2] == [3.0, None]
+
+    nulls = pl.DataFrame({"geometry": [None, None]}, schema={"geometry": pl.Binary})
+    result = nulls.select(st.geom().st.distance_matrix(pl.lit(others))).to_series()
+    assert result.dtype == pl.List(pl.Float64)
+    assert result.to_list() == [None, None]
+
+
+def test_equals_exact_tolerance_column():
+    gdf = st.GeoDataFrame({
+        "geometry": ["POINT (0 0)", "POINT (0 0)", "POINT (0 0)"],
+        "tolerance": [0.05, 0.5, None],
+    })
+    other = st.from_wkt(pl.lit("POINT (0.1 0)"))
+    result = gdf.select(st.geom().st.equals_exact(other, tolerance="tolerance"))
+    assert result.to_series().to_list() == [False, True, None]
+    result = gdf.select(st.geom().st.equals_exact(other, tolerance=0.5))
+    assert result.to_series().to_list() == [True, True, True]
+
+
+@pytest.mark.parametrize(
+    ("boundary_node_rule", "in_interior"),
+    [
+        ("mod2", True),
+        ("endpoint", False),
+        ("multivalent_endpoint", False),
+        ("monovalent_endpoint", True),
+    ],
+)
+def test_relate_boundary_node_rule(
+    boundary_node_rule: Literal["mod2", "endpoint", "multivalent_endpoint", "monovalent_endpoint"],
+    in_interior: bool,
+):
+    """The point where both lines meet is on the boundary depending on the rule."""
+    gdf = st.GeoDataFrame(["MULTILINESTRING ((0 0, 1 0), (1 0, 2 0))"])
+    point = st.from_wkt(pl.lit("POINT (1 0)"))
+    matrix = gdf.select(st.geom().st.relate(point, boundary_node_rule=boundary_node_rule)).item()
+    assert (matrix[0] == "0") == in_interior
+    assert (matrix[3] == "0") != in_interior
+    result = gdf.select(st.geom().st.relate_pattern(point, "T********", boundary_node_rule))
+    assert result.item() == in_interior
+
+
+def test_relate_pattern_per_row():
+    gdf = st.GeoDataFrame({
+        "geometry": ["POINT (0 0)", "POINT (0 0)", "POINT (0 0)"],
+        "pattern": ["T********", "FF*FF****", None],
+    })
+    result = gdf.select(st.geom().st.relate_pattern(st.geom(), pl.col("pattern")))
+    assert result.to_series().to_list() == [True, False, None]
+
+    lowercase = gdf.with_columns(pattern=pl.Series(["t********", "ff*ff****", "t*f**f***"]))
+    result = lowercase.select(st.geom().st.relate_pattern(st.geom(), pl.col("pattern")))
+    assert result.to_series().to_list() == [True, False, True]
+
+    invalid = gdf.with_columns(pattern=pl.Series(["T********", "T*", "X********"]))
+    with pytest.raises(pl.exceptions.ComputeError, match="Invalid DE-9IM pattern at row 1: `T\\*`"):
+        invalid.select(st.geom().st.relate_pattern(st.geom(), pl.col("pattern")))
+
+
+def test_substring():
+    gdf = st.GeoDataFrame({
+        "geometry": ["LINESTRING (0 0, 10 0)"] * 3,
+        "start": [0.2, 0.8, -1.0],
+        "end": [0.5, 0.2, 2.0],
+    })
+    result = gdf.select(st.substring(pl.col("start"), pl.col("end")).st.to_wkt())
+    assert result.to_series().to_list() == [
+        "LINESTRING (2 0, 5 0)",
+        "LINESTRING (8 0, 2 0)",
+        "LINESTRING (0 0, 10 0)",
+    ]
+    result = gdf.select(st.substring(start=-5.0, end=4.0, normalized=False).st.to_wkt())
+    assert result.to_series().to_list() == ["LINESTRING (0 0, 4 0)"] * 3
+
+
+def test_interpolate_many():
+    gdf = st.GeoDataFrame({
+        "geometry": ["LINESTRING (0 0, 10 0)", "LINESTRING (0 0, 0 4)", None],
+        "distances": [[0.0, 5.0, 15.0], [0.5, None], [1.0]],
+    })
+    result = gdf.select(st.geom().st.interpolate_many(pl.col("distances")))
+    assert result.select(pl.col("geometry").list.len()).to_series().to_list() == [3, 2, None]
+    points = result.explode("geometry").select(st.to_wkt()).to_series().to_list()
+    assert points == ["POINT (0 0)", "POINT (5 0)", "POINT (10 0)", "POINT (0 0.5)", None, None]
+
+    normalized = gdf.head(2).select(st.interpolate_many(distances=[0.5], normalized=True))
+    assert normalized.explode("geometry").select(st.to_wkt()).to_series().to_list() == [
+        "POINT (5 0)",
+        "POINT (0 2)",
+    ]
+
+
+@pytest.mark.parametrize("scalar_road", [True, False])
+def test_nearest_points(scalar_road: bool):
+    gdf = st.GeoDataFrame({
+        "geometry": ["POINT (0 1)", "POINT (3 4)", "POINT EMPTY", None],
+        "road": ["LINESTRING (0 0, 10 0)"] * 4,
+    }).with_columns(st.from_wkt("road"))
+    road = st.from_wkt(pl.lit("LINESTRING (0 0, 10 0)")) if scalar_road else st.geom("road")
+    result = gdf.select(st.geom().st.nearest_points(road))
+    assert result.to_series().is_null().to_list() == [False, False, False, True]
+    result = result.unnest("geometry")
+    assert result.select(st.to_wkt()).to_series().to_list() == [
+        "LINESTRING (0 1, 0 0)",
+        "LINESTRING (3 4, 3 0)",
+        "LINESTRING EMPTY",
+        None,
+    ]
+    distances = result.get_column("distance").to_list()
+    assert distances[:2] == [1.0, 4.0]
+    assert math.isnan(distances[2])
+
+    reversed_lines = gdf.select(road.st.shortest_line(st.geom()).st.to_wkt()).to_series()
+    assert reversed_lines.to_list()[:2] == ["LINESTRING (0 0, 0 1)", "LINESTRING (3 0, 3 4)"]
+
+
+def test_3d_measurements():
+    gdf = st.GeoDataFrame([
+        "LINESTRING Z (0 0 0, 3 0 4)",
+        "LINESTRING (0 0, 3 0)",
+        "POLYGON Z ((0 0 0, 3 0 4, 3 1 4, 0 1 0, 0 0 0))",
+        "LINESTRING EMPTY",
+    ])
+    assert gdf.select(st.length()).to_series().to_list() == [3.0, 3.0, 8.0, 0.0]
+    assert gdf.select(st.length_3d()).to_series().to_list() == [5.0, 3.0, 12.0, 0.0]
+
+    point = st.from_wkt(pl.lit("POINT Z (3 0 0)"))
+    distances_2d = gdf.select(st.geom().st.distance(point)).to_series().to_list()
+    distances_3d = gdf.select(st.geom().st.distance_3d(point)).to_series().to_list()
+    assert distances_2d[:3] == [0.0, 0.0, 0.0]
+    assert distances_3d[:3] == pytest.approx([2.4, 0.0, 2.4])
+    assert math.isnan(distances_2d[3])
+    assert math.isnan(distances_3d[3])
+
+
+def test_distance_3d_polygon_interior():
+    """The closest point of a polygon can be inside it rather than on its boundary."""
+    gdf = st.GeoDataFrame([
+        "POLYGON Z ((0 0 0, 10 0 0, 10 10 0, 0 10 0, 0 0 0))",
+        "POLYGON Z ((0 0 0, 10 0 0, 10 10 0, 0 10 0, 0 0 0), (4 4 0, 6 4 0, 6 6 0, 4 6 0, 4 4 0))",
+    ])
+    result = gdf.select(
+        above=st.geom().st.distance_3d(st.from_wkt(pl.lit("POINT Z (5 5 3)"))),
+        outside=st.geom().st.distance_3d(st.from_wkt(pl.lit("POINT Z (15 5 3)"))),
+        through=st.geom().st.distance_3d(st.from_wkt(pl.lit("LINESTRING Z (2 2 -1, 2 2 1)"))),
+    )
+    assert result["above"].to_list() == pytest.approx([3.0, math.sqrt(10)])
+    assert result["outside"].to_list() == pytest.approx([math.sqrt(34)] * 2)
+    assert result["through"].to_list() == [0.0, 0.0]
+
+
+def test_distance_3d_many_segments():
+    """The closest pair should be found among many segments, and curves need linearizing."""
+    high = ", ".join(f"{x} 0 {1 if x == 50 else 10}" for x in range(101))
+    low = ", ".join(f"{x / 2} 2 0" for x in range(201))
+    gdf = st.GeoDataFrame([f"LINESTRING Z ({high})"])
+    other = st.from_wkt(pl.lit(f"LINESTRING Z ({low})"))
+    assert gdf.select(st.geom().st.distance_3d(other)).item() == pytest.approx(math.sqrt(5))
+
+    arc = st.GeoDataFrame(["CIRCULARSTRING (0 0, 1 1, 2 0)"])
+    with pytest.raises(pl.exceptions.ComputeError, match="Unsupported geometry type"):
+        arc.select(st.geom().st.distance_3d(other))
+    linearized = arc.select(st.segmentize(max_segment_length=0.1))
+    assert linearized.select(st.geom().st.distance_3d(other)).item() == pytest.approx(1.0)
+
+
+def test_force_3d_keeps_nan_z():
+    coords = pl.Series([[[0.0, 0.0, float("nan")], [1.0, 1.0, 1.0]], [[0.0, 0.0], [1.0, 1.0]]])
+    gdf = pl.select(geometry=st.linestring(coords))
+    kept = gdf.select(st.force_3d(z=5.0).st.coordinates()).to_series().to_list()
+    assert math.isnan(kept[0][0][2])
+    assert kept[0][1] == [1.0, 1.0, 1.0]
+    assert kept[1] == [[0.0, 0.0, 5.0], [1.0, 1.0, 5.0]]
+
+    filled = gdf.select(st.force_3d(z=5.0, fill_nan=True).st.coordinates()).to_series().to_list()
+    assert filled == [[[0.0, 0.0, 5.0], [1.0, 1.0, 1.0]], [[0.0, 0.0, 5.0], [1.0, 1.0, 5.0]]]
+
+
+def test_multipoint_from_coords():
+    coords = pl.Series(
+        [[[0.0, 1.0], [2.0, 3.0]], [[0.0, 1.0, 2.0]], []],
+        dtype=pl.List(pl.List(pl.Float64)),
+    )
+    gdf = pl.select(geometry=st.multipoint(coords))
+    assert gdf.select(st.to_wkt()).to_series().to_list() == [
+        "MULTIPOINT ((0 1), (2 3))",
+        "MULTIPOINT Z ((0 1 2))",
+        "MULTIPOINT EMPTY",
+    ]
+
+
+def test_coordinates_struct():
+    gdf = st.GeoDataFrame(["POINT (0 1)", "POINT Z (0 1 2)", "POINT M (0 1 3)", "POINT EMPTY"])
+    result = gdf.select(st.coordinates_struct()).to_series().to_list()
+    assert result == [
+        [{"x": 0.0, "y": 1.0, "z": None, "m": None}],
+        [{"x": 0.0, "y": 1.0, "z": 2.0, "m": None}],
+        [{"x": 0.0, "y": 1.0, "z": None, "m": 3.0}],
+        [],
+    ]
+
+
+def test_largest_part_centroid():
+    gdf = st.GeoDataFrame(
+        [
+            "SRID=3857;MULTIPOLYGON (((0 0, 1 0, 1 1, 0 1, 0 0)), ((8 0, 12 0, 12 4, 8 4, 8 0)))",
+            "GEOMETRYCOLLECTION (LINESTRING (0 0, 10 0), POLYGON ((20 0, 21 0, 21 1, 20 0)))",
+            "MULTILINESTRING ((0 0, 1 0), (0 2, 0 6))",
+            "MULTIPOINT ((1 1), (2 2))",
+            "MULTIPOLYGON EMPTY",
+            None,
+        ],
+        geometry_format="ewkt",
+    )
+    result = gdf.select(st.largest_part_centroid().st.to_ewkt()).to_series().to_list()
+    assert result == [
+        "SRID=3857;POINT (10 2)",
+        "POINT (20.666667 0.333333)",
+        "POINT (0 4)",
+        "POINT (1 1)",
+        "POINT EMPTY",
+        None,
+    ]
+
+
+def test_points_on_surface():
+    gdf = st.GeoDataFrame(
+        [
+            "SRID=3857;GEOMETRYCOLLECTION (POINT (0 0), MULTIPOINT ((1 1), (2 2)), POLYGON EMPTY)",
+            "POLYGON ((0 0, 2 0, 2 2, 0 2, 0 0))",
+            "MULTIPOLYGON EMPTY",
+            None,
+        ],
+        geometry_format="ewkt",
+    )
+    points = gdf.select(st.points_on_surface()).to_series()
+    assert points.list.len().to_list() == [3, 1, 0, None]
+    wkt = points.explode().st.to_ewkt().to_list()
+    assert wkt == [
+        "SRID=3857;POINT (0 0)",
+        "SRID=3857;POINT (1 1)",
+        "SRID=3857;POINT (2 2)",
+        "POINT (1 1)",
+        None,
+        None,
+    ]
+
+
+def test_count_points_and_coordinates():
+    gdf = st.GeoDataFrame([
+        "LINESTRING (0 0, 1 1, 2 0)",
+        "POLYGON ((0 0, 3 0, 0 3, 0 0), (1 1, 2 1, 1 2, 1 1))",
+        "MULTIPOINT ((0 0), (1 1))",
+        "GEOMETRYCOLLECTION (POINT (0 0), LINESTRING (0 0, 1 1))",
+    ])
+    result = gdf.select(
+        points=st.count_points(),
+        coordinates=st.count_coordinates(),
+        vertices=st.num_vertices(),
+    )
+    assert result.to_dict(as_series=False) == {
+        "points": [3, 0, 0, 0],
+        "coordinates": [3, 8, 2, 3],
+        "vertices": [3, 8, 2, 3],
+    }
+
+
+def test_count_coordinates_per_part():
+    gdf = st.GeoDataFrame([
+        "GEOMETRYCOLLECTION (POINT (0 0), LINESTRING (0 0, 1 1), POINT EMPTY)",
+        "MULTIPOLYGON (((0 0, 1 0, 0 1, 0 0)), ((2 2, 3 2, 2 3, 2 2)))",
+        "POLYGON EMPTY",
+        None,
+    ])
+    result = gdf.select(st.count_coordinates_per_part()).to_series().to_list()
+    assert result == [[1, 2, 0], [4, 4], [], None]
+
+
+def test_points_from_coords():
+    df = pl.DataFrame({
+        "x": [0.0, 1.0, 2.0],
+        "y": [3.0, None, 5.0],
+        "m": [6.0, 7.0, 8.0],
+    })
+    result = df.select(
+        xy=st.points_from_coords("x", "y").st.to_wkt(),
+        xyz=st.points_from_coords("x", "y", z=1.0).st.to_wkt(),
+        xym=st.points_from_coords("x", "y", m="m").st.to_wkt(),
+        srid=st.points_from_coords("x", "y", srid=4326).st.srid(),
+    )
+    assert result.to_dict(as_series=False) == {
+        "xy": ["POINT (0 3)", None, "POINT (2 5)"],
+        "xyz": ["POINT Z (0 3 1)", None, "POINT Z (2 5 1)"],
+        "xym": ["POINT M (0 3 6)", None, "POINT M (2 5 8)"],
+        "srid": [4326, None, 4326],
+    }